@@ -2,7 +2,8 @@
 
 set -eux
 
-readarray -d '' crates < <(find . -mindepth 2 -maxdepth 2 -type f -name Cargo.toml -print0)
+# The fuzz targets are outside the workspace, so have no package in the flake.
+readarray -d '' crates < <(find . -mindepth 2 -maxdepth 3 -type f -name Cargo.toml -not -path './fuzz/*' -print0)
 for crate in "${crates[@]}"; do
   nix build ".#$(basename "$(dirname "$crate")")"
 done
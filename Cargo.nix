@@ -3,20 +3,53 @@
 args @ {
   release ? true,
   rootFeatures ? [
+    "aoc/default"
+    "aoc2019/default"
+    "solution/default"
+    "intcode/default"
+    "parse/default"
+    "grid/default"
+    "loader/default"
+    "logging/default"
+    "capi/default"
+    "ca/default"
+    "gen/default"
+    "strategies/default"
+    "geometry/default"
+    "graph/default"
+    "numtheory/default"
+    "ocr/default"
+    "snapshot/default"
+    "viz-image/default"
+    "viz-term/default"
+    "icpipe/default"
+    "python/default"
+    "wasm/default"
+    "day_11/default"
+    "day_13/default"
+    "day_15/default"
+    "day_19/default"
+    "day_8/default"
+    "xtask/default"
     "day_1/default"
     "day_2/default"
-    "intcode/default"
     "day_3/default"
     "day_4/default"
     "day_5/default"
     "day_6/default"
     "day_7/default"
-    "day_8/default"
     "day_9/default"
     "day_10/default"
-    "day_11/default"
-    "day_13/default"
-    "day_19/default"
+    "day_12/default"
+    "day_14/default"
+    "day_16/default"
+    "day_17/default"
+    "day_18/default"
+    "day_20/default"
+    "day_21/default"
+    "day_22/default"
+    "day_23/default"
+    "day_25/default"
   ],
   rustPackages,
   buildRustPackages,
@@ -72,20 +105,53 @@ in
   in {
     cargo2nixVersion = "0.11.0";
     workspace = {
+      aoc = rustPackages.unknown.aoc."0.1.0";
+      aoc2019 = rustPackages.unknown.aoc2019."0.1.0";
+      solution = rustPackages.unknown.solution."0.1.0";
+      intcode = rustPackages.unknown.intcode."0.1.0";
+      parse = rustPackages.unknown.parse."0.1.0";
+      grid = rustPackages.unknown.grid."0.1.0";
+      loader = rustPackages.unknown.loader."0.1.0";
+      logging = rustPackages.unknown.logging."0.1.0";
+      capi = rustPackages.unknown.capi."0.1.0";
+      ca = rustPackages.unknown.ca."0.1.0";
+      gen = rustPackages.unknown.gen."0.1.0";
+      strategies = rustPackages.unknown.strategies."0.1.0";
+      geometry = rustPackages.unknown.geometry."0.1.0";
+      graph = rustPackages.unknown.graph."0.1.0";
+      numtheory = rustPackages.unknown.numtheory."0.1.0";
+      ocr = rustPackages.unknown.ocr."0.1.0";
+      snapshot = rustPackages.unknown.snapshot."0.1.0";
+      viz-image = rustPackages.unknown.viz-image."0.1.0";
+      viz-term = rustPackages.unknown.viz-term."0.1.0";
+      icpipe = rustPackages.unknown.icpipe."0.1.0";
+      python = rustPackages.unknown.python."0.1.0";
+      wasm = rustPackages.unknown.wasm."0.1.0";
+      day_11 = rustPackages.unknown.day_11."0.1.0";
+      day_13 = rustPackages.unknown.day_13."0.1.0";
+      day_15 = rustPackages.unknown.day_15."0.1.0";
+      day_19 = rustPackages.unknown.day_19."0.1.0";
+      day_8 = rustPackages.unknown.day_8."0.1.0";
+      xtask = rustPackages.unknown.xtask."0.1.0";
       day_1 = rustPackages.unknown.day_1."0.1.0";
       day_2 = rustPackages.unknown.day_2."0.1.0";
-      intcode = rustPackages.unknown.intcode."0.1.0";
       day_3 = rustPackages.unknown.day_3."0.1.0";
       day_4 = rustPackages.unknown.day_4."0.1.0";
       day_5 = rustPackages.unknown.day_5."0.1.0";
       day_6 = rustPackages.unknown.day_6."0.1.0";
       day_7 = rustPackages.unknown.day_7."0.1.0";
-      day_8 = rustPackages.unknown.day_8."0.1.0";
       day_9 = rustPackages.unknown.day_9."0.1.0";
       day_10 = rustPackages.unknown.day_10."0.1.0";
-      day_11 = rustPackages.unknown.day_11."0.1.0";
-      day_13 = rustPackages.unknown.day_13."0.1.0";
-      day_19 = rustPackages.unknown.day_19."0.1.0";
+      day_12 = rustPackages.unknown.day_12."0.1.0";
+      day_14 = rustPackages.unknown.day_14."0.1.0";
+      day_16 = rustPackages.unknown.day_16."0.1.0";
+      day_17 = rustPackages.unknown.day_17."0.1.0";
+      day_18 = rustPackages.unknown.day_18."0.1.0";
+      day_20 = rustPackages.unknown.day_20."0.1.0";
+      day_21 = rustPackages.unknown.day_21."0.1.0";
+      day_22 = rustPackages.unknown.day_22."0.1.0";
+      day_23 = rustPackages.unknown.day_23."0.1.0";
+      day_25 = rustPackages.unknown.day_25."0.1.0";
     };
     "registry+https://github.com/rust-lang/crates.io-index".aho-corasick."1.1.3" = overridableMkRustCrate (profileName: rec {
       name = "aho-corasick";
@@ -116,6 +182,240 @@ in
       ];
     });
 
+    "unknown".aoc."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "aoc";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      features = builtins.concatLists [
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats") "alloc-stats")
+        (lib.optional (rootFeatures' ? "aoc/fetch") "fetch")
+        (lib.optional (rootFeatures' ? "aoc/serve") "serve")
+      ];
+      dependencies = {
+        aoc2019 = (rustPackages."unknown".aoc2019."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/serve"
+          then "http_body_util"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body-util."0.1.5" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/serve"
+          then "hyper"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hyper."1.12.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/serve"
+          then "hyper_util"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hyper-util."0.1.21" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.217" {inherit profileName;}).out;
+        serde_json = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.134" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/serve"
+          then "tokio"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."1.53.2" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/fetch"
+          then "ureq"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ureq."2.12.1" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".aoc2019."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "aoc2019";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      features = builtins.concatLists [
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day1" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day1")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day10" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day10")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day11" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day11")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day12" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day12")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day13" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day13")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day14" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day14")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day15" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day15")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day16" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day16")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day17" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day17")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day18" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day18")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day19" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day19")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day2" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day2")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day20" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day20")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day21" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day21")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day22" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day22")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day23" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day23")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day25" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day25")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day3" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day3")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day4" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day4")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day5" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day5")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day6" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day6")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day7" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day7")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day8" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day8")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day9" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "day9")
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "default")
+      ];
+      dependencies = {
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day1" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_1"
+          else null
+        } =
+          (rustPackages."unknown".day_1."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day10" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_10"
+          else null
+        } =
+          (rustPackages."unknown".day_10."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day11" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_11"
+          else null
+        } =
+          (rustPackages."unknown".day_11."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day12" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_12"
+          else null
+        } =
+          (rustPackages."unknown".day_12."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day13" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_13"
+          else null
+        } =
+          (rustPackages."unknown".day_13."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day14" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_14"
+          else null
+        } =
+          (rustPackages."unknown".day_14."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day15" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_15"
+          else null
+        } =
+          (rustPackages."unknown".day_15."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day16" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_16"
+          else null
+        } =
+          (rustPackages."unknown".day_16."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day17" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_17"
+          else null
+        } =
+          (rustPackages."unknown".day_17."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day18" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_18"
+          else null
+        } =
+          (rustPackages."unknown".day_18."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day19" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_19"
+          else null
+        } =
+          (rustPackages."unknown".day_19."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day2" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_2"
+          else null
+        } =
+          (rustPackages."unknown".day_2."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day20" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_20"
+          else null
+        } =
+          (rustPackages."unknown".day_20."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day21" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_21"
+          else null
+        } =
+          (rustPackages."unknown".day_21."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day22" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_22"
+          else null
+        } =
+          (rustPackages."unknown".day_22."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day23" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_23"
+          else null
+        } =
+          (rustPackages."unknown".day_23."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day25" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_25"
+          else null
+        } =
+          (rustPackages."unknown".day_25."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day3" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_3"
+          else null
+        } =
+          (rustPackages."unknown".day_3."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day4" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_4"
+          else null
+        } =
+          (rustPackages."unknown".day_4."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day5" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_5"
+          else null
+        } =
+          (rustPackages."unknown".day_5."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day6" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_6"
+          else null
+        } =
+          (rustPackages."unknown".day_6."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day7" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_7"
+          else null
+        } =
+          (rustPackages."unknown".day_7."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day8" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_8"
+          else null
+        } =
+          (rustPackages."unknown".day_8."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day9" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "day_9"
+          else null
+        } =
+          (rustPackages."unknown".day_9."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".atty."0.2.14" = overridableMkRustCrate (profileName: rec {
       name = "atty";
       version = "0.2.14";
@@ -182,6 +482,26 @@ in
       ];
     });
 
+    "unknown".ca."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "ca";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".capi."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "capi";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        aoc = (rustPackages."unknown".aoc."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".cast."0.3.0" = overridableMkRustCrate (profileName: rec {
       name = "cast";
       version = "0.3.0";
@@ -425,11 +745,14 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_1/no_real_inputs") "no_real_inputs")
-      ];
+      dependencies = {
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -439,10 +762,25 @@ in
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
       features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_10/no_real_inputs") "no_real_inputs")
+        (lib.optional (rootFeatures' ? "day_10/animate") "animate")
       ];
+      dependencies = {
+        ${
+          if rootFeatures' ? "day_10/animate"
+          then "crossterm"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossterm."0.28.1" {inherit profileName;}).out;
+        geometry = (rustPackages."unknown".geometry."0.1.0" {inherit profileName;}).out;
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        numtheory = (rustPackages."unknown".numtheory."0.1.0" {inherit profileName;}).out;
+        rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -452,14 +790,52 @@ in
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
       features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_11/no_real_inputs") "no_real_inputs")
+        (lib.optional (rootFeatures' ? "day_11/animate") "animate")
+        (lib.optional (rootFeatures' ? "day_11/image" || rootFeatures' ? "wasm/default") "image")
       ];
       dependencies = {
+        geometry = (rustPackages."unknown".geometry."0.1.0" {inherit profileName;}).out;
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
         intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        ocr = (rustPackages."unknown".ocr."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_11/image" || rootFeatures' ? "wasm/default"
+          then "viz_image"
+          else null
+        } =
+          (rustPackages."unknown".viz-image."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_11/animate"
+          then "viz_term"
+          else null
+        } =
+          (rustPackages."unknown".viz-term."0.1.0" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
-        insta = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".insta."1.42.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        snapshot = (rustPackages."unknown".snapshot."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_12."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_12";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        numtheory = (rustPackages."unknown".numtheory."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -469,13 +845,146 @@ in
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
       features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_13/no_real_inputs") "no_real_inputs")
+        (lib.optional (rootFeatures' ? "day_13/image" || rootFeatures' ? "wasm/default") "image")
+        (lib.optional (rootFeatures' ? "day_13/play") "play")
+      ];
+      dependencies = {
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_13/image" || rootFeatures' ? "wasm/default"
+          then "viz_image"
+          else null
+        } =
+          (rustPackages."unknown".viz-image."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_13/play"
+          then "viz_term"
+          else null
+        } =
+          (rustPackages."unknown".viz-term."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_14."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_14";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_15."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_15";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      features = builtins.concatLists [
+        (lib.optional (rootFeatures' ? "day_15/animate") "animate")
+        (lib.optional (rootFeatures' ? "day_15/image" || rootFeatures' ? "wasm/default") "image")
       ];
       dependencies = {
+        graph = (rustPackages."unknown".graph."0.1.0" {inherit profileName;}).out;
         intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_15/image" || rootFeatures' ? "wasm/default"
+          then "viz_image"
+          else null
+        } =
+          (rustPackages."unknown".viz-image."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_15/animate"
+          then "viz_term"
+          else null
+        } =
+          (rustPackages."unknown".viz-term."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_16."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_16";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_17."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_17";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      features = builtins.concatLists [
+        (lib.optional (rootFeatures' ? "day_17/animate") "animate")
+      ];
+      dependencies = {
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_17/animate"
+          then "viz_term"
+          else null
+        } =
+          (rustPackages."unknown".viz-term."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_18."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_18";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        graph = (rustPackages."unknown".graph."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -485,13 +994,30 @@ in
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
       features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_19/no_real_inputs") "no_real_inputs")
+        (lib.optional (rootFeatures' ? "day_19/image" || rootFeatures' ? "wasm/default") "image")
       ];
       dependencies = {
         intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_19/image" || rootFeatures' ? "wasm/default"
+          then "thiserror"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_19/image" || rootFeatures' ? "wasm/default"
+          then "viz_image"
+          else null
+        } =
+          (rustPackages."unknown".viz-image."0.1.0" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -500,14 +1026,106 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_2/no_real_inputs") "no_real_inputs")
-      ];
       dependencies = {
         intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_20."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_20";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        graph = (rustPackages."unknown".graph."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_21."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_21";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_22."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_22";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        numtheory = (rustPackages."unknown".numtheory."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_23."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_23";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".day_25."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "day_25";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -516,11 +1134,17 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_3/no_real_inputs") "no_real_inputs")
-      ];
+      dependencies = {
+        geometry = (rustPackages."unknown".geometry."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -529,11 +1153,16 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_4/no_real_inputs") "no_real_inputs")
-      ];
+      dependencies = {
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+      };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -542,14 +1171,15 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_5/no_real_inputs") "no_real_inputs")
-      ];
       dependencies = {
         intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -558,11 +1188,18 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_6/no_real_inputs") "no_real_inputs")
-      ];
+      dependencies = {
+        graph = (rustPackages."unknown".graph."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -571,15 +1208,18 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_7/no_real_inputs") "no_real_inputs")
-      ];
       dependencies = {
         intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
         itertools = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itertools."0.13.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -589,11 +1229,26 @@ in
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
       features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_8/no_real_inputs") "no_real_inputs")
+        (lib.optional (rootFeatures' ? "day_8/image" || rootFeatures' ? "wasm/default") "image")
       ];
+      dependencies = {
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        ocr = (rustPackages."unknown".ocr."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "day_8/image" || rootFeatures' ? "wasm/default"
+          then "viz_image"
+          else null
+        } =
+          (rustPackages."unknown".viz-image."0.1.0" {inherit profileName;}).out;
+      };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
-        insta = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".insta."1.42.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        snapshot = (rustPackages."unknown".snapshot."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -602,14 +1257,17 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
-      features = builtins.concatLists [
-        (lib.optional (rootFeatures' ? "day_9/no_real_inputs") "no_real_inputs")
-      ];
       dependencies = {
         intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        num_bigint = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-bigint."0.4.6" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -640,6 +1298,51 @@ in
       ];
     });
 
+    "unknown".gen."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "gen";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        day_16 = (rustPackages."unknown".day_16."0.1.0" {inherit profileName;}).out;
+        day_3 = (rustPackages."unknown".day_3."0.1.0" {inherit profileName;}).out;
+        day_6 = (rustPackages."unknown".day_6."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".geometry."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "geometry";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+    });
+
+    "unknown".graph."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "graph";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+    });
+
+    "unknown".grid."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "grid";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".half."2.4.1" = overridableMkRustCrate (profileName: rec {
       name = "half";
       version = "2.4.1";
@@ -688,6 +1391,20 @@ in
       };
     });
 
+    "unknown".icpipe."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "icpipe";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        logging = (rustPackages."unknown".logging."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".indexmap."1.9.3" = overridableMkRustCrate (profileName: rec {
       name = "indexmap";
       version = "1.9.3";
@@ -733,11 +1450,29 @@ in
       version = "0.1.0";
       registry = "unknown";
       src = fetchCrateLocal workspaceSrc;
+      features = builtins.concatLists [
+        (lib.optional (rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day9" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "day_9/default" || rootFeatures' ? "intcode/bigint" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default") "bigint")
+      ];
       dependencies = {
+        log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day9" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "day_9/default" || rootFeatures' ? "intcode/bigint" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "num_bigint"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-bigint."0.4.6" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "aoc/alloc-stats" || rootFeatures' ? "aoc/default" || rootFeatures' ? "aoc/fetch" || rootFeatures' ? "aoc/serve" || rootFeatures' ? "aoc2019/day9" || rootFeatures' ? "aoc2019/default" || rootFeatures' ? "capi/default" || rootFeatures' ? "day_9/default" || rootFeatures' ? "intcode/bigint" || rootFeatures' ? "python/default" || rootFeatures' ? "python/python" || rootFeatures' ? "wasm/default"
+          then "num_traits"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" {inherit profileName;}).out;
         thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
       };
       devDependencies = {
         criterion = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".criterion."0.4.0" {inherit profileName;}).out;
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
       };
     });
 
@@ -838,6 +1573,13 @@ in
       };
     });
 
+    "unknown".loader."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "loader";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" = overridableMkRustCrate (profileName: rec {
       name = "log";
       version = "0.4.22";
@@ -846,6 +1588,19 @@ in
         inherit name version;
         sha256 = "a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24";
       };
+      features = builtins.concatLists [
+        ["std"]
+      ];
+    });
+
+    "unknown".logging."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "logging";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.22" {inherit profileName;}).out;
+      };
     });
 
     "registry+https://github.com/rust-lang/crates.io-index".memchr."2.7.4" = overridableMkRustCrate (profileName: rec {
@@ -879,6 +1634,28 @@ in
       };
     });
 
+    "unknown".numtheory."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "numtheory";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      devDependencies = {
+        num_bigint = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-bigint."0.4.6" {inherit profileName;}).out;
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".ocr."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "ocr";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".once_cell."1.20.2" = overridableMkRustCrate (profileName: rec {
       name = "once_cell";
       version = "1.20.2";
@@ -918,6 +1695,21 @@ in
       ];
     });
 
+    "unknown".parse."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "parse";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+      devDependencies = {
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+        strategies = (rustPackages."unknown".strategies."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".plotters."0.3.7" = overridableMkRustCrate (profileName: rec {
       name = "plotters";
       version = "0.3.7";
@@ -991,6 +1783,28 @@ in
       };
     });
 
+    "unknown".python."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "python";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      features = builtins.concatLists [
+        (lib.optional (rootFeatures' ? "python/python") "python")
+      ];
+      dependencies = {
+        aoc = (rustPackages."unknown".aoc."0.1.0" {inherit profileName;}).out;
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        ${
+          if rootFeatures' ? "python/python"
+          then "pyo3"
+          else null
+        } =
+          (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pyo3."0.23.3" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".quote."1.0.38" = overridableMkRustCrate (profileName: rec {
       name = "quote";
       version = "1.0.38";
@@ -1192,6 +2006,38 @@ in
       ];
     });
 
+    "unknown".snapshot."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "snapshot";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        insta = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".insta."1.42.0" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".solution."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "solution";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        intcode = (rustPackages."unknown".intcode."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".strategies."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "strategies";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.6.0" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".syn."2.0.95" = overridableMkRustCrate (profileName: rec {
       name = "syn";
       version = "2.0.95";
@@ -1284,6 +2130,29 @@ in
       };
     });
 
+    "unknown".viz-image."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "viz-image";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
+        image = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image."0.25.5" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+    });
+
+    "unknown".viz-term."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "viz-term";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        crossterm = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossterm."0.28.1" {inherit profileName;}).out;
+        grid = (rustPackages."unknown".grid."0.1.0" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".walkdir."2.5.0" = overridableMkRustCrate (profileName: rec {
       name = "walkdir";
       version = "2.5.0";
@@ -1303,6 +2172,26 @@ in
       };
     });
 
+    "unknown".wasm."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "wasm";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        aoc = (rustPackages."unknown".aoc."0.1.0" {inherit profileName;}).out;
+        day_11 = (rustPackages."unknown".day_11."0.1.0" {inherit profileName;}).out;
+        day_13 = (rustPackages."unknown".day_13."0.1.0" {inherit profileName;}).out;
+        day_15 = (rustPackages."unknown".day_15."0.1.0" {inherit profileName;}).out;
+        day_19 = (rustPackages."unknown".day_19."0.1.0" {inherit profileName;}).out;
+        day_8 = (rustPackages."unknown".day_8."0.1.0" {inherit profileName;}).out;
+        parse = (rustPackages."unknown".parse."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+        viz_image = (rustPackages."unknown".viz-image."0.1.0" {inherit profileName;}).out;
+        wasm_bindgen = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.99" {inherit profileName;}).out;
+      };
+    });
+
     "registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.99" = overridableMkRustCrate (profileName: rec {
       name = "wasm-bindgen";
       version = "0.2.99";
@@ -1656,4 +2545,16 @@ in
         sha256 = "589f6da84c646204747d1270a2a5661ea66ed1cced2631d546fdfb155959f9ec";
       };
     });
+
+    "unknown".xtask."0.1.0" = overridableMkRustCrate (profileName: rec {
+      name = "xtask";
+      version = "0.1.0";
+      registry = "unknown";
+      src = fetchCrateLocal workspaceSrc;
+      dependencies = {
+        loader = (rustPackages."unknown".loader."0.1.0" {inherit profileName;}).out;
+        solution = (rustPackages."unknown".solution."0.1.0" {inherit profileName;}).out;
+        thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.9" {inherit profileName;}).out;
+      };
+    });
   }
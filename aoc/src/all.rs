@@ -0,0 +1,207 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::num::NonZeroUsize;
+use std::panic;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::run;
+use crate::table::Row;
+
+/// How long `aoc all` lets each part run by default.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// One part to solve, with its input or the reason it couldn't be read.
+pub struct Job {
+    pub day: u8,
+    pub part: u8,
+    pub input: Result<Arc<str>, String>,
+}
+
+/// Every part of every day, with its default input.
+pub fn every_part() -> Vec<Job> {
+    let mut jobs = Vec::new();
+    for &day in run::DAYS {
+        let input = std::fs::read_to_string(run::default_input_path(day))
+            .map(Arc::from)
+            .map_err(|e| format!("could not read input: {e}"));
+        for &part in run::parts(day) {
+            jobs.push(Job {
+                day,
+                part,
+                input: input.clone(),
+            });
+        }
+    }
+    jobs
+}
+
+/// What a worker tells `run_all` about a job.
+enum Event {
+    Started(usize, Instant),
+    Finished(usize, Result<String, String>, Option<Duration>),
+}
+
+/// Solve every job concurrently on a pool with a thread per available core, giving the rows of
+/// the table in the same order as the jobs. A part which panics is reported as such, and one
+/// which hasn't finished `timeout` after it started is reported as timed out; its worker is left
+/// to run, since there's no way to stop it. If every worker is stuck on a part which timed out,
+/// the parts still waiting for one are reported as never having run.
+pub fn run_all(jobs: Vec<Job>, timeout: Duration) -> Vec<Row> {
+    let mut rows: Vec<Row> = jobs
+        .iter()
+        .map(|job| Row {
+            day: job.day,
+            part: job.part,
+            outcome: Err("not run: every worker was stuck on a part which timed out".to_string()),
+            elapsed: None,
+        })
+        .collect();
+
+    let mut queue = VecDeque::new();
+    for (index, job) in jobs.into_iter().enumerate() {
+        match job.input {
+            Ok(input) => queue.push_back((index, job.day, job.part, input)),
+            Err(e) => rows[index].outcome = Err(e),
+        }
+    }
+    let mut remaining = queue.len();
+    let workers = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(remaining);
+    let queue = Arc::new(Mutex::new(queue));
+
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..workers {
+        let queue = Arc::clone(&queue);
+        let sender = sender.clone();
+        thread::spawn(move || loop {
+            let Some((index, day, part, input)) = queue.lock().unwrap().pop_front() else {
+                break;
+            };
+            // The receiver is gone once every job is accounted for or timed out.
+            let _ = sender.send(Event::Started(index, Instant::now()));
+            let result = panic::catch_unwind(|| run::run_timed(day, part, &input));
+            let (outcome, elapsed) = match result {
+                Ok((answer, elapsed)) => (answer.map_err(|e| e.to_string()), Some(elapsed)),
+                Err(_) => (Err("panicked".to_string()), None),
+            };
+            let _ = sender.send(Event::Finished(index, outcome, elapsed));
+        });
+    }
+    drop(sender);
+
+    // The deadline of each job a worker is running, and the jobs which ran past theirs.
+    let mut deadlines: HashMap<usize, Instant> = HashMap::new();
+    let mut timed_out: HashSet<usize> = HashSet::new();
+    while remaining > 0 && timed_out.len() < workers {
+        let event = match deadlines.values().min() {
+            Some(&deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match event {
+            Ok(Event::Started(index, at)) => {
+                deadlines.insert(index, at + timeout);
+            }
+            Ok(Event::Finished(index, outcome, elapsed)) => {
+                if deadlines.remove(&index).is_some() {
+                    rows[index].outcome = outcome;
+                    rows[index].elapsed = elapsed;
+                    remaining -= 1;
+                } else {
+                    // It finished after we gave up on it, so its worker is free again.
+                    timed_out.remove(&index);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                deadlines.retain(|&index, &mut deadline| {
+                    if deadline > now {
+                        return true;
+                    }
+                    rows[index].outcome = Err(format!("timed out after {timeout:?}"));
+                    timed_out.insert(index);
+                    remaining -= 1;
+                    false
+                });
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_all() {
+        let jobs = vec![
+            Job {
+                day: 1,
+                part: 2,
+                input: Ok(Arc::from("14\n1969")),
+            },
+            Job {
+                day: 4,
+                part: 1,
+                input: Err("could not read input".to_string()),
+            },
+            Job {
+                day: 1,
+                part: 1,
+                input: Ok(Arc::from("12\n14")),
+            },
+            Job {
+                day: 6,
+                part: 1,
+                input: Ok(Arc::from("COM-B")),
+            },
+        ];
+        let rows = run_all(jobs, Duration::from_secs(60));
+        let outcomes: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.day,
+                    row.part,
+                    row.outcome.is_ok(),
+                    row.elapsed.is_some(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            outcomes,
+            [
+                (1, 2, true, true),
+                (4, 1, false, false),
+                (1, 1, true, true),
+                (6, 1, false, true)
+            ]
+        );
+        assert_eq!(rows[0].outcome, Ok("968".to_string()));
+        assert_eq!(rows[1].outcome, Err("could not read input".to_string()));
+    }
+
+    #[test]
+    fn test_run_all_more_jobs_than_workers() {
+        let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let jobs = (0..3 * workers + 1)
+            .map(|i| Job {
+                day: 1,
+                part: 1,
+                input: Ok(Arc::from(format!("{}", 12 + 3 * i))),
+            })
+            .collect();
+        let rows = run_all(jobs, Duration::from_secs(60));
+        let outcomes: Vec<_> = rows.into_iter().map(|row| row.outcome).collect();
+        let expected: Vec<_> = (0..3 * workers + 1)
+            .map(|i| Ok(format!("{}", 2 + i)))
+            .collect();
+        assert_eq!(outcomes, expected);
+    }
+}
@@ -0,0 +1,146 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+/// An allocator which counts what passes through it on the way to the system allocator.
+/// `aoc` installs one as its global allocator when built with the `alloc-stats` feature.
+/// Allocations from every thread are counted, so a part that uses rayon is counted in full.
+pub struct Counting {
+    allocations: AtomicUsize,
+    current: AtomicUsize,
+    peak: AtomicUsize,
+    baseline: AtomicUsize,
+}
+
+/// What was allocated since the last `Counting::reset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllocStats {
+    /// How many allocations and reallocations were made.
+    pub allocations: usize,
+    /// The most bytes allocated at once, beyond what was already allocated at the reset.
+    pub peak_bytes: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, peak {} bytes",
+            self.allocations, self.peak_bytes
+        )
+    }
+}
+
+impl Counting {
+    pub const fn new() -> Counting {
+        Counting {
+            allocations: AtomicUsize::new(0),
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            baseline: AtomicUsize::new(0),
+        }
+    }
+
+    /// Start counting afresh from what's allocated now.
+    pub fn reset(&self) {
+        let current = self.current.load(Relaxed);
+        self.allocations.store(0, Relaxed);
+        self.peak.store(current, Relaxed);
+        self.baseline.store(current, Relaxed);
+    }
+
+    pub fn stats(&self) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations.load(Relaxed),
+            peak_bytes: self
+                .peak
+                .load(Relaxed)
+                .saturating_sub(self.baseline.load(Relaxed)),
+        }
+    }
+
+    /// Count what `f` allocates.
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, AllocStats) {
+        self.reset();
+        let result = f();
+        (result, self.stats())
+    }
+
+    fn grew(&self, bytes: usize) {
+        self.allocations.fetch_add(1, Relaxed);
+        let current = self.current.fetch_add(bytes, Relaxed) + bytes;
+        self.peak.fetch_max(current, Relaxed);
+    }
+}
+
+impl Default for Counting {
+    fn default() -> Counting {
+        Counting::new()
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.current.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            // Count the new size as allocated before the old is freed, as a copy would need.
+            self.grew(new_size);
+            self.current.fetch_sub(layout.size(), Relaxed);
+        }
+        new
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting() {
+        let counting = Counting::new();
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let ((), stats) = counting.measure(|| unsafe {
+            let a = counting.alloc(small);
+            let b = counting.alloc_zeroed(small);
+            counting.dealloc(a, small);
+            let b = counting.realloc(b, small, 300);
+            counting.dealloc(b, Layout::from_size_align(300, 8).unwrap());
+        });
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 3,
+                peak_bytes: 400
+            }
+        );
+        assert_eq!(stats.to_string(), "3 allocations, peak 400 bytes");
+
+        // Whatever's still allocated at a reset doesn't count towards the peak.
+        let held = unsafe { counting.alloc(small) };
+        counting.reset();
+        assert_eq!(counting.stats().peak_bytes, 0);
+        unsafe { counting.dealloc(held, small) };
+        assert_eq!(counting.stats().peak_bytes, 0);
+    }
+}
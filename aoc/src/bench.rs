@@ -0,0 +1,315 @@
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use loader::loader;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::all::Job;
+use crate::run::{self, RunError};
+use crate::table::format_duration;
+
+/// How long `measure` keeps taking samples of a part, by default.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(2);
+
+/// The most samples `measure` takes of a part, however quick it is.
+pub const MAX_SAMPLES: usize = 100;
+
+/// How much slower than its baseline a part must be to count as a regression, by default: 10%.
+pub const DEFAULT_THRESHOLD: f64 = 0.1;
+
+#[derive(Error, Debug)]
+pub enum BenchError {
+    #[error("could not {action} {}: {source}", path.display())]
+    Io {
+        action: &'static str,
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("could not read the baseline at {}: {source}", path.display())]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("a baseline name must be letters, digits, '-' and '_', but got {0:?}")]
+    BadName(String),
+}
+
+/// The median time taken by one part.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub median_ns: u64,
+    pub samples: usize,
+}
+
+/// Every measurement from one run of `aoc bench`, as saved to disk.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Baseline {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    pub fn get(&self, day: u8, part: u8) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.part == part)
+    }
+}
+
+/// Where saved baselines live: under `target`, like criterion's, so that they stay local.
+pub fn baseline_dir() -> PathBuf {
+    loader::workspace_root().join("target").join("aoc-bench")
+}
+
+pub fn baseline_path(name: &str) -> Result<PathBuf, BenchError> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(BenchError::BadName(name.to_string()));
+    }
+    Ok(baseline_dir().join(format!("{name}.json")))
+}
+
+pub fn save(name: &str, baseline: &Baseline) -> Result<PathBuf, BenchError> {
+    let path = baseline_path(name)?;
+    let dir = baseline_dir();
+    std::fs::create_dir_all(&dir).map_err(|source| BenchError::Io {
+        action: "create",
+        path: dir,
+        source,
+    })?;
+    // Serialising these plain structs can't fail.
+    let json = serde_json::to_string_pretty(baseline).expect("baselines serialise");
+    std::fs::write(&path, json + "\n").map_err(|source| BenchError::Io {
+        action: "write",
+        path: path.clone(),
+        source,
+    })?;
+    Ok(path)
+}
+
+pub fn load(name: &str) -> Result<Baseline, BenchError> {
+    let path = baseline_path(name)?;
+    let json = std::fs::read_to_string(&path).map_err(|source| BenchError::Io {
+        action: "read",
+        path: path.clone(),
+        source,
+    })?;
+    serde_json::from_str(&json).map_err(|source| BenchError::Json { path, source })
+}
+
+/// Time one part repeatedly: once to warm up, and then until `budget` has passed or
+/// `MAX_SAMPLES` have been taken, whichever is first. A part slower than the budget is timed just
+/// the once.
+pub fn measure(day: u8, part: u8, input: &str, budget: Duration) -> Result<Measurement, RunError> {
+    let start = Instant::now();
+    let (answer, warm_up) = run::run_timed(day, part, input);
+    answer?;
+    let mut samples = vec![];
+    while samples.len() < MAX_SAMPLES && start.elapsed() + warm_up <= budget {
+        let (answer, elapsed) = run::run_timed(day, part, input);
+        answer?;
+        samples.push(elapsed);
+    }
+    if samples.is_empty() {
+        samples.push(warm_up);
+    }
+    samples.sort();
+    Ok(Measurement {
+        day,
+        part,
+        median_ns: samples[samples.len() / 2].as_nanos() as u64,
+        samples: samples.len(),
+    })
+}
+
+/// Measure each job in turn, giving the measurements and, separately, the parts which couldn't
+/// be measured and why.
+pub fn measure_all(jobs: &[Job], budget: Duration) -> (Baseline, Vec<(u8, u8, String)>) {
+    let mut baseline = Baseline::default();
+    let mut failures = vec![];
+    for job in jobs {
+        let result = match &job.input {
+            Ok(input) => measure(job.day, job.part, input, budget).map_err(|e| e.to_string()),
+            Err(e) => Err(e.clone()),
+        };
+        match result {
+            Ok(measurement) => baseline.measurements.push(measurement),
+            Err(e) => failures.push((job.day, job.part, e)),
+        }
+    }
+    (baseline, failures)
+}
+
+/// A part measured now, and how it compares with the baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// How much slower the part is than its baseline, as a fraction: 0.5 is 50% slower, and
+    /// negative numbers are faster.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline
+            .map(|baseline| self.current.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare(baseline: &Baseline, current: &Baseline) -> Vec<Comparison> {
+    current
+        .measurements
+        .iter()
+        .map(|m| Comparison {
+            day: m.day,
+            part: m.part,
+            baseline: baseline
+                .get(m.day, m.part)
+                .map(|b| Duration::from_nanos(b.median_ns)),
+            current: Duration::from_nanos(m.median_ns),
+        })
+        .collect()
+}
+
+const TIME_WIDTH: usize = 14;
+
+/// Lay out the comparisons as a table, marking the parts slower by more than `threshold`.
+pub fn render(comparisons: &[Comparison], threshold: f64) -> String {
+    let mut result = String::new();
+    writeln!(
+        result,
+        "day  part  {:>TIME_WIDTH$}  {:>TIME_WIDTH$}  {:>8}",
+        "baseline", "now", "change"
+    )
+    .unwrap();
+    for c in comparisons {
+        let baseline = match c.baseline {
+            Some(baseline) => format_duration(baseline, TIME_WIDTH),
+            None => format!("{:>TIME_WIDTH$}", "-"),
+        };
+        let change = match c.change() {
+            Some(change) => format!("{:>+7.1}%", change * 100.0),
+            None => format!("{:>8}", "new"),
+        };
+        let flag = if c.is_regression(threshold) {
+            "  REGRESSION"
+        } else {
+            ""
+        };
+        writeln!(
+            result,
+            "{:>3}  {:>4}  {baseline}  {}  {change}{flag}",
+            c.day,
+            c.part,
+            format_duration(c.current, TIME_WIDTH)
+        )
+        .unwrap();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn measurement(day: u8, part: u8, median_ns: u64) -> Measurement {
+        Measurement {
+            day,
+            part,
+            median_ns,
+            samples: 10,
+        }
+    }
+
+    #[test]
+    fn test_measure() {
+        let jobs = vec![
+            Job {
+                day: 1,
+                part: 2,
+                input: Ok(Arc::from("14\n1969")),
+            },
+            Job {
+                day: 4,
+                part: 1,
+                input: Err("could not read input".to_string()),
+            },
+        ];
+        let (baseline, failures) = measure_all(&jobs, Duration::from_millis(10));
+        assert_eq!(baseline.measurements.len(), 1);
+        let m = &baseline.measurements[0];
+        assert_eq!((m.day, m.part), (1, 2));
+        assert!(m.samples >= 1 && m.samples <= MAX_SAMPLES);
+        assert_eq!(failures, vec![(4, 1, "could not read input".to_string())]);
+
+        assert!(measure(1, 1, "x", Duration::ZERO).is_err());
+        assert_eq!(measure(1, 1, "12", Duration::ZERO).unwrap().samples, 1);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline {
+            measurements: vec![measurement(1, 1, 1000), measurement(1, 2, 1000)],
+        };
+        let current = Baseline {
+            measurements: vec![
+                measurement(1, 1, 1050),
+                measurement(1, 2, 2000),
+                measurement(2, 1, 7),
+            ],
+        };
+        let comparisons = compare(&baseline, &current);
+        let regressions: Vec<_> = comparisons
+            .iter()
+            .map(|c| c.is_regression(DEFAULT_THRESHOLD))
+            .collect();
+        assert_eq!(regressions, [false, true, false]);
+        assert_eq!(comparisons[1].change(), Some(1.0));
+        assert_eq!(comparisons[2].change(), None);
+
+        let table = render(&comparisons, DEFAULT_THRESHOLD);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "day  part        baseline             now    change",
+                "  1     1         0.001ms         0.001ms     +5.0%",
+                "  1     2         0.001ms         0.002ms   +100.0%  REGRESSION",
+                "  2     1               -         0.000ms       new",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_baseline_json() {
+        let baseline = Baseline {
+            measurements: vec![measurement(13, 2, 123_456)],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(
+            json,
+            r#"{"measurements":[{"day":13,"part":2,"median_ns":123456,"samples":10}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+
+        assert!(baseline_path("before-intcode_change").is_ok());
+        assert!(matches!(
+            baseline_path("../escape"),
+            Err(BenchError::BadName(_))
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use crate::all::Job;
+use crate::run;
+
+/// How long each part may take by default, parsing its input included, in a release build.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// The days whose parts may take longer than `DEFAULT_BUDGET`, and how long they may take.
+pub const OVERRIDES: &[(u8, Duration)] = &[];
+
+/// How many times a part is run before it's judged over budget, so that one slow run on a busy
+/// machine doesn't fail it.
+pub const ATTEMPTS: usize = 3;
+
+pub fn budget(day: u8) -> Duration {
+    OVERRIDES
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map_or(DEFAULT_BUDGET, |&(_, budget)| budget)
+}
+
+/// A part which failed, or took longer than its budget on every attempt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overrun {
+    pub day: u8,
+    pub part: u8,
+    pub budget: Duration,
+    /// The fastest attempt, or why the part failed.
+    pub outcome: Result<Duration, String>,
+}
+
+/// Time one part against `budget`, through the day's `Solution`: its fastest time, unless it
+/// fails or is over budget on each of `ATTEMPTS` tries.
+pub fn check(day: u8, part: u8, input: &str, budget: Duration) -> Result<Duration, Overrun> {
+    let overrun = |outcome| Overrun {
+        day,
+        part,
+        budget,
+        outcome,
+    };
+    let mut fastest = Duration::MAX;
+    for _ in 0..ATTEMPTS {
+        let (answer, elapsed) = run::run_timed(day, part, input);
+        answer.map_err(|e| overrun(Err(e.to_string())))?;
+        fastest = fastest.min(elapsed);
+        if fastest <= budget {
+            return Ok(fastest);
+        }
+    }
+    Err(overrun(Ok(fastest)))
+}
+
+/// Check every job with an input against its day's budget, giving the overruns.
+pub fn check_all(jobs: &[Job]) -> Vec<Overrun> {
+    jobs.iter()
+        .filter_map(|job| {
+            let input = job.input.as_ref().ok()?;
+            check(job.day, job.part, input, budget(job.day)).err()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::all;
+
+    #[test]
+    fn test_check() {
+        assert!(check(1, 1, "12\n14", DEFAULT_BUDGET).is_ok());
+        let Err(overrun) = check(1, 1, "12\n14", Duration::ZERO) else {
+            panic!("nothing takes no time at all");
+        };
+        assert_eq!((overrun.day, overrun.part), (1, 1));
+        assert!(overrun.outcome.is_ok());
+        assert!(matches!(
+            check(1, 1, "twelve", DEFAULT_BUDGET),
+            Err(Overrun {
+                outcome: Err(_),
+                ..
+            })
+        ));
+        for &(day, _) in OVERRIDES {
+            assert!(run::DAYS.contains(&day), "day {day} has no solution");
+        }
+    }
+
+    /// Timings in a debug build mean little, so this only runs when asked for.
+    #[test]
+    #[ignore = "times every real input; run with `cargo test --release -p aoc -- --ignored`"]
+    fn test_budgets() {
+        let overruns = check_all(&all::every_part());
+        let report: Vec<_> = overruns
+            .iter()
+            .map(|o| match &o.outcome {
+                Ok(fastest) => format!(
+                    "day {} part {} took {fastest:?}, over its budget of {:?}",
+                    o.day, o.part, o.budget
+                ),
+                Err(e) => format!("day {} part {} failed: {e}", o.day, o.part),
+            })
+            .collect();
+        assert!(report.is_empty(), "{}", report.join("\n"));
+    }
+}
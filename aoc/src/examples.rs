@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use loader::loader;
+use thiserror::Error;
+
+use crate::{run, verify};
+
+/// The file in each day's examples directory which says what each example's answers are.
+pub const MANIFEST: &str = "expected.txt";
+
+#[derive(Error, Debug)]
+pub enum ExampleError {
+    #[error("could not read {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{}, line {line}: {message}", path.display())]
+    Manifest {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    #[error("{} has no answers in {MANIFEST}", path.display())]
+    Unlisted { path: PathBuf },
+}
+
+/// An example from a puzzle's text, and the answer the puzzle gives for one of its parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub day: u8,
+    /// The name of the example's file, without the `.txt`.
+    pub name: String,
+    pub part: u8,
+    pub input: String,
+    pub expected: String,
+}
+
+/// Where `day`'s examples live: `examples` in its crate, with each example in a `.txt` file and
+/// the answers in `MANIFEST`.
+pub fn examples_dir(day: u8) -> PathBuf {
+    loader::day_dir(day).join("examples")
+}
+
+fn read(path: &Path) -> Result<String, ExampleError> {
+    std::fs::read_to_string(path).map_err(|source| ExampleError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// One line of a manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry<'a> {
+    pub line: usize,
+    pub name: &'a str,
+    pub part: u8,
+    pub answer: &'a str,
+}
+
+/// Parses a manifest: a line `<example> <part> <answer>` for each answer, ignoring blank lines
+/// and those starting with `#`. Fails with the number of the first bad line and what's wrong.
+pub fn parse_manifest(text: &str) -> Result<Vec<Entry<'_>>, (usize, String)> {
+    let mut result = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(3, char::is_whitespace);
+        let (Some(name), Some(part), Some(answer)) = (fields.next(), fields.next(), fields.next())
+        else {
+            return Err((i + 1, "expected `<example> <part> <answer>`".to_string()));
+        };
+        let part = part
+            .parse()
+            .map_err(|e| (i + 1, format!("could not parse part {part:?}: {e}")))?;
+        result.push(Entry {
+            line: i + 1,
+            name,
+            part,
+            answer: answer.trim(),
+        });
+    }
+    Ok(result)
+}
+
+/// Every example answer for `day`, in the order of its manifest; none if the day has no
+/// examples directory. Every example file must have an answer in the manifest, so that one can't
+/// be added and then silently never run.
+pub fn load(day: u8) -> Result<Vec<Example>, ExampleError> {
+    let dir = examples_dir(day);
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let manifest_path = dir.join(MANIFEST);
+    let manifest = if manifest_path.is_file() {
+        read(&manifest_path)?
+    } else {
+        String::new()
+    };
+    let entries = parse_manifest(&manifest).map_err(|(line, message)| ExampleError::Manifest {
+        path: manifest_path.clone(),
+        line,
+        message,
+    })?;
+
+    let mut inputs: HashMap<&str, String> = HashMap::new();
+    let mut result = Vec::with_capacity(entries.len());
+    for Entry {
+        line,
+        name,
+        part,
+        answer,
+    } in entries
+    {
+        if !run::parts(day).contains(&part) {
+            return Err(ExampleError::Manifest {
+                path: manifest_path,
+                line,
+                message: format!("day {day} has no part {part}"),
+            });
+        }
+        let input = match inputs.get(name) {
+            Some(input) => input.clone(),
+            None => {
+                let input = read(&dir.join(format!("{name}.txt")))?;
+                inputs.insert(name, input.clone());
+                input
+            }
+        };
+        result.push(Example {
+            day,
+            name: name.to_string(),
+            part,
+            input,
+            expected: answer.to_string(),
+        });
+    }
+
+    let listing = std::fs::read_dir(&dir).map_err(|source| ExampleError::Io {
+        path: dir.clone(),
+        source,
+    })?;
+    for entry in listing {
+        let path = entry
+            .map_err(|source| ExampleError::Io {
+                path: dir.clone(),
+                source,
+            })?
+            .path();
+        let is_example = path.extension().is_some_and(|e| e == "txt")
+            && path.file_name().is_some_and(|f| f != MANIFEST);
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        if is_example && !inputs.contains_key(stem) {
+            return Err(ExampleError::Unlisted { path });
+        }
+    }
+    Ok(result)
+}
+
+/// Solve the example through the day's `Solution`, as `aoc run` would, failing with a message
+/// if the answer is wrong.
+pub fn check(example: &Example) -> Result<(), String> {
+    verify::check(example.day, example.part, &example.input, &example.expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = "# The larger examples\nlarger-1 1 159\n\n  larger-1 2   610 \n";
+        let entries: Vec<_> = parse_manifest(manifest)
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.line, e.name, e.part, e.answer))
+            .collect();
+        assert_eq!(
+            entries,
+            [(2, "larger-1", 1, "159"), (4, "larger-1", 2, "610")]
+        );
+        assert_eq!(
+            parse_manifest("a 1 2\nb 1").unwrap_err(),
+            (2, "expected `<example> <part> <answer>`".to_string())
+        );
+        assert!(parse_manifest("a one 2").is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        let mut checked = 0;
+        let mut failures = vec![];
+        for &day in run::DAYS {
+            for example in load(day).unwrap() {
+                if let Err(e) = check(&example) {
+                    failures.push(format!(
+                        "day {day} part {} {}: {e}",
+                        example.part, example.name
+                    ));
+                }
+                checked += 1;
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        assert!(checked > 0);
+    }
+}
@@ -0,0 +1,75 @@
+use std::io;
+use std::path::PathBuf;
+
+use loader::loader;
+use thiserror::Error;
+
+/// The environment variable holding the value of the adventofcode.com `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error("there is no puzzle for day {0}")]
+    UnknownDay(u8),
+    #[error("set {SESSION_VAR} to the value of your adventofcode.com session cookie")]
+    NoSession,
+    #[error("could not download the input: {0}")]
+    Download(String),
+    #[error("could not write {}: {source}", path.display())]
+    Cache { path: PathBuf, source: io::Error },
+}
+
+/// Where the downloaded input for `day` is kept.
+pub fn cached_path(day: u8) -> PathBuf {
+    loader::cache_dir().join(format!("day_{day}.txt"))
+}
+
+/// The puzzle input for `day`, downloaded with the given session cookie.
+#[cfg(feature = "fetch")]
+pub fn download(day: u8, session: &str) -> Result<String, FetchError> {
+    let url = format!("https://adventofcode.com/2019/day/{day}/input");
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", "github.com/Smaug123/advent-of-code-2019")
+        .call()
+        .map_err(|e| FetchError::Download(e.to_string()))?;
+    response
+        .into_string()
+        .map_err(|e| FetchError::Download(e.to_string()))
+}
+
+/// The path to the input for `day`, downloading it into the cache with the session from
+/// `AOC_SESSION` unless it's already there.
+#[cfg(feature = "fetch")]
+pub fn fetch(day: u8) -> Result<PathBuf, FetchError> {
+    if !(1..=25).contains(&day) {
+        return Err(FetchError::UnknownDay(day));
+    }
+    let path = cached_path(day);
+    if path.exists() {
+        return Ok(path);
+    }
+    let session = std::env::var(SESSION_VAR).map_err(|_| FetchError::NoSession)?;
+    let input = download(day, session.trim())?;
+    let cache = |source| FetchError::Cache {
+        path: path.clone(),
+        source,
+    };
+    std::fs::create_dir_all(loader::cache_dir()).map_err(cache)?;
+    std::fs::write(&path, input).map_err(cache)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_path() {
+        assert!(cached_path(13).ends_with("inputs/day_13.txt"));
+        assert_eq!(
+            cached_path(13).parent(),
+            Some(loader::cache_dir().as_path())
+        );
+    }
+}
@@ -0,0 +1,11 @@
+pub mod all;
+pub mod alloc;
+pub mod bench;
+pub mod budget;
+pub mod examples;
+pub mod fetch;
+pub mod run;
+#[cfg(feature = "serve")]
+pub mod serve;
+pub mod table;
+pub mod verify;
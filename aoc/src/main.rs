@@ -0,0 +1,371 @@
+use aoc::table;
+use aoc::{all, bench, examples, run, verify};
+use solution::solution::Report;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const USAGE: &str =
+    "Usage: aoc run --day <day> [--part <part>] [--input <path>] [--alloc-stats], aoc explain (as for run), aoc all [--timeout <seconds>], aoc bench [--day <day>] [--save <name>] [--compare <name>] [--threshold <percent>] [--budget <seconds>], aoc examples [--day <day>], aoc verify [--day <day>], aoc fetch --day <day>, or aoc serve [--address <host:port>]; add --verbose for debug logging";
+
+struct RunArgs {
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    alloc_stats: bool,
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: aoc::alloc::Counting = aoc::alloc::Counting::new();
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut alloc_stats = false;
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        if flag == "--alloc-stats" {
+            alloc_stats = true;
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Expected a value after {flag}"))?;
+        match flag.as_str() {
+            "--day" => {
+                day = Some(
+                    value
+                        .parse()
+                        .map_err(|e| format!("Could not parse day {value} : {e}"))?,
+                )
+            }
+            "--part" => {
+                part = Some(
+                    value
+                        .parse()
+                        .map_err(|e| format!("Could not parse part {value} : {e}"))?,
+                )
+            }
+            "--input" => input = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unrecognised argument {flag}")),
+        }
+    }
+    let day = day.ok_or_else(|| "Required a --day".to_string())?;
+    Ok(RunArgs {
+        day,
+        part,
+        input,
+        alloc_stats,
+    })
+}
+
+fn read_input(args: &RunArgs) -> Result<String, String> {
+    let path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| run::default_input_path(args.day));
+    fs::read_to_string(&path)
+        .map_err(|e| format!("Error while accessing path {} : {e}", path.display()))
+}
+
+fn run_day(args: &RunArgs) -> Result<(), String> {
+    print_answers(args, &read_input(args)?)
+}
+
+fn print_answers(args: &RunArgs, input_str: &str) -> Result<(), String> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => run::parts(args.day).to_vec(),
+    };
+    for part in parts {
+        let solve = || run::run(args.day, part, input_str);
+        let answer = if args.alloc_stats {
+            let (answer, stats) = measure_allocations(solve)?;
+            answer.map(|answer| format!("{answer} ({stats})"))
+        } else {
+            solve()
+        };
+        match answer {
+            Ok(answer) => println!("part {part} => {answer}"),
+            Err(e) => return Err(format!("Error in day {} part {part}: {e}", args.day)),
+        }
+    }
+    Ok(())
+}
+
+/// Count what `f` allocates, parsing included, through the counting global allocator.
+#[cfg(feature = "alloc-stats")]
+fn measure_allocations<T>(f: impl FnOnce() -> T) -> Result<(T, aoc::alloc::AllocStats), String> {
+    Ok(ALLOCATOR.measure(f))
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+fn measure_allocations<T>(_: impl FnOnce() -> T) -> Result<(T, aoc::alloc::AllocStats), String> {
+    Err("aoc was built without the `alloc-stats` feature".to_string())
+}
+
+/// Solve the day as `run` does, then print what the day's solution says lies behind its
+/// answers. Notes over several lines, like a board, start on a line of their own.
+fn run_explain(args: &RunArgs) -> Result<(), String> {
+    let input_str = read_input(args)?;
+    print_answers(args, &input_str)?;
+    let notes = run::explain(args.day, &input_str)
+        .map_err(|e| format!("Error explaining day {}: {e}", args.day))?;
+    if notes.is_empty() {
+        println!("day {} has nothing more to explain", args.day);
+    }
+    for note in notes {
+        if note.value.contains('\n') {
+            println!("{}:\n{}", note.name, note.value.trim_end());
+        } else {
+            println!("{}: {}", note.name, note.value);
+        }
+    }
+    Ok(())
+}
+
+/// Run every part of every day against its default input, optionally `--timeout <seconds>` for
+/// each part, printing a table of the results.
+fn run_all(args: &[String]) -> Result<(), String> {
+    let timeout = match args {
+        [] => all::DEFAULT_TIMEOUT,
+        [flag, seconds] if flag == "--timeout" => seconds
+            .parse()
+            .map_err(|e| format!("Could not parse timeout {seconds} : {e}"))
+            .and_then(|seconds| {
+                Duration::try_from_secs_f64(seconds)
+                    .map_err(|e| format!("Invalid timeout {seconds} : {e}"))
+            })?,
+        _ => return Err("Expected `aoc all [--timeout <seconds>]`".to_string()),
+    };
+    let rows = all::run_all(all::every_part(), timeout);
+    println!("{}", table::render(&rows));
+
+    let failures = rows.iter().filter(|row| row.outcome.is_err()).count();
+    if failures > 0 {
+        return Err(format!("{failures} of {} parts failed", rows.len()));
+    }
+    Ok(())
+}
+
+struct BenchArgs {
+    day: Option<u8>,
+    save: Option<String>,
+    compare: Option<String>,
+    threshold: f64,
+    budget: Duration,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut result = BenchArgs {
+        day: None,
+        save: None,
+        compare: None,
+        threshold: bench::DEFAULT_THRESHOLD,
+        budget: bench::DEFAULT_BUDGET,
+    };
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Expected a value after {flag}"))?;
+        match flag.as_str() {
+            "--day" => {
+                result.day = Some(
+                    value
+                        .parse()
+                        .map_err(|e| format!("Could not parse day {value} : {e}"))?,
+                )
+            }
+            "--save" => result.save = Some(value.clone()),
+            "--compare" => result.compare = Some(value.clone()),
+            "--threshold" => {
+                let percent: f64 = value
+                    .parse()
+                    .map_err(|e| format!("Could not parse threshold {value} : {e}"))?;
+                result.threshold = percent / 100.0;
+            }
+            "--budget" => {
+                result.budget = value
+                    .parse()
+                    .map_err(|e| format!("Could not parse budget {value} : {e}"))
+                    .and_then(|seconds| {
+                        Duration::try_from_secs_f64(seconds)
+                            .map_err(|e| format!("Invalid budget {seconds} : {e}"))
+                    })?
+            }
+            _ => return Err(format!("Unrecognised argument {flag}")),
+        }
+    }
+    Ok(result)
+}
+
+/// Time every part (or every part of `--day`) against its default input, comparing the times
+/// with the baseline saved as `--compare <name>` and then saving them as `--save <name>`. Fails
+/// if any part is slower than its baseline by more than the threshold.
+fn run_bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = match &args.compare {
+        Some(name) => Some(bench::load(name).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let jobs: Vec<_> = all::every_part()
+        .into_iter()
+        .filter(|job| args.day.is_none_or(|day| job.day == day))
+        .collect();
+    let (current, failures) = bench::measure_all(&jobs, args.budget);
+    let comparisons = bench::compare(&baseline.unwrap_or_default(), &current);
+    print!("{}", bench::render(&comparisons, args.threshold));
+    for (day, part, e) in &failures {
+        println!("{day:>3}  {part:>4}  ERROR: {e}");
+    }
+
+    if let Some(name) = &args.save {
+        let path = bench::save(name, &current).map_err(|e| e.to_string())?;
+        println!("saved baseline {name} => {}", path.display());
+    }
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.is_regression(args.threshold))
+        .count();
+    if regressions > 0 {
+        return Err(format!(
+            "{regressions} of {} parts were more than {}% slower than the baseline",
+            comparisons.len(),
+            args.threshold * 100.0
+        ));
+    }
+    Ok(())
+}
+
+/// Solve every example (or every example for `--day`), printing each result. Fails if any
+/// example gives the wrong answer.
+fn run_examples(args: &[String]) -> Result<(), String> {
+    let days = match args {
+        [] => run::DAYS.to_vec(),
+        [flag, day] if flag == "--day" => vec![day
+            .parse()
+            .map_err(|e| format!("Could not parse day {day} : {e}"))?],
+        _ => return Err("Expected `aoc examples [--day <day>]`".to_string()),
+    };
+    let mut total = 0;
+    let mut failures = 0;
+    for day in days {
+        for example in examples::load(day).map_err(|e| e.to_string())? {
+            total += 1;
+            let result = match examples::check(&example) {
+                Ok(()) => "ok".to_string(),
+                Err(e) => {
+                    failures += 1;
+                    format!("FAILED: {e}")
+                }
+            };
+            println!(
+                "day {day} part {} {} => {result}",
+                example.part, example.name
+            );
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{failures} of {total} examples failed"));
+    }
+    Ok(())
+}
+
+/// Check every day (or just `--day`) against the answers in `answers.toml`, printing each
+/// result. Days without an input are skipped. Fails if any answer is wrong.
+fn run_verify(args: &[String]) -> Result<(), String> {
+    let days = match args {
+        [] => run::DAYS.to_vec(),
+        [flag, day] if flag == "--day" => vec![day
+            .parse()
+            .map_err(|e| format!("Could not parse day {day} : {e}"))?],
+        _ => return Err("Expected `aoc verify [--day <day>]`".to_string()),
+    };
+    let answers = verify::load().map_err(|e| e.to_string())?;
+    let mut total = 0;
+    let mut failures = 0;
+    for day in days {
+        if answers.days().binary_search(&day).is_err() {
+            println!("day {day} => no known answers");
+            continue;
+        }
+        let Some(results) = verify::verify_day(&answers, day) else {
+            println!("day {day} => skipped, with no input");
+            continue;
+        };
+        for (part, result) in results {
+            total += 1;
+            match result {
+                Ok(()) => println!("day {day} part {part} => ok"),
+                Err(e) => {
+                    failures += 1;
+                    println!("day {day} part {part} => FAILED: {e}");
+                }
+            }
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{failures} of {total} answers were wrong"));
+    }
+    Ok(())
+}
+
+/// Download the input for a day into the cache, where `run` and `all` will find it.
+#[cfg(feature = "fetch")]
+fn fetch_day(args: &[String]) -> Result<(), String> {
+    let day = match args {
+        [flag, day] if flag == "--day" => day
+            .parse()
+            .map_err(|e| format!("Could not parse day {day} : {e}"))?,
+        _ => return Err("Expected `aoc fetch --day <day>`".to_string()),
+    };
+    let path = aoc::fetch::fetch(day).map_err(|e| e.to_string())?;
+    println!("day {day} input => {}", path.display());
+    Ok(())
+}
+
+#[cfg(not(feature = "fetch"))]
+fn fetch_day(_: &[String]) -> Result<(), String> {
+    Err("aoc was built without the `fetch` feature".to_string())
+}
+
+/// Answer `POST /solve/<day>[/<part>]` requests on `--address` until killed.
+#[cfg(feature = "serve")]
+fn serve(args: &[String]) -> Result<(), String> {
+    let address = match args {
+        [] => aoc::serve::DEFAULT_ADDRESS,
+        [flag, address] if flag == "--address" => address,
+        _ => return Err("Expected `aoc serve [--address <host:port>]`".to_string()),
+    };
+    let listener = std::net::TcpListener::bind(address)
+        .map_err(|e| format!("Could not listen on {address} : {e}"))?;
+    println!("listening on {address}");
+    aoc::serve::serve(listener).map_err(|e| format!("Stopped serving: {e}"))
+}
+
+#[cfg(not(feature = "serve"))]
+fn serve(_: &[String]) -> Result<(), String> {
+    Err("aoc was built without the `serve` feature".to_string())
+}
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let result = match args.get(1).map(|s| s.as_str()) {
+        Some("run") => run_day(&parse_run_args(&args[2..])?),
+        Some("explain") => run_explain(&parse_run_args(&args[2..])?),
+        Some("all") => run_all(&args[2..]),
+        Some("bench") => run_bench(parse_bench_args(&args[2..])?),
+        Some("examples") => run_examples(&args[2..]),
+        Some("verify") => run_verify(&args[2..]),
+        Some("fetch") => fetch_day(&args[2..]),
+        Some("serve") => serve(&args[2..]),
+        Some(command) => Err(format!(
+            "Unrecognised command {command}; expected `run`, `explain`, `all`, `bench`, `examples`, `verify`, `fetch` or `serve`"
+        )),
+        None => Err(USAGE.to_string()),
+    };
+    Ok(result?)
+}
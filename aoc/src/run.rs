@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use aoc2019::Year2019;
+use loader::loader;
+use solution::solution::{Note, ParseError, Solution, SolveError, Visit, Year};
+use thiserror::Error;
+
+use crate::fetch;
+
+/// The year whose puzzles `aoc` solves. Another year plugs in by implementing `Year` as
+/// `aoc2019::Year2019` does.
+pub type Puzzles = Year2019;
+
+/// The days which have solutions.
+pub const DAYS: &[u8] = Puzzles::DAYS;
+
+#[derive(Error, Debug)]
+pub enum RunError {
+    #[error("there is no solution for day {0}")]
+    UnknownDay(u8),
+    #[error("day {day} has no part {part}")]
+    UnknownPart { day: u8, part: u8 },
+    #[error("could not parse the input: {0}")]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Solve(#[from] SolveError),
+}
+
+/// The parts which `day` has; day 25 has only one.
+pub fn parts(day: u8) -> &'static [u8] {
+    Puzzles::parts(day)
+}
+
+/// Where the input for `day` is found by default; see `loader::candidates`. If there isn't one,
+/// this is where `aoc fetch` would put it.
+pub fn default_input_path(day: u8) -> PathBuf {
+    loader::path(day).unwrap_or_else(|| fetch::cached_path(day))
+}
+
+/// Solving one part with a day's `Solution`.
+struct RunPart<'a> {
+    part: u8,
+    input: &'a str,
+}
+
+impl Visit for RunPart<'_> {
+    type Output = Result<String, RunError>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let parsed = S::parse(self.input)?;
+        let answer = if self.part == 1 {
+            S::part_1(&parsed)
+        } else {
+            S::part_2(&parsed)
+        };
+        Ok(answer?)
+    }
+}
+
+/// Solve one part of one day's puzzle, given the contents of its input file.
+pub fn run(day: u8, part: u8, input: &str) -> Result<String, RunError> {
+    if !DAYS.contains(&day) {
+        return Err(RunError::UnknownDay(day));
+    }
+    if !parts(day).contains(&part) {
+        return Err(RunError::UnknownPart { day, part });
+    }
+
+    Puzzles::visit(day, RunPart { part, input }).ok_or(RunError::UnknownDay(day))?
+}
+
+/// Explaining one day's answers with its `Solution`.
+struct Explain<'a> {
+    input: &'a str,
+}
+
+impl Visit for Explain<'_> {
+    type Output = Result<Vec<Note>, RunError>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let parsed = S::parse(self.input)?;
+        let mut notes: Vec<Note> = vec![];
+        S::explain(&parsed, &mut notes)?;
+        Ok(notes)
+    }
+}
+
+/// What lies behind one day's answers, as its `Solution` explains them; none, for most days.
+pub fn explain(day: u8, input: &str) -> Result<Vec<Note>, RunError> {
+    Puzzles::visit(day, Explain { input }).ok_or(RunError::UnknownDay(day))?
+}
+
+/// Solve one part of one day's puzzle as `run` does, also measuring how long it took
+/// (including parsing the input).
+pub fn run_timed(day: u8, part: u8, input: &str) -> (Result<String, RunError>, Duration) {
+    let start = Instant::now();
+    let answer = run(day, part, input);
+    (answer, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        assert_eq!(run(1, 1, "12\n14\n1969\n100756").unwrap(), "34241");
+        assert_eq!(run(1, 2, "14\n1969").unwrap(), "968");
+        assert_eq!(run(3, 2, "R8,U5,L5,D3\nU7,R6,D4,L4").unwrap(), "30");
+        assert_eq!(run(6, 1, "COM)B\nB)C").unwrap(), "3");
+    }
+
+    #[test]
+    fn test_run_errors() {
+        assert!(matches!(run(24, 1, ""), Err(RunError::UnknownDay(24))));
+        assert!(matches!(
+            run(25, 2, ""),
+            Err(RunError::UnknownPart { day: 25, part: 2 })
+        ));
+        assert!(matches!(run(1, 3, ""), Err(RunError::UnknownPart { .. })));
+        assert_eq!(
+            run(1, 1, "12\nfourteen").unwrap_err().to_string(),
+            "could not parse the input: line 2, column 1: could not parse \"fourteen\": invalid digit found in string"
+        );
+        assert_eq!(run(18, 1, "#####\n#@.a#\n#####").unwrap(), "2");
+    }
+
+    #[test]
+    fn test_explain() {
+        let notes = explain(
+            6,
+            include_str!("../../year2019/day_6/examples/transfers.txt"),
+        )
+        .unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].name, "transfer path");
+        assert_eq!(notes[0].value, "K -> J -> E -> D -> I");
+        assert!(explain(1, "12").unwrap().is_empty());
+        assert!(matches!(explain(24, ""), Err(RunError::UnknownDay(24))));
+        assert!(matches!(explain(6, "COM)YOU"), Err(RunError::Solve(_))));
+    }
+}
@@ -0,0 +1,475 @@
+use std::convert::Infallible;
+use std::io;
+use std::net::TcpListener;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::{TokioIo, TokioTimer};
+use log::{debug, warn};
+use serde::Serialize;
+use tokio::sync::Semaphore;
+
+use crate::all;
+use crate::run::{self, RunError};
+
+/// Where `aoc serve` listens by default.
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:8019";
+
+/// The largest request body `aoc serve` will read; real inputs are well under 100KB.
+pub const MAX_BODY: usize = 16 << 20;
+
+/// How long `aoc serve` waits by default for a client to send its request or read the answer.
+pub const DEFAULT_IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many connections `aoc serve` answers at once by default, and how many solvers it lets run.
+pub const DEFAULT_THREADS: usize = 16;
+
+/// One part's answer, as returned by `POST /solve/<day>/<part>`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Solved {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ns: u64,
+}
+
+/// Every part's answer, as returned by `POST /solve/<day>`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct SolvedDay {
+    pub day: u8,
+    pub parts: Vec<Solved>,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+}
+
+/// An HTTP response: the status code and a JSON body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json<T: Serialize>(status: u16, body: &T) -> Response {
+        // Serialising these plain structs can't fail.
+        let body = serde_json::to_string(body).expect("responses serialise");
+        Response { status, body }
+    }
+
+    fn error(status: u16, error: impl ToString) -> Response {
+        Response::json(
+            status,
+            &Failure {
+                error: error.to_string(),
+            },
+        )
+    }
+}
+
+/// Solves one part, as `run::run_timed` does.
+type Solver = fn(u8, u8, &str) -> (Result<String, RunError>, Duration);
+
+/// Answers requests for solutions over HTTP/1.1 with hyper; see `handle` for what's answered.
+/// A solver which runs too long can't be stopped, so its request is answered with an error while
+/// it carries on in the background. Solvers still count against the limit until they finish, so
+/// a run of slow requests is turned away rather than piling up threads.
+#[derive(Clone)]
+pub struct Server {
+    solve_timeout: Duration,
+    io_timeout: Duration,
+    connections: usize,
+    solvers: usize,
+    /// How many solvers are running, including ones whose requests have been answered.
+    running: Arc<AtomicUsize>,
+    solve: Solver,
+}
+
+impl Default for Server {
+    fn default() -> Server {
+        Server {
+            solve_timeout: all::DEFAULT_TIMEOUT,
+            io_timeout: DEFAULT_IO_TIMEOUT,
+            connections: DEFAULT_THREADS,
+            solvers: DEFAULT_THREADS,
+            running: Arc::new(AtomicUsize::new(0)),
+            solve: run::run_timed,
+        }
+    }
+}
+
+impl Server {
+    /// How long each part may run before its request is answered with a timeout.
+    pub fn with_solve_timeout(mut self, timeout: Duration) -> Self {
+        self.solve_timeout = timeout;
+        self
+    }
+
+    /// How long to wait for a client to send its request or read the answer.
+    pub fn with_io_timeout(mut self, timeout: Duration) -> Self {
+        self.io_timeout = timeout;
+        self
+    }
+
+    /// How many connections to answer at once; any more wait to be accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `connections` is 0.
+    pub fn with_connections(mut self, connections: usize) -> Self {
+        assert!(
+            connections > 0,
+            "a server must answer at least one connection"
+        );
+        self.connections = connections;
+        self
+    }
+
+    /// How many solvers may run at once; requests beyond that are turned away.
+    pub fn with_solvers(mut self, solvers: usize) -> Self {
+        self.solvers = solvers;
+        self
+    }
+
+    /// Solve one part on a thread of its own, turning a panicking solver into an error so that it
+    /// doesn't take the connection down with it, and giving up once the timeout has passed.
+    fn solve(&self, day: u8, part: u8, input: &Arc<str>) -> Result<Solved, Response> {
+        if self.running.fetch_add(1, Ordering::SeqCst) >= self.solvers {
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            return Err(Response::error(
+                503,
+                format!(
+                    "the limit of {} running solvers has been reached",
+                    self.solvers
+                ),
+            ));
+        }
+        let (sender, receiver) = mpsc::channel();
+        let (solve, running, input) = (self.solve, Arc::clone(&self.running), Arc::clone(input));
+        thread::spawn(move || {
+            let result = panic::catch_unwind(|| solve(day, part, &input));
+            running.fetch_sub(1, Ordering::SeqCst);
+            // The receiver is gone if we've already timed out.
+            let _ = sender.send(result);
+        });
+
+        let (answer, elapsed) = match receiver.recv_timeout(self.solve_timeout) {
+            Ok(Ok(solved)) => solved,
+            Ok(Err(_)) | Err(RecvTimeoutError::Disconnected) => {
+                return Err(Response::error(
+                    422,
+                    format!("day {day} part {part} panicked"),
+                ));
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(Response::error(
+                    503,
+                    format!(
+                        "day {day} part {part} timed out after {:?}",
+                        self.solve_timeout
+                    ),
+                ));
+            }
+        };
+        Ok(Solved {
+            day,
+            part,
+            answer: answer.map_err(|e| Response::error(422, e))?,
+            elapsed_ns: elapsed.as_nanos() as u64,
+        })
+    }
+
+    /// Route one request: `POST /solve/<day>/<part>` solves one part of a day against the input
+    /// in the body, and `POST /solve/<day>` solves every part. Anything else is an error, with a
+    /// JSON body saying what went wrong.
+    pub fn handle(&self, method: &str, path: &str, body: &str) -> Response {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let (day, part) = match segments[..] {
+            ["solve", day] => (day, None),
+            ["solve", day, part] => (day, Some(part)),
+            _ => return Response::error(404, format!("no such endpoint {path}")),
+        };
+        if method != "POST" {
+            return Response::error(405, "POST the input to solve it");
+        }
+        let Ok(day) = day.parse::<u8>() else {
+            return Response::error(400, format!("could not parse day {day:?}"));
+        };
+        if !run::DAYS.contains(&day) {
+            return Response::error(404, RunError::UnknownDay(day));
+        }
+        let parts = match part {
+            None => run::parts(day).to_vec(),
+            Some(part) => match part.parse::<u8>() {
+                Ok(part) if run::parts(day).contains(&part) => vec![part],
+                Ok(part) => return Response::error(404, RunError::UnknownPart { day, part }),
+                Err(_) => return Response::error(400, format!("could not parse part {part:?}")),
+            },
+        };
+
+        let input = Arc::from(body);
+        let mut solved = Vec::with_capacity(parts.len());
+        for part in parts {
+            match self.solve(day, part, &input) {
+                Ok(answer) => solved.push(answer),
+                Err(response) => return response,
+            }
+        }
+        if part.is_some() {
+            Response::json(200, &solved[0])
+        } else {
+            Response::json(200, &SolvedDay { day, parts: solved })
+        }
+    }
+
+    /// Answer one request with `handle`, which blocks while it solves, so it's run on a thread
+    /// of its own.
+    async fn respond(&self, request: hyper::Request<Incoming>) -> hyper::Response<Full<Bytes>> {
+        let method = request.method().to_string();
+        let path = request.uri().path().to_string();
+        let response = match self.read_body(request).await {
+            Ok(body) => {
+                let (server, method, path) = (self.clone(), method.clone(), path.clone());
+                tokio::task::spawn_blocking(move || server.handle(&method, &path, &body))
+                    .await
+                    .unwrap_or_else(|_| Response::error(500, "the request's handler panicked"))
+            }
+            Err(response) => response,
+        };
+        debug!("{method} {path} => {}", response.status);
+        hyper::Response::builder()
+            .status(response.status)
+            .header("Content-Type", "application/json")
+            .body(Full::new(Bytes::from(response.body)))
+            // The statuses are all valid, and the header is fixed.
+            .expect("responses are well-formed")
+    }
+
+    /// The body of `request`, as long as it arrives in time, is at most `MAX_BODY` bytes, and
+    /// is UTF-8. hyper takes care of chunked bodies and of `Expect: 100-continue`.
+    async fn read_body(&self, request: hyper::Request<Incoming>) -> Result<String, Response> {
+        let body = Limited::new(request.into_body(), MAX_BODY).collect();
+        let bytes = match tokio::time::timeout(self.io_timeout, body).await {
+            Ok(Ok(body)) => body.to_bytes(),
+            Ok(Err(e)) if e.is::<LengthLimitError>() => {
+                return Err(Response::error(
+                    413,
+                    format!("inputs may be at most {MAX_BODY} bytes"),
+                ));
+            }
+            Ok(Err(e)) => {
+                return Err(Response::error(
+                    400,
+                    format!("could not read the input: {e}"),
+                ))
+            }
+            Err(_) => return Err(Response::error(408, "the input took too long to arrive")),
+        };
+        String::from_utf8(bytes.to_vec()).map_err(|_| Response::error(400, "the input isn't UTF-8"))
+    }
+
+    /// Answer requests on `listener` until it fails, accepting at most `connections` at once.
+    /// Each connection carries one request, whose headers must arrive within the I/O timeout;
+    /// hyper bounds how long they may be.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        listener.set_nonblocking(true)?;
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?;
+        runtime.block_on(async {
+            let listener = tokio::net::TcpListener::from_std(listener)?;
+            let permits = Arc::new(Semaphore::new(self.connections));
+            loop {
+                // Only accept a connection once there's room to answer it.
+                let permit = Arc::clone(&permits)
+                    .acquire_owned()
+                    .await
+                    .expect("the semaphore is never closed");
+                let (stream, _) = listener.accept().await?;
+                let server = self.clone();
+                let service = service_fn(move |request| {
+                    let server = server.clone();
+                    async move { Ok::<_, Infallible>(server.respond(request).await) }
+                });
+                let connection = http1::Builder::new()
+                    .timer(TokioTimer::new())
+                    .header_read_timeout(self.io_timeout)
+                    .keep_alive(false)
+                    .serve_connection(TokioIo::new(stream), service);
+                tokio::spawn(async move {
+                    if let Err(e) = connection.await {
+                        warn!("could not answer a request: {e}");
+                    }
+                    drop(permit);
+                });
+            }
+        })
+    }
+}
+
+/// Answer requests on `listener` until it fails, with the default limits.
+pub fn serve(listener: TcpListener) -> io::Result<()> {
+    Server::default().serve(listener)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    #[test]
+    fn test_handle() {
+        let server = Server::default();
+        let response = server.handle("POST", "/solve/1/2", "14\n1969");
+        assert_eq!(response.status, 200);
+        let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], "968");
+        assert!(json["elapsed_ns"].is_u64());
+
+        let response = server.handle("POST", "/solve/6", "COM)B\nB)YOU\nB)SAN");
+        assert_eq!(response.status, 200);
+        let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(json["parts"][0]["answer"], "5");
+        assert_eq!(json["parts"][1]["answer"], "0");
+    }
+
+    #[test]
+    fn test_handle_errors() {
+        let server = Server::default();
+        let status = |method, path, body| server.handle(method, path, body).status;
+        assert_eq!(status("GET", "/solve/1/1", "12"), 405);
+        assert_eq!(status("POST", "/answer/1/1", "12"), 404);
+        assert_eq!(status("POST", "/solve/24/1", "12"), 404);
+        assert_eq!(status("POST", "/solve/25/2", "12"), 404);
+        assert_eq!(status("POST", "/solve/one/1", "12"), 400);
+        assert_eq!(status("POST", "/solve/1/x", "12"), 400);
+        assert_eq!(
+            server.handle("POST", "/solve/1/1", "twelve"),
+            Response {
+                status: 422,
+                body: r#"{"error":"could not parse the input: line 1, column 1: could not parse \"twelve\": invalid digit found in string"}"#.to_string()
+            }
+        );
+    }
+
+    /// Send `request` to `address`, and read the whole response.
+    fn exchange(address: std::net::SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serve() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || serve(listener));
+
+        let response = exchange(
+            address,
+            "POST /solve/1/1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 2\r\n\r\n12",
+        );
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+        assert!(response.contains(r#""answer":"2""#), "{response}");
+
+        let response = exchange(
+            address,
+            "POST /solve/1/1 HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n1\r\n1\r\n3\r\n969\r\n0\r\n\r\n",
+        );
+        assert!(response.contains(r#""answer":"654""#), "{response}");
+
+        // A client which waits to be told to go on with its body is told straight away.
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /solve/1/1 HTTP/1.1\r\nHost: localhost\r\nExpect: 100-continue\r\ncontent-length: 2\r\n\r\n"
+        )
+        .unwrap();
+        let mut interim = [0; 25];
+        stream.read_exact(&mut interim).unwrap();
+        assert_eq!(&interim, b"HTTP/1.1 100 Continue\r\n\r\n");
+        stream.write_all(b"12").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.contains(r#""answer":"2""#), "{response}");
+
+        // Headers can't go on for ever.
+        let endless = format!(
+            "POST /solve/1/1 HTTP/1.1\r\nX-Padding: {}\r\n\r\n",
+            "a".repeat(1 << 20)
+        );
+        let mut stream = TcpStream::connect(address).unwrap();
+        // The server may hang up before it has read everything.
+        let _ = stream.write_all(endless.as_bytes());
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response);
+        assert!(!response.contains("200 OK"), "{response}");
+    }
+
+    /// Answers after a while, whatever it's asked.
+    fn slow(_: u8, _: u8, _: &str) -> (Result<String, RunError>, Duration) {
+        thread::sleep(Duration::from_millis(500));
+        (Ok("slow".to_string()), Duration::from_millis(500))
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let server = Server {
+            solve: slow,
+            ..Server::default()
+        }
+        .with_solve_timeout(Duration::from_millis(10))
+        .with_solvers(1);
+        let response = server.handle("POST", "/solve/1/1", "12");
+        assert_eq!(
+            response,
+            Response {
+                status: 503,
+                body: r#"{"error":"day 1 part 1 timed out after 10ms"}"#.to_string()
+            }
+        );
+        // The solver which timed out is still running, so there's no room for another.
+        let response = server.handle("POST", "/solve/1/1", "12");
+        assert_eq!(
+            response.body,
+            r#"{"error":"the limit of 1 running solvers has been reached"}"#
+        );
+        let server = server.with_solve_timeout(Duration::from_secs(10));
+        thread::sleep(Duration::from_secs(1));
+        assert_eq!(server.handle("POST", "/solve/1/1", "12").status, 200);
+
+        // A client which never sends its request is hung up on.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = Server::default()
+            .with_io_timeout(Duration::from_millis(10))
+            .with_connections(1);
+        thread::spawn(move || server.serve(listener));
+        let mut silent = TcpStream::connect(address).unwrap();
+        let mut response = String::new();
+        silent.read_to_string(&mut response).unwrap();
+        assert!(!response.contains("200 OK"), "{response}");
+
+        // Nor is one which never sends its body.
+        let response = exchange(
+            address,
+            "POST /solve/1/1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 2\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 408 "), "{response}");
+    }
+}
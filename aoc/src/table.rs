@@ -0,0 +1,97 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+/// One line of the table: the outcome of a part, and how long it took if it ran at all.
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Result<String, String>,
+    pub elapsed: Option<Duration>,
+}
+
+/// A duration in milliseconds to three decimal places, right-aligned to `width`.
+pub fn format_duration(duration: Duration, width: usize) -> String {
+    format!(
+        "{:>width$.3}ms",
+        duration.as_secs_f64() * 1000.0,
+        width = width - 2
+    )
+}
+
+const TIME_WIDTH: usize = 14;
+
+/// The first line of `s`, marked as truncated if there were more, so that each row of the table
+/// stays on one line.
+fn first_line(s: &str) -> String {
+    let mut lines = s.trim().lines();
+    let first = lines.next().unwrap_or("");
+    if lines.next().is_some() {
+        format!("{first} ...")
+    } else {
+        first.to_string()
+    }
+}
+
+/// Lay out the rows as a table, followed by the total time taken.
+pub fn render(rows: &[Row]) -> String {
+    let mut result = String::new();
+    writeln!(result, "day  part  {:>TIME_WIDTH$}  answer", "time").unwrap();
+    for row in rows {
+        let time = match row.elapsed {
+            Some(elapsed) => format_duration(elapsed, TIME_WIDTH),
+            None => format!("{:>TIME_WIDTH$}", "-"),
+        };
+        let answer = match &row.outcome {
+            Ok(answer) => first_line(answer),
+            Err(e) => format!("ERROR: {}", first_line(e)),
+        };
+        writeln!(result, "{:>3}  {:>4}  {time}  {answer}", row.day, row.part).unwrap();
+    }
+    let total: Duration = rows.iter().filter_map(|row| row.elapsed).sum();
+    write!(
+        result,
+        "{:>9}  {}",
+        "total",
+        format_duration(total, TIME_WIDTH)
+    )
+    .unwrap();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let rows = [
+            Row {
+                day: 1,
+                part: 1,
+                outcome: Ok("3301059".to_string()),
+                elapsed: Some(Duration::from_micros(1500)),
+            },
+            Row {
+                day: 25,
+                part: 1,
+                outcome: Err("could not read the droid's output:\n#..#\n".to_string()),
+                elapsed: None,
+            },
+            Row {
+                day: 12,
+                part: 2,
+                outcome: Ok("4686774924".to_string()),
+                elapsed: Some(Duration::from_millis(12345)),
+            },
+        ];
+        assert_eq!(
+            render(&rows),
+            "\
+day  part            time  answer
+  1     1         1.500ms  3301059
+ 25     1               -  ERROR: could not read the droid's output: ...
+ 12     2     12345.000ms  4686774924
+    total     12346.500ms"
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+
+use loader::loader;
+use thiserror::Error;
+
+use crate::run;
+
+/// The file at the root of the workspace holding the accepted answers.
+pub const ANSWERS: &str = "answers.toml";
+
+#[derive(Error, Debug)]
+pub enum VerifyError {
+    #[error("could not read {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{}, line {line}: {message}", path.display())]
+    Parse {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+}
+
+/// The accepted answer to each part, where it's known.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// The days with at least one known answer, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.0.keys().map(|&(day, _)| day).collect();
+        days.dedup();
+        days
+    }
+}
+
+pub fn answers_path() -> PathBuf {
+    loader::workspace_root().join(ANSWERS)
+}
+
+/// Parse the little TOML that the answers file uses: `[day_N]` tables holding `part_N = 123` or
+/// `part_N = "text"`, with `#` comments. Fails with the number of the first bad line and what's
+/// wrong with it.
+pub fn parse(text: &str) -> Result<Answers, (usize, String)> {
+    let mut answers = BTreeMap::new();
+    let mut day = None;
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: String| (i + 1, message);
+        if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let parsed = table
+                .strip_prefix("day_")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| error(format!("expected a table named day_N, not {table:?}")))?;
+            day = Some(parsed);
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `part_N = <answer>`".to_string()))?;
+        let (key, value) = (key.trim(), value.trim());
+        let part = key
+            .strip_prefix("part_")
+            .and_then(|p| p.parse::<u8>().ok())
+            .ok_or_else(|| error(format!("expected a key named part_N, not {key:?}")))?;
+        let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            Some(text) if !text.contains(['"', '\\']) => text.to_string(),
+            Some(_) => return Err(error("escapes aren't supported in answers".to_string())),
+            None if value.parse::<i64>().is_ok() => value.to_string(),
+            None => {
+                return Err(error(format!(
+                    "expected an integer or a string, not {value}"
+                )))
+            }
+        };
+        let day = day.ok_or_else(|| error("expected a [day_N] table first".to_string()))?;
+        if answers.insert((day, part), value).is_some() {
+            return Err(error(format!("day {day} part {part} is answered twice")));
+        }
+    }
+    Ok(Answers(answers))
+}
+
+pub fn load() -> Result<Answers, VerifyError> {
+    let path = answers_path();
+    let text = std::fs::read_to_string(&path).map_err(|source| VerifyError::Io {
+        path: path.clone(),
+        source,
+    })?;
+    parse(&text).map_err(|(line, message)| VerifyError::Parse {
+        path,
+        line,
+        message,
+    })
+}
+
+/// Solve one part through the day's `Solution`, as `aoc run` would, failing with a message if
+/// the answer isn't `expected`.
+pub fn check(day: u8, part: u8, input: &str, expected: &str) -> Result<(), String> {
+    let answer = run::run(day, part, input).map_err(|e| e.to_string())?;
+    if answer.trim() == expected {
+        Ok(())
+    } else {
+        Err(format!("expected {expected}, but got {}", answer.trim()))
+    }
+}
+
+/// Check each of `day`'s known answers against its default input, giving each part checked and
+/// how it went; None if there's no input to check against.
+pub fn verify_day(answers: &Answers, day: u8) -> Option<Vec<(u8, Result<(), String>)>> {
+    let input = loader::load(day)?;
+    Some(
+        run::parts(day)
+            .iter()
+            .filter_map(|&part| {
+                let expected = answers.get(day, part)?;
+                Some((part, check(day, part, &input, expected)))
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = parse(
+            "# Answers\n[day_8]\npart_1 = 2016\npart_2 = \"HZCZU\"\n\n[day_10]\npart_1 = 314",
+        )
+        .unwrap();
+        assert_eq!(answers.get(8, 1), Some("2016"));
+        assert_eq!(answers.get(8, 2), Some("HZCZU"));
+        assert_eq!(answers.get(10, 2), None);
+        assert_eq!(answers.days(), [8, 10]);
+
+        assert_eq!(
+            parse("part_1 = 3").unwrap_err(),
+            (1, "expected a [day_N] table first".to_string())
+        );
+        assert_eq!(parse("[day_1]\npart_1 = 3\npart_1 = 3").unwrap_err().0, 3);
+        assert!(parse("[dag_1]").is_err());
+        assert!(parse("[day_1]\npart_1 = three").is_err());
+        assert!(parse("[day_1]\npart_1 = \"a\\\"b\"").is_err());
+        assert!(parse("[day_1]\npart 1").is_err());
+    }
+
+    #[test]
+    fn test_answers_file() {
+        let answers = load().unwrap();
+        for day in answers.days() {
+            assert!(run::DAYS.contains(&day), "day {day} has no solution");
+            for part in [1, 2, 3] {
+                if answers.get(day, part).is_some() {
+                    assert!(run::parts(day).contains(&part), "day {day} part {part}");
+                }
+            }
+        }
+    }
+
+    fn assert_verified(day: u8) {
+        let answers = load().unwrap();
+        let Some(results) = verify_day(&answers, day) else {
+            return;
+        };
+        for (part, result) in results {
+            if let Err(e) = result {
+                panic!("day {day} part {part}: {e}");
+            }
+        }
+    }
+
+    /// A test for each day, so that each day's failure is reported on its own.
+    macro_rules! verify_days {
+        ($($name:ident => $day:expr),* $(,)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_verified($day);
+                }
+            )*
+
+            #[test]
+            fn test_every_day_verified() {
+                assert_eq!([$($day),*], run::DAYS);
+            }
+        };
+    }
+
+    verify_days!(
+        verify_day_1 => 1,
+        verify_day_2 => 2,
+        verify_day_3 => 3,
+        verify_day_4 => 4,
+        verify_day_5 => 5,
+        verify_day_6 => 6,
+        verify_day_7 => 7,
+        verify_day_8 => 8,
+        verify_day_9 => 9,
+        verify_day_10 => 10,
+        verify_day_11 => 11,
+        verify_day_12 => 12,
+        verify_day_13 => 13,
+        verify_day_14 => 14,
+        verify_day_15 => 15,
+        verify_day_16 => 16,
+        verify_day_17 => 17,
+        verify_day_18 => 18,
+        verify_day_19 => 19,
+        verify_day_20 => 20,
+        verify_day_21 => 21,
+        verify_day_22 => 22,
+        verify_day_23 => 23,
+        verify_day_25 => 25,
+    );
+}
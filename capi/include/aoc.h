@@ -0,0 +1,34 @@
+/* The C interface to the Advent of Code 2019 solutions; see capi/src/capi.rs. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The answer was written to the output buffer. */
+#define AOC_OK 0
+/* Solving failed, and the error's message was written to the output buffer instead. */
+#define AOC_ERROR 1
+/* The output buffer was too small; *out_len is now the size it needs to be. */
+#define AOC_BUFFER_TOO_SMALL 2
+/* One of the pointers was null. */
+#define AOC_NULL_POINTER 3
+
+/*
+ * Solve one part of one day's puzzle, given the contents of its input file as len bytes of
+ * UTF-8 at input_ptr. On entry *out_len is the capacity of out_buf; on return it is the length
+ * of the answer or error message, which is written to out_buf without a terminating NUL.
+ * Returns one of the AOC_ status codes.
+ */
+int32_t aoc_solve(uint8_t day, uint8_t part, const uint8_t *input_ptr, size_t len,
+                  uint8_t *out_buf, size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
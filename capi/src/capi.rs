@@ -0,0 +1,161 @@
+use std::{panic, slice};
+
+use aoc::run;
+
+/// The answer was written to the output buffer.
+pub const AOC_OK: i32 = 0;
+/// Solving failed, and the error's message was written to the output buffer instead.
+pub const AOC_ERROR: i32 = 1;
+/// The output buffer was too small; `*out_len` is now the size it needs to be.
+pub const AOC_BUFFER_TOO_SMALL: i32 = 2;
+/// One of the pointers was null.
+pub const AOC_NULL_POINTER: i32 = 3;
+
+/// Write `text` into the caller's buffer, whose capacity is `*out_len`, and set `*out_len` to
+/// the text's length. Nothing is written if it doesn't fit.
+///
+/// # Safety
+///
+/// As for `aoc_solve`.
+unsafe fn write_out(text: &str, out_buf: *mut u8, out_len: *mut usize) -> bool {
+    let capacity = *out_len;
+    *out_len = text.len();
+    if text.len() > capacity {
+        return false;
+    }
+    if text.is_empty() {
+        return true;
+    }
+    slice::from_raw_parts_mut(out_buf, text.len()).copy_from_slice(text.as_bytes());
+    true
+}
+
+/// The status and text to give back for `input`, as answered by `solve`. A panicking solver
+/// is an error rather than something to unwind across the FFI boundary, which would abort the
+/// caller.
+fn answer<F>(day: u8, part: u8, input: &[u8], solve: F) -> (i32, String)
+where
+    F: FnOnce(&str) -> Result<String, run::RunError>,
+{
+    let input = match std::str::from_utf8(input) {
+        Ok(input) => input,
+        Err(e) => return (AOC_ERROR, format!("input is not UTF-8: {e}")),
+    };
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| solve(input))) {
+        Ok(Ok(answer)) => (AOC_OK, answer),
+        Ok(Err(e)) => (AOC_ERROR, e.to_string()),
+        Err(_) => (AOC_ERROR, format!("day {day} part {part} panicked")),
+    }
+}
+
+/// Solve one part of one day's puzzle, given the contents of its input file as `len` bytes of
+/// UTF-8 at `input_ptr`. On entry `*out_len` is the capacity of `out_buf`; on return it is the
+/// length of the answer or error message, which is written to `out_buf` without a terminating
+/// NUL. Returns one of the `AOC_` status codes.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `len` readable bytes, `out_len` to a readable and writable
+/// `usize`, and `out_buf` to `*out_len` writable bytes. None of them may overlap. The input and
+/// the buffer may be null if their lengths are 0, so passing a null buffer with `*out_len` of 0
+/// asks how big the buffer needs to be.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    len: usize,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if out_len.is_null() || (out_buf.is_null() && *out_len > 0) || (input_ptr.is_null() && len > 0)
+    {
+        return AOC_NULL_POINTER;
+    }
+    let input = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input_ptr, len)
+    };
+    let (status, text) = answer(day, part, input, |input| run::run(day, part, input));
+    if write_out(&text, out_buf, out_len) {
+        status
+    } else {
+        AOC_BUFFER_TOO_SMALL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(day: u8, part: u8, input: &[u8], capacity: usize) -> (i32, String, usize) {
+        let mut buf = vec![0; capacity];
+        let mut len = capacity;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                buf.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        let written = String::from_utf8_lossy(&buf[..len.min(capacity)]).into_owned();
+        (status, written, len)
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(1, 1, b"12\n14\n", 16), (AOC_OK, "4".to_string(), 1));
+        assert_eq!(
+            solve(24, 1, b"", 64),
+            (AOC_ERROR, "there is no solution for day 24".to_string(), 31)
+        );
+        assert_eq!(solve(1, 1, b"\xff", 128).0, AOC_ERROR);
+    }
+
+    #[test]
+    fn test_panic() {
+        let (status, text) = answer(16, 1, b"1", |input| panic!("no digits past {input}"));
+        assert_eq!(
+            (status, text.as_str()),
+            (AOC_ERROR, "day 16 part 1 panicked")
+        );
+    }
+
+    #[test]
+    fn test_buffer_too_small() {
+        let (status, _, len) = solve(1, 1, b"1969\n", 2);
+        assert_eq!((status, len), (AOC_BUFFER_TOO_SMALL, 3));
+        assert_eq!(solve(1, 1, b"1969\n", 3), (AOC_OK, "654".to_string(), 3));
+
+        let input = b"1969\n";
+        let mut len = 0;
+        let status = unsafe {
+            aoc_solve(
+                1,
+                1,
+                input.as_ptr(),
+                input.len(),
+                std::ptr::null_mut(),
+                &mut len,
+            )
+        };
+        assert_eq!((status, len), (AOC_BUFFER_TOO_SMALL, 3));
+
+        let mut len = 1;
+        let status = unsafe {
+            aoc_solve(
+                1,
+                1,
+                input.as_ptr(),
+                input.len(),
+                std::ptr::null_mut(),
+                &mut len,
+            )
+        };
+        assert_eq!(status, AOC_NULL_POINTER);
+    }
+}
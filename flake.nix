@@ -25,11 +25,19 @@
           ];
         };
 
-        crates = builtins.attrNames (builtins.readDir self);
+        # The front ends sit at the top, with the framework crates and each year's crates grouped
+        # into directories of their own; every crate is named after its directory. The fuzz
+        # targets are outside the workspace, and are built by cargo-fuzz instead.
+        groups = ["framework" "year2019"];
+        excluded = ["fuzz"];
+        subdirs = dir: builtins.attrNames (pkgs.lib.filterAttrs (_: type: type == "directory") (builtins.readDir dir));
+        crateDirs = dir: builtins.filter (sub: builtins.pathExists (dir + "/${sub}/Cargo.toml")) (subdirs dir);
+        crates =
+          builtins.filter (dir: !(builtins.elem dir excluded)) (crateDirs self)
+          ++ builtins.concatMap (group: crateDirs (self + "/${group}")) groups;
 
         rustConfig = {
           rustVersion = "1.83.0";
-          rootFeatures = map (x: "${x}/no_real_inputs") crates;
           packageFun = import ./Cargo.nix;
           extraRustComponents = ["clippy"];
         };
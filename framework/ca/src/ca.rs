@@ -0,0 +1,488 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+use grid::grid::{Grid, Position, ALL_DIRECTIONS, ORTHOGONAL};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A rectangular grid of cells which are each alive or dead, one bit per cell, row-major.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    row_count: usize,
+    col_count: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A grid of dead cells.
+    pub fn new(row_count: usize, col_count: usize) -> BitGrid {
+        BitGrid {
+            row_count,
+            col_count,
+            words: vec![0; (row_count * col_count).div_ceil(WORD_BITS)],
+        }
+    }
+
+    pub fn from_fn<F>(row_count: usize, col_count: usize, mut alive: F) -> BitGrid
+    where
+        F: FnMut(Position) -> bool,
+    {
+        let mut grid = BitGrid::new(row_count, col_count);
+        for row in 0..row_count {
+            for col in 0..col_count {
+                if alive((row, col)) {
+                    grid.set(row, col, true);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        (row < self.row_count && col < self.col_count).then_some(row * self.col_count + col)
+    }
+
+    /// Whether the cell is alive; cells outside the grid are dead.
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        self.index(row, col)
+            .is_some_and(|i| self.words[i / WORD_BITS] >> (i % WORD_BITS) & 1 == 1)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn set(&mut self, row: usize, col: usize, alive: bool) {
+        let i = self.index(row, col).unwrap_or_else(|| {
+            panic!(
+                "position ({row}, {col}) is outside a grid with {} rows and {} columns",
+                self.row_count, self.col_count
+            )
+        });
+        let bit = 1 << (i % WORD_BITS);
+        if alive {
+            self.words[i / WORD_BITS] |= bit;
+        } else {
+            self.words[i / WORD_BITS] &= !bit;
+        }
+    }
+
+    /// How many cells are alive.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// The live cells, in reading order.
+    pub fn alive(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.row_count)
+            .flat_map(move |row| (0..self.col_count).map(move |col| (row, col)))
+            .filter(|&(row, col)| self.get(row, col))
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::from_fn(self.row_count, self.col_count, |(row, col)| {
+            self.get(row, col)
+        })
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> BitGrid {
+        BitGrid::from_fn(grid.row_count(), grid.col_count(), |pos| grid[pos])
+    }
+}
+
+/// Drawn as the puzzles draw maps: '#' for alive and '.' for dead.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_grid().fmt(f)
+    }
+}
+
+/// Which cells count as a cell's neighbours, in a grid of the given size. Neighbours can be on
+/// other levels of a stack of grids, one inside another: the level is relative to the cell's
+/// own, with positive levels further in.
+pub trait Neighbourhood {
+    /// Whether any neighbours are on other levels. If not, each level evolves on its own, and
+    /// no new levels ever come to life.
+    const RECURSIVE: bool = false;
+
+    /// Calls `visit` with the level and position of each of the cell's neighbours.
+    fn neighbours<F>(&self, row_count: usize, col_count: usize, cell: Position, visit: F)
+    where
+        F: FnMut(i32, Position);
+
+    /// Whether the position holds a cell at all, rather than standing for something else like
+    /// another level.
+    fn is_cell(&self, _row_count: usize, _col_count: usize, _cell: Position) -> bool {
+        true
+    }
+}
+
+fn offset(
+    row_count: usize,
+    col_count: usize,
+    (row, col): Position,
+    (d_row, d_col): (isize, isize),
+) -> Option<Position> {
+    let row = row.checked_add_signed(d_row).filter(|&r| r < row_count)?;
+    let col = col.checked_add_signed(d_col).filter(|&c| c < col_count)?;
+    Some((row, col))
+}
+
+/// The four orthogonally adjacent cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VonNeumann;
+
+impl Neighbourhood for VonNeumann {
+    fn neighbours<F>(&self, row_count: usize, col_count: usize, cell: Position, mut visit: F)
+    where
+        F: FnMut(i32, Position),
+    {
+        for direction in ORTHOGONAL {
+            if let Some(pos) = offset(row_count, col_count, cell, direction) {
+                visit(0, pos);
+            }
+        }
+    }
+}
+
+/// The eight cells around, diagonals included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Moore;
+
+impl Neighbourhood for Moore {
+    fn neighbours<F>(&self, row_count: usize, col_count: usize, cell: Position, mut visit: F)
+    where
+        F: FnMut(i32, Position),
+    {
+        for direction in ALL_DIRECTIONS {
+            if let Some(pos) = offset(row_count, col_count, cell, direction) {
+                visit(0, pos);
+            }
+        }
+    }
+}
+
+/// The four orthogonally adjacent cells on an infinitely recursive grid, as on Eris: the
+/// centre of each grid, which must have odd dimensions, is a whole grid one level in. Stepping
+/// off the edge of a grid lands next to the centre of the level outside, and stepping into the
+/// centre lands on every cell of the facing edge of the level inside.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Recursive;
+
+impl Neighbourhood for Recursive {
+    const RECURSIVE: bool = true;
+
+    fn neighbours<F>(&self, row_count: usize, col_count: usize, cell: Position, mut visit: F)
+    where
+        F: FnMut(i32, Position),
+    {
+        let centre = (row_count / 2, col_count / 2);
+        for direction in ORTHOGONAL {
+            match offset(row_count, col_count, cell, direction) {
+                None => {
+                    let outside = offset(row_count, col_count, centre, direction)
+                        .expect("the centre of a grid is not on its edge");
+                    visit(-1, outside);
+                }
+                Some(pos) if pos == centre => match direction {
+                    (1, 0) => (0..col_count).for_each(|col| visit(1, (0, col))),
+                    (-1, 0) => (0..col_count).for_each(|col| visit(1, (row_count - 1, col))),
+                    (0, 1) => (0..row_count).for_each(|row| visit(1, (row, 0))),
+                    _ => (0..row_count).for_each(|row| visit(1, (row, col_count - 1))),
+                },
+                Some(pos) => visit(0, pos),
+            }
+        }
+    }
+
+    fn is_cell(&self, row_count: usize, col_count: usize, cell: Position) -> bool {
+        cell != (row_count / 2, col_count / 2)
+    }
+}
+
+/// Whether a cell is alive next time, given whether it is alive now and how many of its
+/// neighbours are. Any `Fn(bool, usize) -> bool` is a rule.
+pub trait Rule {
+    fn next(&self, alive: bool, live_neighbours: usize) -> bool;
+}
+
+impl<F> Rule for F
+where
+    F: Fn(bool, usize) -> bool,
+{
+    fn next(&self, alive: bool, live_neighbours: usize) -> bool {
+        self(alive, live_neighbours)
+    }
+}
+
+/// A stack of equally-sized grids, one inside another, as a recursive neighbourhood sees them.
+/// Only the levels from the outermost to the innermost with any live cells are stored.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Levels {
+    row_count: usize,
+    col_count: usize,
+    outermost: i32,
+    grids: VecDeque<BitGrid>,
+}
+
+impl Levels {
+    /// The grid alone at level 0.
+    pub fn new(grid: BitGrid) -> Levels {
+        Levels {
+            row_count: grid.row_count,
+            col_count: grid.col_count,
+            outermost: 0,
+            grids: VecDeque::from([grid]),
+        }
+    }
+
+    pub fn level(&self, level: i32) -> Option<&BitGrid> {
+        usize::try_from(level - self.outermost)
+            .ok()
+            .and_then(|i| self.grids.get(i))
+    }
+
+    fn get(&self, level: i32, (row, col): Position) -> bool {
+        self.level(level).is_some_and(|grid| grid.get(row, col))
+    }
+
+    /// The stored levels, from the outermost in.
+    pub fn levels(&self) -> impl Iterator<Item = (i32, &BitGrid)> {
+        (self.outermost..).zip(self.grids.iter())
+    }
+
+    /// How many cells are alive on every level.
+    pub fn count(&self) -> usize {
+        self.grids.iter().map(BitGrid::count).sum()
+    }
+
+    /// Drops the empty levels at either end, but always keeps one.
+    fn trim(mut self) -> Levels {
+        while self.grids.len() > 1 && self.grids.front().is_some_and(|g| g.count() == 0) {
+            self.grids.pop_front();
+            self.outermost += 1;
+        }
+        while self.grids.len() > 1 && self.grids.back().is_some_and(|g| g.count() == 0) {
+            self.grids.pop_back();
+        }
+        self
+    }
+}
+
+/// When the states of an automaton start repeating: the state after `start` steps comes round
+/// again every `period` steps after that.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+    /// The first state to be seen twice.
+    pub state: BitGrid,
+}
+
+/// A synchronous cellular automaton: every cell changes at once, according to the rule, based
+/// on how many of its neighbours are alive.
+#[derive(Clone, Copy, Debug)]
+pub struct Automaton<N, R> {
+    neighbourhood: N,
+    rule: R,
+}
+
+impl<N, R> Automaton<N, R>
+where
+    N: Neighbourhood,
+    R: Rule,
+{
+    pub fn new(neighbourhood: N, rule: R) -> Automaton<N, R> {
+        Automaton {
+            neighbourhood,
+            rule,
+        }
+    }
+
+    /// The next state of one level, given a way to look up any cell on any level.
+    fn next_level<A>(&self, row_count: usize, col_count: usize, level: i32, alive: A) -> BitGrid
+    where
+        A: Fn(i32, Position) -> bool,
+    {
+        BitGrid::from_fn(row_count, col_count, |cell| {
+            if !self.neighbourhood.is_cell(row_count, col_count, cell) {
+                return false;
+            }
+            let mut live_neighbours = 0;
+            self.neighbourhood
+                .neighbours(row_count, col_count, cell, |relative, pos| {
+                    if alive(level + relative, pos) {
+                        live_neighbours += 1;
+                    }
+                });
+            self.rule.next(alive(level, cell), live_neighbours)
+        })
+    }
+
+    /// The grid after one step, taking any other levels to be empty.
+    pub fn step(&self, grid: &BitGrid) -> BitGrid {
+        self.next_level(grid.row_count, grid.col_count, 0, |level, (row, col)| {
+            level == 0 && grid.get(row, col)
+        })
+    }
+
+    /// The grid after the given number of steps.
+    pub fn run(&self, grid: &BitGrid, steps: usize) -> BitGrid {
+        (0..steps).fold(grid.clone(), |grid, _| self.step(&grid))
+    }
+
+    /// Step until some state comes round a second time. Every automaton on a finite grid gets
+    /// there eventually, but it can take up to one step per possible state.
+    pub fn find_cycle(&self, grid: &BitGrid) -> Cycle {
+        let mut seen = HashMap::new();
+        let mut state = grid.clone();
+        for steps in 0.. {
+            if let Some(&start) = seen.get(&state) {
+                return Cycle {
+                    start,
+                    period: steps - start,
+                    state,
+                };
+            }
+            let next = self.step(&state);
+            seen.insert(state, steps);
+            state = next;
+        }
+        unreachable!("the loop only ends by returning")
+    }
+
+    /// Every level after one step. With a recursive neighbourhood, this can bring the levels
+    /// just outside and just inside the stored ones to life.
+    pub fn step_levels(&self, levels: &Levels) -> Levels {
+        let (row_count, col_count) = (levels.row_count, levels.col_count);
+        let grown = if N::RECURSIVE { 1 } else { 0 };
+        let outermost = levels.outermost - grown;
+        let innermost = levels.outermost + levels.grids.len() as i32 - 1 + grown;
+        let grids = (outermost..=innermost)
+            .map(|level| {
+                self.next_level(row_count, col_count, level, |level, pos| {
+                    levels.get(level, pos)
+                })
+            })
+            .collect();
+        Levels {
+            row_count,
+            col_count,
+            outermost,
+            grids,
+        }
+        .trim()
+    }
+
+    /// Every level after the given number of steps.
+    pub fn run_levels(&self, levels: &Levels, steps: usize) -> Levels {
+        (0..steps).fold(levels.clone(), |levels, _| self.step_levels(&levels))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example from day 24 of 2019.
+    const ERIS: &str = "\
+....#
+#..#.
+#..##
+..#..
+#....";
+
+    /// A bug survives only with exactly one bug next to it, and an empty tile becomes infested
+    /// with one or two.
+    fn bugs(alive: bool, neighbours: usize) -> bool {
+        neighbours == 1 || (!alive && neighbours == 2)
+    }
+
+    fn parse(s: &str) -> BitGrid {
+        BitGrid::from(&Grid::parse(s).unwrap())
+    }
+
+    #[test]
+    fn test_bit_grid() {
+        let mut grid = BitGrid::new(9, 9);
+        grid.set(8, 8, true);
+        grid.set(7, 1, true);
+        assert!(grid.get(8, 8));
+        assert!(!grid.get(8, 9));
+        assert_eq!(grid.count(), 2);
+        assert_eq!(grid.alive().collect::<Vec<_>>(), vec![(7, 1), (8, 8)]);
+        grid.set(8, 8, false);
+        assert_eq!(grid.count(), 1);
+
+        let eris = parse(ERIS);
+        assert_eq!(eris.to_string(), format!("{ERIS}\n"));
+    }
+
+    #[test]
+    fn test_steps() {
+        let automaton = Automaton::new(VonNeumann, bugs);
+        let after = parse(
+            "\
+#..#.
+####.
+###.#
+##.##
+.##..",
+        );
+        assert_eq!(automaton.step(&parse(ERIS)), after);
+
+        // A blinker in the game of life.
+        let life = Automaton::new(Moore, |alive, n| n == 3 || (alive && n == 2));
+        let blinker = parse(".....\n..#..\n..#..\n..#..\n.....");
+        let turned = parse(".....\n.....\n.###.\n.....\n.....");
+        assert_eq!(life.step(&blinker), turned);
+        assert_eq!(life.run(&blinker, 2), blinker);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let automaton = Automaton::new(VonNeumann, bugs);
+        let cycle = automaton.find_cycle(&parse(ERIS));
+        assert_eq!(cycle.state, parse(".....\n.....\n.....\n#....\n.#..."));
+        let biodiversity: u32 = cycle
+            .state
+            .alive()
+            .map(|(row, col)| 1 << (row * 5 + col))
+            .sum();
+        assert_eq!(biodiversity, 2129920);
+        assert_eq!(automaton.run(&parse(ERIS), cycle.start), cycle.state);
+        assert_eq!(automaton.run(&cycle.state, cycle.period), cycle.state);
+    }
+
+    #[test]
+    fn test_recursive() {
+        let automaton = Automaton::new(Recursive, bugs);
+        let levels = automaton.run_levels(&Levels::new(parse(ERIS)), 10);
+        assert_eq!(levels.count(), 99);
+        assert_eq!(levels.levels().count(), 11);
+        assert_eq!(
+            levels.level(-5).unwrap().to_string(),
+            "..#..\n.#.#.\n....#\n.#.#.\n..#..\n"
+        );
+        assert_eq!(
+            levels.level(5).unwrap().to_string(),
+            "####.\n#..#.\n#..#.\n####.\n.....\n"
+        );
+        assert_eq!(levels.level(6), None);
+
+        // Flat neighbourhoods never leave level 0.
+        let flat = Automaton::new(VonNeumann, bugs);
+        let levels = flat.run_levels(&Levels::new(parse(ERIS)), 3);
+        assert_eq!(levels.levels().count(), 1);
+        assert_eq!(levels.level(0), Some(&flat.run(&parse(ERIS), 3)));
+    }
+}
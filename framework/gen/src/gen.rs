@@ -0,0 +1,252 @@
+use std::fmt::Write;
+
+use strategies::strategies::{body, format_digits, format_orbits, format_wire};
+
+/// The days which have a generator.
+pub const DAYS: [u8; 3] = [3, 6, 16];
+
+/// The size `generate` makes each day's input unless told otherwise: the number of steps along
+/// each of day 3's wires, of bodies in day 6's orbit tree, and of digits in day 16's signal.
+pub fn default_size(day: u8) -> Option<usize> {
+    match day {
+        3 => Some(1_000_000),
+        6 => Some(100_000),
+        16 => Some(1_000_000),
+        _ => None,
+    }
+}
+
+/// A generated puzzle input, with the answers to its parts where they're known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// SplitMix64: not much of a random number generator, but plenty to make up puzzle inputs, and
+/// the same seed always gives the same input.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+/// A wire wandering from the origin for `steps` steps, staying on one side of the x-axis: above
+/// it if `up`, below otherwise. Returns the moves and where the wire ends up.
+fn wander(rng: &mut Rng, steps: u32, up: bool) -> (Vec<(char, u32)>, (i64, i64)) {
+    let (away, towards) = if up { ('U', 'D') } else { ('D', 'U') };
+    let mut moves = vec![(away, 1)];
+    let (mut x, mut y): (i64, i64) = (0, if up { 1 } else { -1 });
+    let mut remaining = steps.saturating_sub(1);
+    while remaining > 0 {
+        let mut direction = ['U', 'D', 'L', 'R'][rng.below(4) as usize];
+        let mut distance = 1 + rng.below(u64::from(remaining.min(100))) as u32;
+        if direction == towards {
+            // Stop short of the axis, or go the other way if already next to it.
+            let room = y.unsigned_abs() as u32 - 1;
+            if room == 0 {
+                direction = away;
+            } else {
+                distance = distance.min(room);
+            }
+        }
+        match direction {
+            'U' => y += i64::from(distance),
+            'D' => y -= i64::from(distance),
+            'L' => x -= i64::from(distance),
+            _ => x += i64::from(distance),
+        }
+        moves.push((direction, distance));
+        remaining -= distance;
+    }
+    (moves, (x, y))
+}
+
+/// Day 3: two wires of about `steps` steps each, which cross only once. One wanders above the
+/// x-axis and the other below it; then the first drops down to the right of everything, where
+/// the second comes across to meet it.
+pub fn wires(steps: u32, seed: u64) -> Generated {
+    let mut rng = Rng::new(seed);
+    let (mut first, (x1, y1)) = wander(&mut rng, steps, true);
+    let (mut second, (x2, y2)) = wander(&mut rng, steps, false);
+
+    let extent = |wire: &[(char, u32)], horizontal: bool| {
+        let (mut position, mut furthest) = (0i64, 0);
+        for &(direction, distance) in wire {
+            match (direction, horizontal) {
+                ('R', true) | ('U', false) => position += i64::from(distance),
+                ('L', true) | ('D', false) => position -= i64::from(distance),
+                _ => {}
+            }
+            furthest = furthest.max(position.abs());
+        }
+        furthest
+    };
+    // The crossing is at (right, -depth), beyond anywhere either wire has been.
+    let right = 1 + extent(&first, true).max(extent(&second, true));
+    let depth = 1 + extent(&first, false).max(extent(&second, false));
+
+    first.push(('R', (right - x1) as u32));
+    first.push(('D', (y1 + depth + 1) as u32));
+    second.push(('D', (depth + y2) as u32));
+    second.push(('R', (right + 1 - x2) as u32));
+
+    let length = |wire: &[(char, u32)]| wire.iter().map(|&(_, d)| i64::from(d)).sum::<i64>();
+    let first_steps = length(&first) - 1;
+    let second_steps = length(&second) - 1;
+
+    Generated {
+        input: format!("{}\n{}\n", format_wire(&first), format_wire(&second)),
+        part_1: Some((right + depth).to_string()),
+        part_2: Some((first_steps + second_steps).to_string()),
+    }
+}
+
+/// Day 6: an orbit tree of `bodies` bodies besides COM, plus YOU and SAN. Each body mostly
+/// orbits the one named just before it, so the tree is deep like a real input's; now and then it
+/// branches off somewhere earlier instead. The orbits come in a random order.
+pub fn orbits(bodies: usize, seed: u64) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut parents = vec![0; bodies + 1];
+    let mut depths = vec![0u64; bodies + 1];
+    for i in 1..=bodies {
+        parents[i] = if rng.below(16) == 0 {
+            rng.below(i as u64) as usize
+        } else {
+            i - 1
+        };
+        depths[i] = depths[parents[i]] + 1;
+    }
+    let you = rng.below(bodies as u64 + 1) as usize;
+    let san = rng.below(bodies as u64 + 1) as usize;
+
+    let (mut a, mut b) = (you, san);
+    while a != b {
+        if depths[a] < depths[b] {
+            b = parents[b];
+        } else {
+            a = parents[a];
+        }
+    }
+    let transfers = depths[you] + depths[san] - 2 * depths[a];
+
+    let mut edges: Vec<(String, String)> = (1..=bodies)
+        .map(|i| (body(parents[i]), body(i)))
+        .chain([
+            (body(you), "YOU".to_string()),
+            (body(san), "SAN".to_string()),
+        ])
+        .collect();
+    for i in (1..edges.len()).rev() {
+        edges.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+
+    Generated {
+        input: format_orbits(&edges),
+        part_1: Some((depths.iter().sum::<u64>() + depths[you] + depths[san] + 2).to_string()),
+        part_2: Some(transfers.to_string()),
+    }
+}
+
+/// Day 16: a signal of `digits` random digits. Its answers aren't known, since working them out
+/// is the very thing being measured; and part 2 has no answer once the signal is too long for its
+/// first seven digits to reach into the second half of the repeated signal.
+pub fn signal(digits: usize, seed: u64) -> Generated {
+    let mut rng = Rng::new(seed);
+    let signal: Vec<u8> = (0..digits).map(|_| rng.below(10) as u8).collect();
+    let mut input = format_digits(&signal);
+    input.push('\n');
+    Generated {
+        input,
+        part_1: None,
+        part_2: None,
+    }
+}
+
+/// An input of the given size for one of the `DAYS`, or None if the day has no generator.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<Generated> {
+    match day {
+        3 => Some(wires(size.try_into().unwrap_or(u32::MAX), seed)),
+        6 => Some(orbits(size, seed)),
+        16 => Some(signal(size, seed)),
+        _ => None,
+    }
+}
+
+/// The known answers, as the day binaries would print them.
+pub fn format_answers(generated: &Generated) -> String {
+    let mut result = String::new();
+    for (part, answer) in [(1, &generated.part_1), (2, &generated.part_2)] {
+        if let Some(answer) = answer {
+            // Writing to a String can't fail.
+            let _ = writeln!(result, "part {part} => {answer}");
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wires() {
+        for seed in 0..20 {
+            let generated = wires(2_000, seed);
+            let (first, second) = day_3::day_3::input(&generated.input).unwrap();
+            assert_eq!(
+                Some(day_3::day_3::part_1(&first, &second).to_string()),
+                generated.part_1
+            );
+            assert_eq!(
+                Some(day_3::day_3::part_2(&first, &second).to_string()),
+                generated.part_2
+            );
+        }
+    }
+
+    #[test]
+    fn test_orbits() {
+        for seed in 0..20 {
+            let generated = orbits(500, seed);
+            let edges = day_6::day_6::input(&generated.input).unwrap();
+            assert_eq!(edges.len(), 502);
+            assert_eq!(
+                Some(day_6::day_6::part_1(&edges).unwrap().to_string()),
+                generated.part_1
+            );
+            assert_eq!(
+                Some(day_6::day_6::part_2(&edges).unwrap().to_string()),
+                generated.part_2
+            );
+        }
+    }
+
+    #[test]
+    fn test_signal() {
+        let generated = signal(1_000, 7);
+        assert_eq!(
+            day_16::day_16::input(&generated.input).unwrap().len(),
+            1_000
+        );
+        assert_eq!(generated, generate(16, 1_000, 7).unwrap());
+        assert_eq!(format_answers(&generated), "");
+        assert!(generate(1, 1_000, 7).is_none());
+    }
+}
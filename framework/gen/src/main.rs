@@ -0,0 +1,48 @@
+use gen::gen;
+use solution::solution::Report;
+
+const USAGE: &str = "Usage: gen <day> [<size>] [--seed <seed>]; the input goes to stdout and any known answers to stderr";
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+
+    let mut positional = vec![];
+    let mut seed = 0;
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        if arg == "--seed" {
+            let value = rest.next().ok_or("--seed needs a value")?;
+            seed = value
+                .parse()
+                .map_err(|e| format!("Could not parse seed {value} : {e}"))?;
+        } else {
+            positional.push(arg);
+        }
+    }
+    let day: u8 = match positional.first() {
+        None => return Err(USAGE.into()),
+        Some(day) => day
+            .parse()
+            .map_err(|e| format!("Could not parse day {day} : {e}"))?,
+    };
+    let size = match positional.get(1) {
+        None => gen::default_size(day),
+        Some(size) => Some(
+            size.parse()
+                .map_err(|e| format!("Could not parse size {size} : {e}"))?,
+        ),
+    };
+    let generated = size
+        .and_then(|size| gen::generate(day, size, seed))
+        .ok_or_else(|| {
+            format!(
+                "There is no generator for day {day}; try one of {:?}",
+                gen::DAYS
+            )
+        })?;
+
+    print!("{}", generated.input);
+    eprint!("{}", gen::format_answers(&generated));
+    Ok(())
+}
@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on the plane, with y increasing upwards.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The point one step away in the given direction.
+    pub fn step(self, direction: Direction) -> Point2 {
+        self + direction.offset()
+    }
+
+    /// The four points one step away, in the order of `Direction::ALL`.
+    pub fn neighbours(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(i32, i32)> for Point2 {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl From<Point2> for (i32, i32) {
+    fn from(p: Point2) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+    fn add(self, other: Point2) -> Point2 {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Point2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+    fn sub(self, other: Point2) -> Point2 {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, other: Point2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Point2;
+    fn neg(self) -> Point2 {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point2 {
+    type Output = Point2;
+    fn mul(self, scale: i32) -> Point2 {
+        Point2::new(self.x * scale, self.y * scale)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses the initial of the direction: 'U', 'R', 'D' or 'L'.
+    pub fn parse(c: char) -> Option<Direction> {
+        match c {
+            'U' => Some(Direction::Up),
+            'R' => Some(Direction::Right),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The initial `parse` reads.
+    pub fn initial(self) -> char {
+        match self {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        }
+    }
+
+    pub fn rotate_clockwise(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn rotate_anticlockwise(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate_clockwise().rotate_clockwise()
+    }
+
+    /// The displacement of one step in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, 1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, -1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// Orders nonzero displacements by their angle clockwise from straight up, exactly: that is,
+/// with a cross product rather than with floating-point angles. Displacements in the same
+/// direction compare equal, whatever their lengths.
+///
+/// # Panics
+///
+/// Panics if either displacement is zero, which has no angle.
+pub fn clockwise_from_up(a: Point2, b: Point2) -> Ordering {
+    assert!(
+        a != Point2::ORIGIN && b != Point2::ORIGIN,
+        "the zero displacement has no angle"
+    );
+    // Up and the right half-plane come first; down and the left half-plane second.
+    let half = |p: Point2| !(p.x > 0 || (p.x == 0 && p.y > 0));
+    half(a).cmp(&half(b)).then_with(|| {
+        // Within a half-plane, b is clockwise of a exactly when the cross product is negative.
+        let cross = a.x as i64 * b.y as i64 - a.y as i64 * b.x as i64;
+        cross.cmp(&0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let p = Point2::new(3, -4);
+        assert_eq!(p.manhattan(Point2::ORIGIN), 7);
+        assert_eq!(p.manhattan(Point2::new(-1, 1)), 9);
+        assert_eq!(
+            p + Point2::new(1, 1) - Point2::new(0, 2),
+            Point2::new(4, -5)
+        );
+        assert_eq!(-p * 2, Point2::new(-6, 8));
+        assert_eq!(p.step(Direction::Up), Point2::new(3, -3));
+        assert_eq!(
+            Point2::ORIGIN.neighbours().collect::<Vec<_>>(),
+            vec![
+                Point2::new(0, 1),
+                Point2::new(1, 0),
+                Point2::new(0, -1),
+                Point2::new(-1, 0)
+            ]
+        );
+        assert_eq!(<(i32, i32)>::from(p), (3, -4));
+        assert_eq!(p.to_string(), "(3, -4)");
+    }
+
+    #[test]
+    fn test_directions() {
+        for d in Direction::ALL {
+            assert_eq!(d.rotate_clockwise().rotate_anticlockwise(), d);
+            assert_eq!(d.opposite().offset(), -d.offset());
+            assert_eq!(Direction::parse(d.initial()), Some(d));
+        }
+        assert_eq!(Direction::Left.rotate_clockwise(), Direction::Up);
+        assert_eq!(Direction::parse('D'), Some(Direction::Down));
+        assert_eq!(Direction::parse('N'), None);
+    }
+
+    #[test]
+    fn test_clockwise_from_up() {
+        let sorted = [
+            (0, 1),
+            (1, 3),
+            (1, 1),
+            (3, 1),
+            (1, 0),
+            (2, -1),
+            (1, -2),
+            (0, -1),
+            (-1, -1),
+            (-1, 0),
+            (-5, 1),
+            (-1, 100),
+        ]
+        .map(Point2::from);
+        for (i, &a) in sorted.iter().enumerate() {
+            for (j, &b) in sorted.iter().enumerate() {
+                assert_eq!(clockwise_from_up(a, b), i.cmp(&j), "{a} against {b}");
+            }
+        }
+        assert_eq!(
+            clockwise_from_up(Point2::new(2, -2), Point2::new(1, -1)),
+            Ordering::Equal
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The path from the root of a search tree to `goal`, given each node's parent in the tree.
+/// The root is the node with no parent.
+pub fn reconstruct_path<N>(parents: &HashMap<N, N>, goal: N) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = vec![goal];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Every node reachable from `start`, with the fewest edges needed to reach it.
+/// As with every search here, the graph is given by a function from each node to its
+/// neighbours.
+pub fn bfs_distances<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Entry::Vacant(e) = distances.entry(next.clone()) {
+                e.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// A path with the fewest edges from `start` to a node satisfying `is_goal`, including both
+/// ends, or None if no such node is reachable.
+pub fn bfs<N, F, I, G>(start: N, mut neighbours: F, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start`, in depth-first preorder, visiting each node's
+/// neighbours in the order they are given.
+pub fn dfs<N, F, I>(start: N, mut neighbours: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut order = vec![];
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let mut next: Vec<N> = neighbours(&node)
+            .into_iter()
+            .filter(|n| !seen.contains(n))
+            .collect();
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+    order
+}
+
+/// A cheapest path from `start` to a node satisfying `is_goal`, with its cost; see `astar`.
+pub fn dijkstra<N, C, F, I, G>(start: N, neighbours: F, is_goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash + Ord,
+    C: Copy + Ord + Default + Add<C, Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// The cheapest cost of reaching every node reachable from `start`, where each edge's cost is
+/// nonnegative and `C::default()` is zero.
+pub fn dijkstra_all<N, C, F, I>(start: N, mut neighbours: F) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash + Ord,
+    C: Copy + Ord + Default + Add<C, Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut queue = BinaryHeap::from([Reverse((C::default(), start))]);
+    while let Some(Reverse((cost, node))) = queue.pop() {
+        if best.get(&node).is_some_and(|&c| c < cost) {
+            continue;
+        }
+        for (next, edge) in neighbours(&node) {
+            let next_cost = cost + edge;
+            if best.get(&next).is_none_or(|&c| next_cost < c) {
+                best.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+    best
+}
+
+/// A cheapest path from `start` to a node satisfying `is_goal`, including both ends, with its
+/// cost; or None if no such node is reachable.
+/// Each edge's cost must be nonnegative, where `C::default()` is zero; and `heuristic` must
+/// never overestimate the cost still to go from a node to the nearest goal.
+/// Nodes are ordered only to break ties between equally promising nodes.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash + Ord,
+    C: Copy + Ord + Default + Add<C, Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), start))]);
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if best.get(&node).is_some_and(|&c| c < cost) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((reconstruct_path(&parents, node), cost));
+        }
+        for (next, edge) in neighbours(&node) {
+            let next_cost = cost + edge;
+            if best.get(&next).is_none_or(|&c| next_cost < c) {
+                best.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 5x5 grid with a wall down column 2, except at row 4.
+    fn open_neighbours(&(row, col): &(i32, i32)) -> Vec<(i32, i32)> {
+        [
+            (row - 1, col),
+            (row + 1, col),
+            (row, col - 1),
+            (row, col + 1),
+        ]
+        .into_iter()
+        .filter(|&(r, c)| (0..5).contains(&r) && (0..5).contains(&c) && (c != 2 || r == 4))
+        .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs((0, 0), open_neighbours, |&pos| pos == (0, 4)).unwrap();
+        assert_eq!(path.len() - 1, 12);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[12], (0, 4));
+        assert!(path
+            .windows(2)
+            .all(|w| open_neighbours(&w[0]).contains(&w[1])));
+
+        let distances = bfs_distances((0, 0), open_neighbours);
+        assert_eq!(distances.len(), 25 - 4);
+        assert_eq!(distances[&(0, 4)], 12);
+        assert_eq!(distances.get(&(0, 2)), None);
+
+        assert_eq!(bfs((0, 0), open_neighbours, |&pos| pos == (0, 2)), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let children = |&n: &u32| {
+            if n < 8 {
+                vec![2 * n, 2 * n + 1]
+            } else {
+                vec![]
+            }
+        };
+        assert_eq!(
+            dfs(1, children),
+            vec![1, 2, 4, 8, 9, 5, 10, 11, 3, 6, 12, 13, 7, 14, 15]
+        );
+        // Cycles are only followed once.
+        assert_eq!(dfs(0, |&n: &u32| [(n + 1) % 3]), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Going straight to 3 is dearer than going round by 1 and 2.
+        let edges = |&n: &u8| match n {
+            0 => vec![(1, 1), (3, 10_u32)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        };
+        assert_eq!(dijkstra(0, edges, |&n| n == 3), Some((vec![0, 1, 2, 3], 6)));
+        assert_eq!(dijkstra(1, edges, |&n| n == 0), None);
+        assert_eq!(
+            dijkstra_all(0, edges),
+            HashMap::from([(0, 0), (1, 1), (2, 3), (3, 6)])
+        );
+
+        let unit = |pos: &(i32, i32)| open_neighbours(pos).into_iter().map(|n| (n, 1_u32));
+        let manhattan = |&(r, c): &(i32, i32)| r.abs_diff(0) + c.abs_diff(4);
+        let (path, cost) = astar((0, 0), unit, manhattan, |&pos| pos == (0, 4)).unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+    }
+
+    #[test]
+    fn test_reconstruct_path() {
+        let parents = HashMap::from([('b', 'a'), ('c', 'b'), ('d', 'a')]);
+        assert_eq!(reconstruct_path(&parents, 'c'), vec!['a', 'b', 'c']);
+        assert_eq!(reconstruct_path(&parents, 'a'), vec!['a']);
+    }
+}
@@ -0,0 +1,400 @@
+use std::fmt::{Display, Write};
+use std::ops::{Index, IndexMut};
+
+use thiserror::Error;
+
+/// A (row, col) position in a grid, with rows increasing downwards.
+pub type Position = (usize, usize);
+
+/// The (row, col) offsets to the four orthogonally adjacent cells, clockwise from up.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// The (row, col) offsets to the eight surrounding cells, clockwise from up and to the left.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Something which can be drawn as a single character of a grid.
+pub trait Cell {
+    fn to_char(&self) -> char;
+}
+
+/// Drawn as the puzzles draw maps: '#' for true and '.' for false.
+impl Cell for bool {
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+impl Cell for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("grid was empty")]
+    Empty,
+    #[error("row {row} had length {actual}, but previous rows had length {expected}")]
+    Ragged {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("unexpected character {c:?} at row {row}, column {col}")]
+    InvalidChar { row: usize, col: usize, c: char },
+}
+
+/// A rectangular grid whose dimensions are only known at runtime, stored row-major.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    row_count: usize,
+    col_count: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                f.write_char(cell.to_char())?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        self.get(row, col).unwrap_or_else(|| {
+            panic!(
+                "position ({row}, {col}) is outside a grid with {} rows and {} columns",
+                self.row_count, self.col_count
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        let (row_count, col_count) = (self.row_count, self.col_count);
+        self.get_mut(row, col).unwrap_or_else(|| {
+            panic!(
+                "position ({row}, {col}) is outside a grid with {row_count} rows and {col_count} columns"
+            )
+        })
+    }
+}
+
+impl Grid<bool> {
+    /// Parse a grid of '#' (true) and '.' (false), ignoring surrounding whitespace.
+    pub fn parse(s: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::parse_with(s, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given size with every cell the same.
+    pub fn new(row_count: usize, col_count: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            row_count,
+            col_count,
+            cells: vec![fill; row_count * col_count],
+        }
+    }
+
+    pub fn from_fn<F>(row_count: usize, col_count: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(Position) -> T,
+    {
+        let mut cells = Vec::with_capacity(row_count * col_count);
+        for row in 0..row_count {
+            for col in 0..col_count {
+                cells.push(f((row, col)));
+            }
+        }
+        Grid {
+            row_count,
+            col_count,
+            cells,
+        }
+    }
+
+    /// A grid with the given cells in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not `row_count * col_count` cells.
+    pub fn from_cells(row_count: usize, col_count: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            row_count * col_count,
+            "wrong number of cells for a grid with {row_count} rows and {col_count} columns"
+        );
+        Grid {
+            row_count,
+            col_count,
+            cells,
+        }
+    }
+
+    /// Parse a grid with one character per cell and one line per row, ignoring surrounding
+    /// whitespace; `parse_cell` says what each character means, or None if it is invalid.
+    pub fn parse_with<F>(s: &str, mut parse_cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut col_count = None;
+        let mut row_count = 0;
+        for (row, line) in s.trim().lines().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                cells.push(parse_cell(c).ok_or(ParseError::InvalidChar { row, col, c })?);
+            }
+            let len = line.chars().count();
+            match col_count {
+                None => col_count = Some(len),
+                Some(expected) if expected != len => {
+                    return Err(ParseError::Ragged {
+                        row,
+                        expected,
+                        actual: len,
+                    })
+                }
+                Some(_) => {}
+            }
+            row_count += 1;
+        }
+
+        match col_count {
+            None | Some(0) => Err(ParseError::Empty),
+            Some(col_count) => Ok(Grid {
+                row_count,
+                col_count,
+                cells,
+            }),
+        }
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if col >= self.col_count {
+            return None;
+        }
+        self.cells.get(row * self.col_count + col)
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if col >= self.col_count {
+            return None;
+        }
+        self.cells.get_mut(row * self.col_count + col)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the position is outside the grid.
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self[(row, col)] = value;
+    }
+
+    /// The position at the given signed coordinates, if it is inside the grid.
+    pub fn checked(&self, row: isize, col: isize) -> Option<Position> {
+        let row = usize::try_from(row).ok().filter(|&r| r < self.row_count)?;
+        let col = usize::try_from(col).ok().filter(|&c| c < self.col_count)?;
+        Some((row, col))
+    }
+
+    /// The position offset from `(row, col)` by the given amounts, if it is inside the grid.
+    pub fn offset(&self, (row, col): Position, d_row: isize, d_col: isize) -> Option<Position> {
+        self.checked(
+            (row as isize).checked_add(d_row)?,
+            (col as isize).checked_add(d_col)?,
+        )
+    }
+
+    /// The positions above, right of, below and left of the given one, in that order, leaving
+    /// out those outside the grid.
+    pub fn neighbours_4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&(d_row, d_col)| self.offset(pos, d_row, d_col))
+    }
+
+    /// As `neighbours_4`, but including the diagonals, clockwise from the top left.
+    pub fn neighbours_8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |&(d_row, d_col)| self.offset(pos, d_row, d_col))
+    }
+
+    /// The cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let col_count = self.col_count;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / col_count, i % col_count), cell))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.row_count {
+            return None;
+        }
+        Some(&self.cells[row * self.col_count..(row + 1) * self.col_count])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, but a grid with no columns has no cells either.
+        self.cells.chunks(self.col_count.max(1))
+    }
+
+    /// The cells of the given column, top to bottom; empty if there is no such column.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let skip = if col < self.col_count {
+            col
+        } else {
+            self.cells.len()
+        };
+        self.cells.iter().skip(skip).step_by(self.col_count.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.col_count).map(move |col| self.column(col))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            row_count: self.row_count,
+            col_count: self.col_count,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use strategies::strategies::format_grid;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(".#.\r\n##.\r\n").unwrap();
+        assert_eq!((grid.row_count(), grid.col_count()), (2, 3));
+        assert_eq!(grid.get(1, 0), Some(&true));
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.to_string(), ".#.\n##.\n");
+
+        assert_eq!(
+            Grid::parse(".#\n#..\n").unwrap_err(),
+            ParseError::Ragged {
+                row: 1,
+                expected: 2,
+                actual: 3
+            }
+        );
+        assert_eq!(
+            Grid::parse("..\n.X\n").unwrap_err(),
+            ParseError::InvalidChar {
+                row: 1,
+                col: 1,
+                c: 'X'
+            }
+        );
+        assert_eq!(Grid::parse("\n").unwrap_err(), ParseError::Empty);
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits[(1, 0)], 3);
+        assert_eq!(digits.map(|&d| d % 2 == 0).to_string(), ".#\n.#\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::from_fn(3, 4, |(row, col)| row * 4 + col);
+        assert_eq!(
+            grid.neighbours_4((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (2, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours_4((0, 3)).collect::<Vec<_>>(),
+            vec![(1, 3), (0, 2)]
+        );
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours_8((2, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (1, 1), (2, 1)]
+        );
+        assert_eq!(grid.checked(-1, 0), None);
+        assert_eq!(grid.checked(2, 3), Some((2, 3)));
+        assert_eq!(grid.offset((2, 3), 0, 1), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::from_cells(2, 3, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+        grid.set(1, 1, 'x');
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'x', 'f']]
+        );
+        assert_eq!(grid.row(2), None);
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "bx", "cf"]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'x')));
+        assert_eq!(grid.to_string(), "abc\ndxf\n");
+        assert_eq!(Grid::new(0, 0, false).rows().count(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn display_roundtrips(rows in strategies::strategies::grid(10, 10, &['#', '.', 'O'])) {
+            let text = format_grid(&rows);
+            let grid = Grid::parse_with(&text, Some).unwrap();
+            prop_assert_eq!(grid.row_count(), rows.len());
+            prop_assert_eq!(grid.to_string(), text);
+        }
+    }
+}
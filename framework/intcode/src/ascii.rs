@@ -0,0 +1,175 @@
+use std::{
+    io::{self, Write},
+    mem,
+};
+
+use thiserror::Error;
+
+use crate::intcode::{MachineExecutionError, MachineState, StepIoResult};
+
+#[derive(Error, Debug)]
+pub enum ConsoleError {
+    #[error("program output {0}, which is not an ASCII character")]
+    NotAscii(i64),
+    #[error("the program asked for input without prompting for it, after {0:?}")]
+    Unprompted(String),
+    #[error("the program isn't waiting for input, but was sent {0:?}")]
+    NotWaiting(String),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Machine(#[from] MachineExecutionError),
+}
+
+/// Where a `Console` left the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The program has printed its prompt, and is waiting for a line of input.
+    Prompted,
+    Halted,
+}
+
+/// A program which talks in lines of ASCII, like the droids of days 21 and 25, and which asks
+/// for each line of input with a prompt, like `Command?`.
+/// Everything the program prints is written to a sink a line at a time, and the program is only
+/// given input once it has prompted for it: asking for input anywhere else is an error, except
+/// partway through a line which it's already been sent.
+#[derive(Clone)]
+pub struct Console {
+    machine: MachineState<i64>,
+    prompt: String,
+    /// What the program has printed of the line it's partway through.
+    line: String,
+    /// The last whole line the program printed since it was last given input.
+    last: String,
+    /// Whether the program has prompted for a line of input, and not yet been sent one.
+    prompted: bool,
+}
+
+impl Console {
+    /// A console for `machine`, which prompts for input by ending what it prints with `prompt`.
+    /// Nothing runs until `run`.
+    pub fn new(machine: MachineState<i64>, prompt: &str) -> Console {
+        Console {
+            machine,
+            prompt: prompt.to_string(),
+            line: String::new(),
+            last: String::new(),
+            prompted: false,
+        }
+    }
+
+    /// Whether the program is waiting for a line of input.
+    pub fn is_prompted(&self) -> bool {
+        self.prompted
+    }
+
+    /// Runs the program until it prompts for input or halts, writing what it prints to `sink`.
+    /// The sink is flushed at the prompt, so the prompt is shown before anyone answers it.
+    pub fn run(&mut self, sink: &mut impl Write) -> Result<Status, ConsoleError> {
+        if !self.run_until_input(sink)? {
+            return Ok(Status::Halted);
+        }
+        let printed = if self.line.is_empty() {
+            &self.last
+        } else {
+            &self.line
+        };
+        if !printed.trim_end().ends_with(&self.prompt) {
+            return Err(ConsoleError::Unprompted(printed.clone()));
+        }
+        sink.write_all(self.line.as_bytes())?;
+        sink.flush()?;
+        self.line.clear();
+        self.last.clear();
+        self.prompted = true;
+        Ok(Status::Prompted)
+    }
+
+    /// Types `line` at the prompt, followed by a newline, then runs the program on as `run` does.
+    pub fn send(&mut self, line: &str, sink: &mut impl Write) -> Result<Status, ConsoleError> {
+        if !mem::take(&mut self.prompted) {
+            return Err(ConsoleError::NotWaiting(line.to_string()));
+        }
+        self.machine
+            .push_inputs(line.bytes().chain([b'\n']).map(i64::from));
+        self.run(sink)
+    }
+
+    /// Runs the program until it asks for input which it hasn't been sent, giving true, or halts,
+    /// giving false, writing each line it finishes to `sink`. A halted program's last line is
+    /// written even if it's unfinished.
+    fn run_until_input(&mut self, sink: &mut impl Write) -> Result<bool, ConsoleError> {
+        loop {
+            match self.machine.execute_until_input()? {
+                StepIoResult::Output(c) => {
+                    let c = u8::try_from(c)
+                        .ok()
+                        .filter(u8::is_ascii)
+                        .map(char::from)
+                        .ok_or(ConsoleError::NotAscii(c))?;
+                    self.line.push(c);
+                    if c == '\n' {
+                        sink.write_all(self.line.as_bytes())?;
+                        self.last = mem::take(&mut self.line);
+                    }
+                }
+                StepIoResult::AwaitingInput(_) => return Ok(true),
+                StepIoResult::Terminated => {
+                    sink.write_all(self.line.as_bytes())?;
+                    sink.flush()?;
+                    self.line.clear();
+                    return Ok(false);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Prints `Go?` and echoes a line of input, `times` times, then halts. It prints the prompt
+    /// from 0, echoes from 8 until it's echoed a newline, and counts the lines in 102.
+    fn echo(times: i64) -> MachineState<i64> {
+        let mut program = vec![
+            104, 71, 104, 111, 104, 63, 104, 10, 3, 100, 4, 100, 1008, 100, 10, 101, 1006, 101, 8,
+            1001, 102, 1, 102, 8, 102, 103, 104, 1006, 104, 0, 99,
+        ];
+        program.resize(104, 0);
+        program[103] = times;
+        MachineState::new_with_memory(&program)
+    }
+
+    #[test]
+    fn test_console() {
+        let mut console = Console::new(echo(2), "Go?");
+        let mut sink = vec![];
+        assert!(!console.is_prompted());
+        assert_eq!(console.run(&mut sink).unwrap(), Status::Prompted);
+        assert!(console.is_prompted());
+        assert_eq!(console.send("hello", &mut sink).unwrap(), Status::Prompted);
+        assert_eq!(console.send("bye", &mut sink).unwrap(), Status::Halted);
+        assert_eq!(String::from_utf8(sink).unwrap(), "Go?\nhello\nGo?\nbye\n");
+        assert!(matches!(
+            console.send("again", &mut vec![]),
+            Err(ConsoleError::NotWaiting(line)) if line == "again"
+        ));
+    }
+
+    #[test]
+    fn test_console_errors() {
+        let mut console = Console::new(echo(1), "Command?");
+        assert_eq!(
+            console.run(&mut vec![]).unwrap_err().to_string(),
+            "the program asked for input without prompting for it, after \"Go?\\n\""
+        );
+
+        let mut console = Console::new(MachineState::new_with_memory(&[104, 200, 99]), "?");
+        assert!(matches!(
+            console.run(&mut vec![]),
+            Err(ConsoleError::NotAscii(200))
+        ));
+    }
+}
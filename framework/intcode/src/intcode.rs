@@ -0,0 +1,1809 @@
+use log::trace;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Debug, Display, Formatter},
+    io::{self, Write},
+    ops::{Add, Mul, Range},
+    sync::{Arc, Mutex, PoisonError},
+};
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct MachineState<T> {
+    memory: Vec<T>,
+    sparse_memory: HashMap<usize, T>,
+    pc: usize,
+    relative_base: i32,
+    gas: Option<Gas>,
+    extensions: HashMap<usize, Extension<T>>,
+    devices: Vec<(Range<usize>, SharedDevice<T>)>,
+    tracer: Option<Arc<Mutex<Tracer<T>>>>,
+    inputs: VecDeque<T>,
+    /// Where the machine will store the next input it's given, if its last step asked for one
+    /// which its queue didn't have.
+    awaiting: Option<usize>,
+}
+
+/// Something mapped into a machine's memory with `MachineState::with_device`: reads and writes
+/// of the addresses it's mapped at go to it rather than to memory, as to a screen or a register
+/// of input.
+pub trait Device<T> {
+    /// The value at `offset` from the start of the device's addresses.
+    fn read(&mut self, offset: usize) -> T;
+
+    /// A write of `value` to `offset` from the start of the device's addresses.
+    fn write(&mut self, offset: usize, value: T);
+}
+
+/// A device, shared between the machines it's mapped into and whoever else is watching it.
+pub type SharedDevice<T> = Arc<Mutex<dyn Device<T> + Send>>;
+
+/// What an instruction registered with `MachineState::with_opcode` does. It's given the machine,
+/// with the pc still on the instruction, and the instruction's opcode with its parameter modes.
+/// It must move the pc on itself, with `set_pc`.
+pub type Extension<T> = Arc<
+    dyn Fn(&mut MachineState<T>, usize) -> Result<StepResult<T>, MachineExecutionError>
+        + Send
+        + Sync,
+>;
+
+/// The opcodes of the machine's own instructions, which can't be registered as extensions.
+pub const OPCODES: [usize; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 99];
+
+/// What each instruction costs a metered machine, by the instruction's name; see
+/// `MachineState::meter`. Every instruction costs one gas by default, which counts the steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasCosts {
+    pub add: u64,
+    pub mul: u64,
+    pub input: u64,
+    pub output: u64,
+    pub jump_if_true: u64,
+    pub jump_if_false: u64,
+    pub less_than: u64,
+    pub equals: u64,
+    pub adjust_base: u64,
+    pub halt: u64,
+    /// Any instruction registered with `MachineState::with_opcode`.
+    pub custom: u64,
+}
+
+impl GasCosts {
+    /// Every instruction costing the same.
+    pub const fn uniform(cost: u64) -> GasCosts {
+        GasCosts {
+            add: cost,
+            mul: cost,
+            input: cost,
+            output: cost,
+            jump_if_true: cost,
+            jump_if_false: cost,
+            less_than: cost,
+            equals: cost,
+            adjust_base: cost,
+            halt: cost,
+            custom: cost,
+        }
+    }
+
+    /// The cost of the instruction with this opcode, without its parameter modes.
+    fn of(&self, opcode: usize) -> u64 {
+        match opcode {
+            1 => self.add,
+            2 => self.mul,
+            3 => self.input,
+            4 => self.output,
+            5 => self.jump_if_true,
+            6 => self.jump_if_false,
+            7 => self.less_than,
+            8 => self.equals,
+            9 => self.adjust_base,
+            99 => self.halt,
+            _ => self.custom,
+        }
+    }
+}
+
+impl Default for GasCosts {
+    fn default() -> GasCosts {
+        GasCosts::uniform(1)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Gas {
+    costs: GasCosts,
+    used: u64,
+}
+
+/// Where a machine traced with `MachineState::trace_to` writes its trace.
+struct Tracer<T> {
+    out: Box<dyn Write + Send>,
+    show: fn(&T) -> String,
+    steps: u64,
+    /// The line for the last instruction, if it awaited input: it's finished with the input once
+    /// the input has been stored at the address.
+    awaiting: Option<(String, usize)>,
+}
+
+impl<T> Tracer<T> {
+    fn finish_input(&mut self, line: &str, address: usize, input: Option<&T>) -> io::Result<()> {
+        match input {
+            Some(input) => {
+                let input = (self.show)(input);
+                writeln!(
+                    self.out,
+                    r#"{line},"writes":[{{"address":{address},"value":{input}}}],"input":{input}}}"#
+                )
+            }
+            None => writeln!(self.out, r#"{line},"writes":[]}}"#),
+        }
+    }
+}
+
+/// What an instruction read and wrote as it executed, for its line in a trace.
+struct Recorded<T> {
+    operands: Vec<T>,
+    write: Option<(usize, T)>,
+}
+
+/// The name of each of the machine's own instructions in a trace.
+const fn instruction(opcode: usize) -> &'static str {
+    match opcode {
+        1 => "add",
+        2 => "mul",
+        3 => "input",
+        4 => "output",
+        5 => "jump_if_true",
+        6 => "jump_if_false",
+        7 => "less_than",
+        8 => "equals",
+        9 => "adjust_base",
+        99 => "halt",
+        _ => "custom",
+    }
+}
+
+/// A parsed program, to load into any number of machines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Program<T> {
+    memory: Vec<T>,
+}
+
+impl<T> Program<T> {
+    pub fn new<J>(mem: J) -> Program<T>
+    where
+        J: IntoIterator<Item = T>,
+    {
+        Program {
+            memory: mem.into_iter().collect(),
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.memory
+    }
+
+    /// The program with each of `patches` applied in turn, logging each one at trace level (day
+    /// 2 tries ten thousand). Memory is extended with zeros to reach a patch beyond the end of
+    /// the program.
+    pub fn with_patches(mut self, patches: &[Patch<T>]) -> Program<T>
+    where
+        T: Clone + Debug + Num,
+    {
+        for patch in patches {
+            trace!(
+                "{}: setting {} to {:?}",
+                patch.label,
+                patch.addr,
+                patch.value
+            );
+            if patch.addr >= self.memory.len() {
+                self.memory.resize_with(patch.addr + 1, T::zero);
+            }
+            self.memory[patch.addr] = patch.value.clone();
+        }
+        self
+    }
+}
+
+/// A well-known change to a program before it runs, like setting day 2's noun and verb, with
+/// what it's for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Patch<T> {
+    pub addr: usize,
+    pub value: T,
+    pub label: &'static str,
+}
+
+impl<T> From<Vec<T>> for Program<T> {
+    fn from(memory: Vec<T>) -> Self {
+        Program { memory }
+    }
+}
+
+pub trait Num {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn to_usize(self) -> Option<usize>;
+    fn to_i32(self) -> Option<i32>;
+    fn if_less_then_else(self, other: Self, if_less: Self, if_not_less: Self) -> Self;
+    fn if_eq_then_else(self, other: Self, if_eq: Self, if_neq: Self) -> Self;
+}
+
+impl Num for i32 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn to_usize(self) -> Option<usize> {
+        if self < 0 {
+            None
+        } else {
+            Some(self as usize)
+        }
+    }
+
+    fn to_i32(self) -> Option<i32> {
+        Some(self)
+    }
+
+    fn if_less_then_else(self, other: i32, if_less: i32, if_not_less: i32) -> i32 {
+        if self < other {
+            if_less
+        } else {
+            if_not_less
+        }
+    }
+
+    fn if_eq_then_else(self, other: i32, if_eq: i32, if_neq: i32) -> i32 {
+        if self == other {
+            if_eq
+        } else {
+            if_neq
+        }
+    }
+}
+
+impl Num for i64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn to_usize(self) -> Option<usize> {
+        if self < 0 {
+            None
+        } else {
+            Some(self as usize)
+        }
+    }
+
+    fn to_i32(self) -> Option<i32> {
+        if self < (i32::MIN as i64) || self > (i32::MAX as i64) {
+            None
+        } else {
+            Some(self as i32)
+        }
+    }
+
+    fn if_less_then_else(self, other: Self, if_less: Self, if_not_less: Self) -> Self {
+        if self < other {
+            if_less
+        } else {
+            if_not_less
+        }
+    }
+
+    fn if_eq_then_else(self, other: Self, if_eq: Self, if_neq: Self) -> Self {
+        if self == other {
+            if_eq
+        } else {
+            if_neq
+        }
+    }
+}
+
+impl Num for u64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn to_usize(self) -> Option<usize> {
+        Some(self as usize)
+    }
+
+    fn to_i32(self) -> Option<i32> {
+        if self <= (i32::MAX as u64) {
+            Some(self as i32)
+        } else {
+            None
+        }
+    }
+
+    fn if_less_then_else(self, other: Self, if_less: Self, if_not_less: Self) -> Self {
+        if self < other {
+            if_less
+        } else {
+            if_not_less
+        }
+    }
+
+    fn if_eq_then_else(self, other: Self, if_eq: Self, if_neq: Self) -> Self {
+        if self == other {
+            if_eq
+        } else {
+            if_neq
+        }
+    }
+}
+
+impl Num for usize {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn to_usize(self) -> Option<usize> {
+        Some(self)
+    }
+
+    fn to_i32(self) -> Option<i32> {
+        if self <= (i32::MAX as usize) {
+            Some(self as i32)
+        } else {
+            None
+        }
+    }
+
+    fn if_less_then_else(self, other: Self, if_less: Self, if_not_less: Self) -> Self {
+        if self < other {
+            if_less
+        } else {
+            if_not_less
+        }
+    }
+
+    fn if_eq_then_else(self, other: Self, if_eq: Self, if_neq: Self) -> Self {
+        if self == other {
+            if_eq
+        } else {
+            if_neq
+        }
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Num for num_bigint::BigInt {
+    fn zero() -> Self {
+        num_traits::Zero::zero()
+    }
+
+    fn one() -> Self {
+        num_traits::One::one()
+    }
+
+    fn to_usize(self) -> Option<usize> {
+        num_traits::ToPrimitive::to_usize(&self)
+    }
+
+    fn to_i32(self) -> Option<i32> {
+        num_traits::ToPrimitive::to_i32(&self)
+    }
+
+    fn if_less_then_else(self, other: Self, if_less: Self, if_not_less: Self) -> Self {
+        if self < other {
+            if_less
+        } else {
+            if_not_less
+        }
+    }
+
+    fn if_eq_then_else(self, other: Self, if_eq: Self, if_neq: Self) -> Self {
+        if self == other {
+            if_eq
+        } else {
+            if_neq
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(
+    "attempted to access position {pos} but memory only has length {len} (is_write: {is_write})"
+)]
+pub struct MemoryAccessTooFarError {
+    pos: usize,
+    len: usize,
+    is_write: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum MemoryAccessError {
+    #[error(transparent)]
+    TooFar(#[from] MemoryAccessTooFarError),
+    #[error("attempted to access negative memory index")]
+    Negative,
+    #[error("attempted to apply memory index offset too big to store")]
+    Overflow,
+}
+
+#[derive(Error, Debug)]
+pub enum MachineExecutionError {
+    #[error("instruction {0} at position {1} unrecognised")]
+    BadOpcode(usize, usize),
+    #[error(transparent)]
+    OutOfBounds(#[from] MemoryAccessError),
+    #[error("input requested but no input provided")]
+    NoInput,
+    #[error("invalid parameter mode {0}")]
+    BadParameterMode(usize),
+    #[error("could not write the trace: {0}")]
+    Trace(io::Error),
+}
+
+pub enum StepIoResult<T> {
+    // Machine has terminated.
+    Terminated,
+    // Machine has emitted a single output.
+    Output(T),
+    // Get an input and store it in this location.
+    AwaitingInput(usize),
+}
+
+pub enum StepResult<T> {
+    // Machine has executed an instruction, with no I/O.
+    Stepped,
+    // Machine has executed an instruction and performed output, or is blocked on input, or has finished.
+    Io(StepIoResult<T>),
+}
+
+impl<T> From<StepIoResult<T>> for StepResult<T> {
+    fn from(value: StepIoResult<T>) -> Self {
+        StepResult::Io(value)
+    }
+}
+
+enum ParameterMode {
+    Immediate,
+    Position,
+    Relative,
+}
+
+impl ParameterMode {
+    const fn of_int(u: usize) -> Option<ParameterMode> {
+        match u {
+            0 => Some(ParameterMode::Position),
+            1 => Some(ParameterMode::Immediate),
+            2 => Some(ParameterMode::Relative),
+            _ => None,
+        }
+    }
+}
+
+/// How many cells of memory either side of the pc a machine's `Debug` and `Display` show.
+const WINDOW: usize = 4;
+
+/// The memory around a machine's pc, as `1008 21 8 [1005] 20 22 107 8 ..`, with the pc's cell in
+/// brackets and `..` where there's more memory beyond what's shown.
+struct AroundPc<'a, T>(&'a MachineState<T>);
+
+impl<T> AroundPc<'_, T> {
+    fn fmt_with(
+        &self,
+        f: &mut Formatter<'_>,
+        cell: impl Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        let MachineState { memory, pc, .. } = self.0;
+        let start = pc.saturating_sub(WINDOW).min(memory.len());
+        let end = (pc + WINDOW + 1).min(memory.len());
+        let mut parts = vec![];
+        if start > 0 {
+            parts.push(None);
+        }
+        parts.extend((start..end).map(Some));
+        if end < memory.len() {
+            parts.push(None);
+        }
+        if parts.is_empty() {
+            return f.write_str("(no memory)");
+        }
+        for (n, i) in parts.into_iter().enumerate() {
+            if n > 0 {
+                f.write_str(" ")?;
+            }
+            match i {
+                None => f.write_str("..")?,
+                Some(i) if i == *pc => {
+                    f.write_str("[")?;
+                    cell(&memory[i], f)?;
+                    f.write_str("]")?;
+                }
+                Some(i) => cell(&memory[i], f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> Debug for AroundPc<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, |cell, f| Debug::fmt(cell, f))
+    }
+}
+
+/// A summary of the machine rather than all its memory, which would be a wall of numbers.
+impl<T: Debug> Debug for MachineState<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MachineState")
+            .field("pc", &self.pc)
+            .field("relative_base", &self.relative_base)
+            .field("memory_len", &self.memory.len())
+            .field("sparse_len", &self.sparse_memory.len())
+            .field("around_pc", &AroundPc(self))
+            .finish()
+    }
+}
+
+/// As `pc 3, relative base 0, memory 5 (+1 sparse): 1 2 3 [4] 99`.
+impl<T: Display> Display for MachineState<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pc {}, relative base {}, memory {}",
+            self.pc,
+            self.relative_base,
+            self.memory.len()
+        )?;
+        if !self.sparse_memory.is_empty() {
+            write!(f, " (+{} sparse)", self.sparse_memory.len())?;
+        }
+        f.write_str(": ")?;
+        AroundPc(self).fmt_with(f, |cell, f| Display::fmt(cell, f))
+    }
+}
+
+impl<T> Default for MachineState<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> MachineState<T> {
+    pub fn new() -> MachineState<T> {
+        MachineState {
+            memory: vec![],
+            sparse_memory: HashMap::new(),
+            pc: 0,
+            relative_base: 0,
+            gas: None,
+            extensions: HashMap::new(),
+            devices: vec![],
+            tracer: None,
+            inputs: VecDeque::new(),
+            awaiting: None,
+        }
+    }
+
+    pub fn new_with_memory<J>(mem: &J) -> MachineState<T>
+    where
+        J: IntoIterator<Item = T>,
+        J: Clone,
+    {
+        MachineState {
+            memory: mem.clone().into_iter().collect(),
+            sparse_memory: HashMap::new(),
+            pc: 0,
+            relative_base: 0,
+            gas: None,
+            extensions: HashMap::new(),
+            devices: vec![],
+            tracer: None,
+            inputs: VecDeque::new(),
+            awaiting: None,
+        }
+    }
+
+    pub fn from_program(program: &Program<T>) -> MachineState<T>
+    where
+        T: Clone,
+    {
+        MachineState {
+            memory: program.memory.clone(),
+            sparse_memory: HashMap::new(),
+            pc: 0,
+            relative_base: 0,
+            gas: None,
+            extensions: HashMap::new(),
+            devices: vec![],
+            tracer: None,
+            inputs: VecDeque::new(),
+            awaiting: None,
+        }
+    }
+
+    pub fn reset<J>(&mut self, mem: J)
+    where
+        J: IntoIterator<Item = T> + Clone,
+    {
+        self.pc = 0;
+        self.relative_base = 0;
+        self.memory.clear();
+        self.memory.extend(mem);
+        self.sparse_memory.clear();
+        self.inputs.clear();
+        self.awaiting = None;
+        self.refuel();
+    }
+
+    /// As `reset`, but copying the memory straight from a program which has already been
+    /// parsed, and reusing this machine's buffer where it is big enough.
+    pub fn reset_from_program(&mut self, program: &Program<T>)
+    where
+        T: Clone,
+    {
+        self.pc = 0;
+        self.relative_base = 0;
+        self.memory.clone_from(&program.memory);
+        self.sparse_memory.clear();
+        self.inputs.clear();
+        self.awaiting = None;
+        self.refuel();
+    }
+
+    /// Queue `value` for the machine to read when it next asks for input. The machine reads from
+    /// its queue without stopping, and only gives `AwaitingInput` once the queue is empty; if it
+    /// has just done so, `value` is stored where it asked for it instead, and the machine can
+    /// carry on. Resetting the machine empties the queue.
+    pub fn push_input(&mut self, value: T) {
+        match self.awaiting.take() {
+            Some(location) => self.set_mem_elt(location, value),
+            None => self.inputs.push_back(value),
+        }
+    }
+
+    /// Queue each of `values` in turn, as `push_input` does.
+    pub fn push_inputs<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in values {
+            self.push_input(value);
+        }
+    }
+
+    /// Whether the machine's last step asked for input which it hasn't yet been given.
+    pub fn is_awaiting_input(&self) -> bool {
+        self.awaiting.is_some()
+    }
+
+    /// How many queued inputs the machine has yet to read.
+    pub fn queued_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Charge each instruction the machine executes from now on its cost in `costs`, starting
+    /// from no gas used. A metered machine stays metered when it's reset, with its gas zeroed.
+    pub fn meter(&mut self, costs: GasCosts) {
+        self.gas = Some(Gas { costs, used: 0 });
+    }
+
+    /// The gas used by the instructions executed since metering started, or since the machine
+    /// was last reset; None if the machine isn't metered.
+    pub fn gas_used(&self) -> Option<u64> {
+        self.gas.map(|gas| gas.used)
+    }
+
+    /// Write a line of JSON to `out` for each instruction the machine executes from now on, for
+    /// analysis with tools like jq: the step's index, the pc, the opcode and the instruction's
+    /// name, the operands it read, the writes it made to memory, and any input or output, as in
+    /// `{"step":0,"pc":0,"opcode":1101,"instruction":"add","operands":[2,3],"writes":[{"address":7,"value":5}]}`.
+    /// Values are written as they display, which for integers is a JSON number. An input
+    /// instruction's line is written once the input has been stored, at the next step.
+    ///
+    /// Instructions which fail aren't traced, nor is anything about custom opcodes but their
+    /// name, `custom`. Tracing reads nothing the instruction didn't, so devices see the same
+    /// reads whether or not the machine is traced; an input stored to a device after the machine
+    /// awaited it is traced without its value. A traced machine stays traced when it's reset,
+    /// counting steps on from where it was, and its clones write to the same trace;
+    /// `finish_trace` stops tracing.
+    pub fn trace_to<W>(&mut self, out: W)
+    where
+        T: Display,
+        W: Write + Send + 'static,
+    {
+        self.tracer = Some(Arc::new(Mutex::new(Tracer {
+            out: Box::new(out),
+            show: T::to_string,
+            steps: 0,
+            awaiting: None,
+        })));
+    }
+
+    /// Stop tracing, writing the line for an instruction still awaiting input (with no input),
+    /// and flushing the trace.
+    pub fn finish_trace(&mut self) -> io::Result<()> {
+        let Some(tracer) = self.tracer.take() else {
+            return Ok(());
+        };
+        let mut tracer = tracer.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((line, address)) = tracer.awaiting.take() {
+            tracer.finish_input(&line, address, None)?;
+        }
+        tracer.out.flush()
+    }
+
+    /// The machine, which now also executes instructions with `opcode` by calling `handler`.
+    /// Instructions whose opcode nothing handles still fail with `BadOpcode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `opcode` is one of the machine's own `OPCODES`, or isn't below 100, which would
+    /// leave no room for parameter modes.
+    pub fn with_opcode<F>(mut self, opcode: usize, handler: F) -> MachineState<T>
+    where
+        F: Fn(&mut MachineState<T>, usize) -> Result<StepResult<T>, MachineExecutionError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        assert!(
+            opcode < 100 && !OPCODES.contains(&opcode),
+            "opcode {opcode} can't be an extension"
+        );
+        self.extensions.insert(opcode, Arc::new(handler));
+        self
+    }
+
+    /// The machine, with `device` mapped over the addresses in `range`. The device is shared,
+    /// not copied, when the machine is cloned, and stays mapped when the machine is reset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or overlaps the range of a device already mapped.
+    pub fn with_device<D>(mut self, range: Range<usize>, device: Arc<Mutex<D>>) -> MachineState<T>
+    where
+        D: Device<T> + Send + 'static,
+    {
+        assert!(
+            !range.is_empty()
+                && self
+                    .devices
+                    .iter()
+                    .all(|(r, _)| r.end <= range.start || range.end <= r.start),
+            "a device can't be mapped at {range:?}"
+        );
+        self.devices.push((range, device));
+        self
+    }
+
+    /// The device mapped over address `i`, and how far into its range `i` is.
+    #[cold]
+    fn device_at(&self, i: usize) -> Option<(&SharedDevice<T>, usize)> {
+        self.devices
+            .iter()
+            .find(|(range, _)| range.contains(&i))
+            .map(|(range, device)| (device, i - range.start))
+    }
+
+    /// Where the machine is executing from.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn set_pc(&mut self, pc: usize) {
+        self.pc = pc;
+    }
+
+    /// The value of the `n`th parameter (counting from 1) of the instruction at the pc, which
+    /// has the given opcode, read in the mode the opcode gives it.
+    pub fn param(&self, opcode: usize, n: u32) -> Result<T, MachineExecutionError>
+    where
+        T: Clone + Num,
+    {
+        let mode = ParameterMode::of_int((opcode / 10_usize.pow(n + 1)) % 10)
+            .ok_or(MachineExecutionError::BadParameterMode(opcode))?;
+        Ok(self.read_param(self.pc + n as usize, mode)?)
+    }
+
+    /// The address which the `n`th parameter (counting from 1) of the instruction at the pc,
+    /// which has the given opcode, refers to: as the destination of an add, say.
+    pub fn param_address(&self, opcode: usize, n: u32) -> Result<usize, MachineExecutionError>
+    where
+        T: Clone + Num,
+    {
+        let mode = ParameterMode::of_int((opcode / 10_usize.pow(n + 1)) % 10)
+            .ok_or(MachineExecutionError::BadParameterMode(opcode))?;
+        let param = self.read_mem_elt(self.pc + n as usize);
+        match mode {
+            ParameterMode::Position => Ok(T::to_usize(param).ok_or(MemoryAccessError::Negative)?),
+            ParameterMode::Relative => {
+                let offset = T::to_i32(param).ok_or(MemoryAccessError::Overflow)?;
+                let target = self.relative_base + offset;
+                if target < 0 {
+                    return Err(MemoryAccessError::Negative.into());
+                }
+                Ok(target as usize)
+            }
+            ParameterMode::Immediate => Err(MachineExecutionError::BadParameterMode(opcode)),
+        }
+    }
+
+    fn refuel(&mut self) {
+        if let Some(gas) = &mut self.gas {
+            gas.used = 0;
+        }
+    }
+
+    fn consume_args_2(&self, opcode: usize) -> Result<(T, T), MachineExecutionError>
+    where
+        T: Clone + Num,
+    {
+        if opcode >= 10000 {
+            return Err(MachineExecutionError::BadParameterMode(opcode));
+        }
+        let mode_1 = ParameterMode::of_int((opcode / 100) % 10)
+            .ok_or(MachineExecutionError::BadParameterMode(opcode))?;
+        let mode_2 = ParameterMode::of_int((opcode / 1000) % 10)
+            .ok_or(MachineExecutionError::BadParameterMode(opcode))?;
+        let arg1 = self.read_param(self.pc + 1, mode_1)?;
+        let arg2 = self.read_param(self.pc + 2, mode_2)?;
+        Ok((arg1, arg2))
+    }
+
+    fn consume_args_1(&self, opcode: usize) -> Result<T, MachineExecutionError>
+    where
+        T: Clone + Num,
+    {
+        if opcode >= 1000 {
+            return Err(MachineExecutionError::BadParameterMode(opcode));
+        }
+        let mode = ParameterMode::of_int((opcode / 100) % 10)
+            .ok_or(MachineExecutionError::BadParameterMode(opcode))?;
+        let to_output = self.read_param(self.pc + 1, mode)?;
+        Ok(to_output)
+    }
+
+    fn transform_to_dest<F>(
+        &mut self,
+        opcode: usize,
+        record: &mut Option<Recorded<T>>,
+        f: F,
+    ) -> Result<StepResult<T>, MachineExecutionError>
+    where
+        T: Clone + Num,
+        F: Fn(T, T) -> T,
+    {
+        if opcode >= 100000 {
+            return Err(MachineExecutionError::BadParameterMode(opcode));
+        }
+        let mode_1 = ParameterMode::of_int((opcode / 100) % 10)
+            .ok_or(MachineExecutionError::BadParameterMode(opcode))?;
+        let mode_2 = ParameterMode::of_int((opcode / 1000) % 10)
+            .ok_or(MachineExecutionError::BadParameterMode(opcode))?;
+        let result_pos = self.param_address(opcode, 3)?;
+        let arg1 = self.read_param(self.pc + 1, mode_1)?;
+        let arg2 = self.read_param(self.pc + 2, mode_2)?;
+        if let Some(record) = record {
+            record.operands = vec![arg1.clone(), arg2.clone()];
+        }
+        let result = f(arg1, arg2);
+        if let Some(record) = record {
+            record.write = Some((result_pos, result.clone()));
+        }
+        self.set_mem_elt(result_pos, result);
+        self.pc += 4;
+        Ok(StepResult::Stepped)
+    }
+
+    pub fn one_step(&mut self) -> Result<StepResult<T>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + std::cmp::Ord + Num,
+    {
+        // Whoever ran the machine on has seen to any input it was awaiting.
+        self.awaiting = None;
+        let opcode = self.read_mem_elt(self.pc);
+        let opcode: usize = T::to_usize(opcode).ok_or(MachineExecutionError::OutOfBounds(
+            MemoryAccessError::Negative,
+        ))?;
+        let result = match &self.tracer {
+            None => self.execute(opcode, &mut None),
+            Some(tracer) => self.execute_traced(Arc::clone(tracer), opcode),
+        };
+        if let (Some(gas), Ok(_)) = (&mut self.gas, &result) {
+            gas.used += gas.costs.of(opcode % 100);
+        }
+        result
+    }
+
+    fn execute_traced(
+        &mut self,
+        tracer: Arc<Mutex<Tracer<T>>>,
+        opcode: usize,
+    ) -> Result<StepResult<T>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + std::cmp::Ord + Num,
+    {
+        let lock = || tracer.lock().unwrap_or_else(PoisonError::into_inner);
+        let awaiting = lock().awaiting.take();
+        if let Some((line, address)) = awaiting {
+            // Reading the input back from a device could change what the device does next.
+            let input = match self.device_at(address) {
+                None => Some(self.read_mem_elt(address)),
+                Some(_) => None,
+            };
+            lock()
+                .finish_input(&line, address, input.as_ref())
+                .map_err(MachineExecutionError::Trace)?;
+        }
+
+        let pc = self.pc;
+        let name = instruction(opcode % 100);
+        let mut record = Some(Recorded {
+            operands: vec![],
+            write: None,
+        });
+        let result = self.execute(opcode, &mut record)?;
+        let Recorded { operands, write } = record.expect("execute keeps the record");
+        let mut tracer = lock();
+        let show = tracer.show;
+        let operands: Vec<String> = operands.iter().map(show).collect();
+        let mut line = format!(
+            r#"{{"step":{},"pc":{pc},"opcode":{opcode},"instruction":"{name}","operands":[{}]"#,
+            tracer.steps,
+            operands.join(",")
+        );
+        tracer.steps += 1;
+        match (&result, write) {
+            (StepResult::Io(StepIoResult::AwaitingInput(address)), _) => {
+                tracer.awaiting = Some((line, *address));
+                return Ok(result);
+            }
+            (StepResult::Io(StepIoResult::Output(output)), _) => {
+                line.push_str(&format!(r#","writes":[],"output":{}"#, show(output)));
+            }
+            (StepResult::Stepped, Some((address, input))) if opcode % 100 == 3 => {
+                // The input came from the machine's own queue.
+                tracer
+                    .finish_input(&line, address, Some(&input))
+                    .map_err(MachineExecutionError::Trace)?;
+                return Ok(result);
+            }
+            (_, Some((address, value))) => line.push_str(&format!(
+                r#","writes":[{{"address":{address},"value":{}}}]"#,
+                show(&value)
+            )),
+            (_, None) => line.push_str(r#","writes":[]"#),
+        }
+        writeln!(tracer.out, "{line}}}").map_err(MachineExecutionError::Trace)?;
+        Ok(result)
+    }
+
+    /// Execute the instruction at the pc, noting what it read and wrote in `record` if there is
+    /// one.
+    fn execute(
+        &mut self,
+        opcode: usize,
+        record: &mut Option<Recorded<T>>,
+    ) -> Result<StepResult<T>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + std::cmp::Ord + Num,
+    {
+        match opcode % 100 {
+            1_usize => self.transform_to_dest(opcode, record, |a, b| a + b),
+            2 => self.transform_to_dest(opcode, record, |a, b| a * b),
+            3 => {
+                let location = match opcode {
+                    3 => {
+                        let location = self.read_mem_elt(self.pc + 1);
+                        T::to_usize(location).ok_or(MemoryAccessError::Negative)?
+                    }
+                    203 => {
+                        let offset = T::to_i32(self.read_mem_elt(self.pc + 1)).ok_or(
+                            MachineExecutionError::OutOfBounds(MemoryAccessError::Overflow),
+                        )?;
+                        let target = offset + self.relative_base;
+                        if target < 0 {
+                            return Err(MachineExecutionError::OutOfBounds(
+                                MemoryAccessError::Negative,
+                            ));
+                        }
+                        target as usize
+                    }
+                    _ => {
+                        return Err(MachineExecutionError::BadParameterMode(opcode));
+                    }
+                };
+                self.pc += 2;
+                match self.inputs.pop_front() {
+                    Some(input) => {
+                        if let Some(record) = record {
+                            record.write = Some((location, input.clone()));
+                        }
+                        self.set_mem_elt(location, input);
+                        Ok(StepResult::Stepped)
+                    }
+                    None => {
+                        self.awaiting = Some(location);
+                        Ok(StepResult::Io(StepIoResult::AwaitingInput(location)))
+                    }
+                }
+            }
+            4 => {
+                let to_output = self.consume_args_1(opcode)?;
+                if let Some(record) = record {
+                    record.operands = vec![to_output.clone()];
+                }
+                self.pc += 2;
+                Ok(StepResult::Io(StepIoResult::Output(to_output)))
+            }
+            5 => {
+                let (comparand, target) = self.consume_args_2(opcode)?;
+                if let Some(record) = record {
+                    record.operands = vec![comparand.clone(), target.clone()];
+                }
+                if comparand != T::zero() {
+                    self.pc = T::to_usize(target).ok_or(MemoryAccessError::Negative)?;
+                } else {
+                    self.pc += 3;
+                }
+                Ok(StepResult::Stepped)
+            }
+            6 => {
+                let (comparand, target) = self.consume_args_2(opcode)?;
+                if let Some(record) = record {
+                    record.operands = vec![comparand.clone(), target.clone()];
+                }
+                if comparand == T::zero() {
+                    self.pc = T::to_usize(target).ok_or(MemoryAccessError::Negative)?;
+                } else {
+                    self.pc += 3;
+                }
+                Ok(StepResult::Stepped)
+            }
+            7 => self.transform_to_dest(opcode, record, |a, b| {
+                T::if_less_then_else(a, b, T::one(), T::zero())
+            }),
+            8 => self.transform_to_dest(opcode, record, |a, b| {
+                T::if_eq_then_else(a, b, T::one(), T::zero())
+            }),
+            9 => {
+                let arg = self.consume_args_1(opcode)?;
+                if let Some(record) = record {
+                    record.operands = vec![arg.clone()];
+                }
+                let increment = T::to_i32(arg).ok_or(MemoryAccessError::Overflow)?;
+                self.relative_base += increment;
+                self.pc += 2;
+                Ok(StepResult::Stepped)
+            }
+            99 => {
+                if opcode != 99 {
+                    return Err(MachineExecutionError::BadParameterMode(opcode));
+                }
+                Ok(StepResult::Io(StepIoResult::Terminated))
+            }
+            other => match self.extensions.get(&other) {
+                Some(handler) => Arc::clone(handler)(self, opcode),
+                None => Err(MachineExecutionError::BadOpcode(other, self.pc)),
+            },
+        }
+    }
+
+    pub fn execute_until_input(&mut self) -> Result<StepIoResult<T>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Ord + Num,
+    {
+        loop {
+            match self.one_step()? {
+                StepResult::Io(res) => {
+                    return Ok(res);
+                }
+                StepResult::Stepped => {}
+            }
+        }
+    }
+
+    pub fn execute_to_end<I>(&mut self, get_input: &mut I) -> Result<Vec<T>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Ord + Num,
+        I: Iterator<Item = T>,
+    {
+        let mut outputs = vec![];
+        loop {
+            match self.execute_until_input()? {
+                StepIoResult::Terminated => {
+                    return Ok(outputs);
+                }
+                StepIoResult::Output(output) => {
+                    outputs.push(output);
+                }
+                StepIoResult::AwaitingInput(target_location) => match get_input.next() {
+                    None => {
+                        return Err(MachineExecutionError::NoInput);
+                    }
+                    Some(input) => {
+                        self.set_mem_elt(target_location, input);
+                    }
+                },
+            }
+        }
+    }
+
+    /// Runs a program which takes no input to the end, returning everything it output.
+    pub fn execute_collect_outputs(&mut self) -> Result<Vec<T>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Ord + Num,
+    {
+        self.execute_to_end(&mut std::iter::empty())
+    }
+
+    pub fn dump_memory(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Copy,
+    {
+        self.memory.iter().copied()
+    }
+
+    #[cold]
+    fn set_mem_elt_sparse(&mut self, i: usize, new_val: T) {
+        self.sparse_memory.insert(i, new_val);
+    }
+
+    pub fn set_mem_elt(&mut self, i: usize, new_val: T) {
+        if !self.devices.is_empty() {
+            if let Some((device, offset)) = self.device_at(i) {
+                let mut device = device.lock().unwrap_or_else(PoisonError::into_inner);
+                device.write(offset, new_val);
+                return;
+            }
+        }
+        if i < self.memory.len() {
+            self.memory[i] = new_val;
+        } else {
+            self.set_mem_elt_sparse(i, new_val);
+        }
+    }
+
+    #[cold]
+    fn read_sparse_mem(&self, i: usize) -> T
+    where
+        T: Clone + Num,
+    {
+        match self.sparse_memory.get(&i) {
+            None => T::zero(),
+            Some(v) => v.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn read_mem_elt(&self, i: usize) -> T
+    where
+        T: Clone + Num,
+    {
+        if !self.devices.is_empty() {
+            if let Some((device, offset)) = self.device_at(i) {
+                let mut device = device.lock().unwrap_or_else(PoisonError::into_inner);
+                return device.read(offset);
+            }
+        }
+        if i < self.memory.len() {
+            self.memory[i].clone()
+        } else {
+            self.read_sparse_mem(i)
+        }
+    }
+
+    /// The memory in `range` all at once, as long as it lies within the memory the machine was
+    /// loaded with; memory beyond that is stored sparsely, and must be read with `read_mem_elt`.
+    /// This is the memory itself, even where a device is mapped over it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends.
+    pub fn read_mem_slice(&self, range: Range<usize>) -> Result<&[T], MemoryAccessTooFarError> {
+        if range.end > self.memory.len() {
+            return Err(MemoryAccessTooFarError {
+                pos: range.end - 1,
+                len: self.memory.len(),
+                is_write: false,
+            });
+        }
+        Ok(&self.memory[range])
+    }
+
+    /// Write `values` into memory from `start` onwards, as `set_mem_elt` would one at a time.
+    pub fn write_mem_slice(&mut self, start: usize, values: &[T])
+    where
+        T: Clone,
+    {
+        if !self.devices.is_empty() {
+            for (i, value) in values.iter().enumerate() {
+                self.set_mem_elt(start + i, value.clone());
+            }
+            return;
+        }
+        let dense = self.memory.len().saturating_sub(start).min(values.len());
+        let (within, beyond) = values.split_at(dense);
+        if dense > 0 {
+            self.memory[start..start + dense].clone_from_slice(within);
+        }
+        for (i, value) in beyond.iter().enumerate() {
+            self.set_mem_elt_sparse(start + dense + i, value.clone());
+        }
+    }
+
+    fn read_param(&self, i: usize, mode: ParameterMode) -> Result<T, MemoryAccessError>
+    where
+        T: Clone + Num,
+    {
+        match mode {
+            ParameterMode::Immediate => Ok(self.read_mem_elt(i)),
+            ParameterMode::Position => {
+                let pos = self.read_mem_elt(i);
+                let pos = T::to_usize(pos);
+                match pos {
+                    None => Err(MemoryAccessError::Negative),
+                    Some(pos) => Ok(self.read_mem_elt(pos)),
+                }
+            }
+            ParameterMode::Relative => {
+                let offset = self.read_mem_elt(i);
+                let target =
+                    self.relative_base + T::to_i32(offset).ok_or(MemoryAccessError::Overflow)?;
+                if target >= 0 {
+                    Ok(self.read_mem_elt(target as usize))
+                } else {
+                    Err(MemoryAccessError::Negative)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use strategies::strategies::straight_line_program;
+
+    fn assert_machines_eq<T, I, const N: usize>(
+        initial: &[T; N],
+        expected_memory: Option<&[T]>,
+        input: &mut I,
+        expected_output: &[T],
+    ) where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Copy + Ord + Num,
+        I: Iterator<Item = T>,
+    {
+        let mut machine: MachineState<T> = MachineState::<T>::new_with_memory(initial);
+        let output = machine.execute_to_end(input).unwrap();
+        match expected_memory {
+            None => {}
+            Some(expected_memory) => {
+                assert!(machine.dump_memory().eq(expected_memory.iter().copied()));
+            }
+        }
+        assert!(output.iter().copied().eq(expected_output.iter().copied()));
+    }
+
+    #[test]
+    fn day_2_1() {
+        assert_machines_eq(
+            &[1_usize, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50],
+            Some(&[3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]),
+            &mut std::iter::empty(),
+            &[],
+        );
+    }
+
+    #[test]
+    fn day_2_2() {
+        assert_machines_eq(
+            &[2_usize, 3, 0, 3, 99],
+            Some(&[2, 3, 0, 6, 99]),
+            &mut std::iter::empty(),
+            &[],
+        );
+    }
+
+    #[test]
+    fn day_2_3() {
+        assert_machines_eq(
+            &[2_usize, 4, 4, 5, 99, 0],
+            Some(&[2, 4, 4, 5, 99, 9801]),
+            &mut std::iter::empty(),
+            &[],
+        );
+    }
+
+    #[test]
+    fn day_2_4() {
+        assert_machines_eq(
+            &[1_usize, 1, 1, 4, 99, 5, 6, 0, 99],
+            Some(&[30, 1, 1, 4, 2, 5, 6, 0, 99]),
+            &mut std::iter::empty(),
+            &[],
+        );
+    }
+
+    #[test]
+    fn day_5_1() {
+        let program = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        assert_machines_eq(&program, None, &mut std::iter::once(8), &[1]);
+        assert_machines_eq(&program, None, &mut std::iter::once(7), &[0]);
+    }
+
+    #[test]
+    fn day_5_2() {
+        let program = [3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8];
+        assert_machines_eq(&program, None, &mut std::iter::once(8), &[0]);
+        assert_machines_eq(&program, None, &mut std::iter::once(7), &[1]);
+        assert_machines_eq(&program, None, &mut std::iter::once(9), &[0]);
+    }
+
+    #[test]
+    fn day_5_3() {
+        let program = [3, 3, 1108, -1, 8, 3, 4, 3, 99];
+        assert_machines_eq(&program, None, &mut std::iter::once(8), &[1]);
+        assert_machines_eq(&program, None, &mut std::iter::once(7), &[0]);
+    }
+
+    #[test]
+    fn day_5_4() {
+        let program = [3, 3, 1107, -1, 8, 3, 4, 3, 99];
+        assert_machines_eq(&program, None, &mut std::iter::once(8), &[0]);
+        assert_machines_eq(&program, None, &mut std::iter::once(7), &[1]);
+        assert_machines_eq(&program, None, &mut std::iter::once(9), &[0]);
+    }
+
+    #[test]
+    fn day_5_6() {
+        let program = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+        assert_machines_eq(&program, None, &mut std::iter::once(0), &[0]);
+        assert_machines_eq(&program, None, &mut std::iter::once(3), &[1]);
+    }
+
+    #[test]
+    fn day_5_7() {
+        let program = [
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+        assert_machines_eq(&program, None, &mut std::iter::once(7), &[999]);
+        assert_machines_eq(&program, None, &mut std::iter::once(8), &[1000]);
+        assert_machines_eq(&program, None, &mut std::iter::once(9), &[1001]);
+    }
+
+    #[test]
+    fn day_9_1() {
+        // A quine, which keeps its counter and loop condition beyond the end of its own code.
+        let program = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let mut machine: MachineState<i64> = MachineState::new_with_memory(&program);
+        assert_eq!(machine.execute_collect_outputs().unwrap(), program);
+        assert_eq!(machine.read_mem_elt(100), 16);
+        assert_eq!(machine.read_mem_elt(101), 1);
+        assert!(machine.dump_memory().eq(program.iter().copied()));
+    }
+
+    #[test]
+    fn test_reset_from_program() {
+        let quine = Program::new([
+            109_i64, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ]);
+        let mut machine = MachineState::from_program(&quine);
+        assert_eq!(machine.execute_collect_outputs().unwrap(), quine.as_slice());
+
+        // Runs again from scratch, despite the relative base and the memory beyond the program.
+        machine.reset_from_program(&quine);
+        assert_eq!(machine.execute_collect_outputs().unwrap(), quine.as_slice());
+
+        let shorter = Program::from(vec![104_i64, 5, 99]);
+        machine.reset_from_program(&shorter);
+        assert!(machine.dump_memory().eq(shorter.as_slice().iter().copied()));
+        assert_eq!(machine.execute_collect_outputs().unwrap(), [5]);
+    }
+
+    #[test]
+    fn test_mem_slices() {
+        let mut machine: MachineState<i64> = MachineState::new_with_memory(&[1, 2, 3, 4, 99]);
+        assert_eq!(machine.read_mem_slice(1..4).unwrap(), [2, 3, 4]);
+        assert_eq!(machine.read_mem_slice(5..5).unwrap(), []);
+        assert_eq!(
+            machine.read_mem_slice(3..6).unwrap_err().to_string(),
+            "attempted to access position 5 but memory only has length 5 (is_write: false)"
+        );
+
+        // Writes past the end of the program land in the sparse memory, as single writes do.
+        machine.write_mem_slice(3, &[7, 8, 9, 10]);
+        assert_eq!(machine.read_mem_slice(0..5).unwrap(), [1, 2, 3, 7, 8]);
+        assert_eq!(machine.read_mem_elt(5), 9);
+        assert_eq!(machine.read_mem_elt(6), 10);
+        machine.write_mem_slice(100, &[11]);
+        assert_eq!(machine.read_mem_elt(100), 11);
+        assert!(machine.read_mem_slice(0..6).is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        let program: Vec<i64> = vec![1101, 2, 3, 20, 1, 0, 0, 0, 0, 0, 104, 7, 99];
+        let mut machine = MachineState::new_with_memory(&program);
+        assert_eq!(
+            machine.to_string(),
+            "pc 0, relative base 0, memory 13: [1101] 2 3 20 1 .."
+        );
+        machine.one_step().unwrap();
+        assert_eq!(
+            machine.to_string(),
+            "pc 4, relative base 0, memory 13 (+1 sparse): 1101 2 3 20 [1] 0 0 0 0 .."
+        );
+        assert_eq!(
+            format!("{machine:?}"),
+            "MachineState { pc: 4, relative_base: 0, memory_len: 13, sparse_len: 1, around_pc: 1101 2 3 20 [1] 0 0 0 0 .. }"
+        );
+        let empty: MachineState<i64> = MachineState::new();
+        assert_eq!(
+            empty.to_string(),
+            "pc 0, relative base 0, memory 0: (no memory)"
+        );
+    }
+
+    #[test]
+    fn test_gas() {
+        // Adds, outputs, then halts.
+        let program = Program::new([1101_i64, 2, 3, 7, 104, 5, 99, 0]);
+        let mut machine = MachineState::from_program(&program);
+        assert_eq!(machine.gas_used(), None);
+        machine.meter(GasCosts::default());
+        assert_eq!(machine.execute_collect_outputs().unwrap(), [5]);
+        assert_eq!(machine.gas_used(), Some(3));
+
+        machine.reset_from_program(&program);
+        assert_eq!(machine.gas_used(), Some(0));
+        machine.meter(GasCosts {
+            add: 10,
+            output: 100,
+            halt: 0,
+            ..GasCosts::uniform(1)
+        });
+        machine.execute_collect_outputs().unwrap();
+        assert_eq!(machine.gas_used(), Some(110));
+
+        // Instructions which fail cost nothing.
+        let mut machine = MachineState::new_with_memory(&[1_i64, 2, 3, -1]);
+        machine.meter(GasCosts::default());
+        assert!(machine.one_step().is_err());
+        assert_eq!(machine.gas_used(), Some(0));
+    }
+
+    #[test]
+    fn test_input_queue() {
+        // Outputs the sum of two inputs, then halts.
+        let program = Program::new([3_i64, 11, 3, 12, 1, 11, 12, 13, 4, 13, 99, 0, 0, 0]);
+        let mut machine = MachineState::from_program(&program);
+        machine.push_input(3);
+        assert!(matches!(
+            machine.execute_until_input().unwrap(),
+            StepIoResult::AwaitingInput(12)
+        ));
+        machine.set_mem_elt(12, 4);
+        assert!(matches!(
+            machine.execute_until_input().unwrap(),
+            StepIoResult::Output(7)
+        ));
+
+        machine.reset_from_program(&program);
+        machine.push_inputs([1, 2]);
+        assert_eq!(machine.queued_inputs(), 2);
+        assert_eq!(machine.execute_collect_outputs().unwrap(), [3]);
+        assert_eq!(machine.queued_inputs(), 0);
+
+        // Queued inputs come before any others.
+        machine.reset_from_program(&program);
+        machine.push_input(10);
+        assert_eq!(machine.execute_to_end(&mut [20].into_iter()).unwrap(), [30]);
+
+        machine.push_input(5);
+        machine.reset_from_program(&program);
+        assert!(matches!(
+            machine.execute_until_input().unwrap(),
+            StepIoResult::AwaitingInput(11)
+        ));
+
+        // Input pushed once the machine is waiting for it goes where it asked, and the rest is
+        // queued.
+        assert!(machine.is_awaiting_input());
+        machine.push_inputs([5, 6]);
+        assert!(!machine.is_awaiting_input());
+        assert_eq!(machine.queued_inputs(), 1);
+        assert_eq!(machine.execute_collect_outputs().unwrap(), [11]);
+
+        // Input stored by hand needs no pushing, so anything pushed later is queued.
+        machine.reset_from_program(&program);
+        assert!(matches!(
+            machine.execute_until_input().unwrap(),
+            StepIoResult::AwaitingInput(11)
+        ));
+        machine.set_mem_elt(11, 1);
+        assert!(matches!(
+            machine.execute_until_input().unwrap(),
+            StepIoResult::AwaitingInput(12)
+        ));
+        machine.set_mem_elt(12, 2);
+        assert!(matches!(
+            machine.execute_until_input().unwrap(),
+            StepIoResult::Output(3)
+        ));
+        assert!(!machine.is_awaiting_input());
+    }
+
+    #[test]
+    fn test_patches() {
+        let program = Program::new([1_i64, 0, 0, 0, 99]).with_patches(&[
+            Patch {
+                addr: 1,
+                value: 4,
+                label: "noun",
+            },
+            Patch {
+                addr: 6,
+                value: 7,
+                label: "past the end",
+            },
+        ]);
+        assert_eq!(program.as_slice(), [1, 4, 0, 0, 99, 0, 7]);
+    }
+
+    #[test]
+    fn test_trace() {
+        #[derive(Clone, Default)]
+        struct Shared(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Shared {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        // Stores an input at 9, adds 2 to it into 10, outputs that, then halts.
+        let program = [3_i64, 9, 1001, 9, 2, 10, 4, 10, 99, 0, 0];
+        let trace = Shared::default();
+        let mut machine = MachineState::new_with_memory(&program);
+        machine.trace_to(trace.clone());
+        assert_eq!(machine.execute_to_end(&mut [5].into_iter()).unwrap(), [7]);
+        machine.finish_trace().unwrap();
+        let lines = String::from_utf8(trace.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            lines.lines().collect::<Vec<_>>(),
+            [
+                r#"{"step":0,"pc":0,"opcode":3,"instruction":"input","operands":[],"writes":[{"address":9,"value":5}],"input":5}"#,
+                r#"{"step":1,"pc":2,"opcode":1001,"instruction":"add","operands":[5,2],"writes":[{"address":10,"value":7}]}"#,
+                r#"{"step":2,"pc":6,"opcode":4,"instruction":"output","operands":[7],"writes":[],"output":7}"#,
+                r#"{"step":3,"pc":8,"opcode":99,"instruction":"halt","operands":[],"writes":[]}"#,
+            ]
+        );
+
+        // An input from the machine's own queue is traced just the same.
+        let queued = Shared::default();
+        let mut machine = MachineState::new_with_memory(&program);
+        machine.trace_to(queued.clone());
+        machine.push_input(5);
+        assert_eq!(machine.execute_collect_outputs().unwrap(), [7]);
+        machine.finish_trace().unwrap();
+        assert_eq!(*queued.0.lock().unwrap(), *trace.0.lock().unwrap());
+
+        // A machine left awaiting input has its last line written when tracing stops.
+        let trace = Shared::default();
+        let mut machine = MachineState::new_with_memory(&program);
+        machine.trace_to(trace.clone());
+        assert!(matches!(
+            machine.execute_until_input().unwrap(),
+            StepIoResult::AwaitingInput(9)
+        ));
+        assert!(trace.0.lock().unwrap().is_empty());
+        machine.finish_trace().unwrap();
+        assert_eq!(
+            String::from_utf8(trace.0.lock().unwrap().clone()).unwrap(),
+            "{\"step\":0,\"pc\":0,\"opcode\":3,\"instruction\":\"input\",\"operands\":[],\"writes\":[]}\n"
+        );
+    }
+
+    #[test]
+    fn test_extensions() {
+        use std::sync::Mutex;
+
+        // Prints 42, stores a random number at 9, outputs it, then halts.
+        let program = [121_i64, 42, 22, 9, 4, 9, 99, 0, 0, 0];
+        let printed = Arc::new(Mutex::new(vec![]));
+        let log = Arc::clone(&printed);
+        let mut machine = MachineState::new_with_memory(&program)
+            .with_opcode(21, move |machine, opcode| {
+                log.lock().unwrap().push(machine.param(opcode, 1)?);
+                machine.set_pc(machine.pc() + 2);
+                Ok(StepResult::Stepped)
+            })
+            .with_opcode(22, |machine: &mut MachineState<i64>, opcode| {
+                let address = machine.param_address(opcode, 1)?;
+                machine.set_mem_elt(address, 4);
+                machine.set_pc(machine.pc() + 2);
+                Ok(StepResult::Stepped)
+            });
+        machine.meter(GasCosts {
+            custom: 10,
+            ..GasCosts::default()
+        });
+        assert_eq!(machine.clone().execute_collect_outputs().unwrap(), [4]);
+        assert_eq!(machine.execute_collect_outputs().unwrap(), [4]);
+        assert_eq!(*printed.lock().unwrap(), [42, 42]);
+        assert_eq!(machine.gas_used(), Some(22));
+
+        let mut unextended = MachineState::new_with_memory(&program);
+        assert!(matches!(
+            unextended.one_step(),
+            Err(MachineExecutionError::BadOpcode(21, 0))
+        ));
+    }
+
+    /// Draws `(x, y, tile)` when the tile is written, as day 13's screen would.
+    #[derive(Default)]
+    struct Screen {
+        position: (i64, i64),
+        drawn: Vec<(i64, i64, i64)>,
+    }
+
+    impl Device<i64> for Screen {
+        fn read(&mut self, _: usize) -> i64 {
+            0
+        }
+
+        fn write(&mut self, offset: usize, value: i64) {
+            match offset {
+                0 => self.position.0 = value,
+                1 => self.position.1 = value,
+                _ => self.drawn.push((self.position.0, self.position.1, value)),
+            }
+        }
+    }
+
+    /// Counts up each time it's read.
+    struct Counter(i64);
+
+    impl Device<i64> for Counter {
+        fn read(&mut self, _: usize) -> i64 {
+            self.0 += 1;
+            self.0
+        }
+
+        fn write(&mut self, _: usize, value: i64) {
+            self.0 = value;
+        }
+    }
+
+    #[test]
+    fn test_devices() {
+        // Draws tile 2 at (3, 4), outputs the counter twice, and adds 5 to the memory at 9.
+        let program = [
+            1101, 3, 0, 1000, 1101, 4, 0, 1001, 1101, 2, 0, 1002, 4, 2000, 4, 2000, 1001, 9, 5, 9,
+            99,
+        ];
+        let screen = Arc::new(Mutex::new(Screen::default()));
+        let counter = Arc::new(Mutex::new(Counter(10)));
+        let mut machine = MachineState::new_with_memory(&program)
+            .with_device(1000..1003, screen.clone())
+            .with_device(2000..2001, counter.clone());
+        assert_eq!(machine.execute_collect_outputs().unwrap(), [11, 12]);
+        assert_eq!(screen.lock().unwrap().drawn, [(3, 4, 2)]);
+        assert_eq!(machine.read_mem_elt(9), 7);
+        assert_eq!(machine.read_mem_elt(1000), 0);
+
+        machine.write_mem_slice(1999, &[7, 100]);
+        assert_eq!(counter.lock().unwrap().0, 100);
+        assert_eq!(machine.read_mem_elt(1999), 7);
+    }
+
+    #[test]
+    fn test_trace_devices() {
+        // Outputs the counter, adds 0 to it into 20 and outputs that, stores an input in the
+        // counter, outputs the counter again, then halts.
+        let program = [4_i64, 2000, 1001, 2000, 0, 20, 4, 20, 3, 2000, 4, 2000, 99];
+        let run = |traced: bool| {
+            let counter = Arc::new(Mutex::new(Counter(10)));
+            let mut machine =
+                MachineState::new_with_memory(&program).with_device(2000..2001, counter);
+            let trace = Arc::new(Mutex::new(vec![]));
+            if traced {
+                struct Lines(Arc<Mutex<Vec<u8>>>);
+                impl Write for Lines {
+                    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                        self.0.lock().unwrap().write(buf)
+                    }
+
+                    fn flush(&mut self) -> io::Result<()> {
+                        Ok(())
+                    }
+                }
+                machine.trace_to(Lines(Arc::clone(&trace)));
+            }
+            let outputs = machine.execute_to_end(&mut [50].into_iter()).unwrap();
+            machine.finish_trace().unwrap();
+            let trace = String::from_utf8(trace.lock().unwrap().clone()).unwrap();
+            (outputs, trace)
+        };
+        let (untraced, _) = run(false);
+        let (traced, trace) = run(true);
+        assert_eq!(untraced, [11, 12, 51]);
+        assert_eq!(traced, untraced);
+        assert_eq!(
+            trace.lines().collect::<Vec<_>>(),
+            [
+                r#"{"step":0,"pc":0,"opcode":4,"instruction":"output","operands":[11],"writes":[],"output":11}"#,
+                r#"{"step":1,"pc":2,"opcode":1001,"instruction":"add","operands":[12,0],"writes":[{"address":20,"value":12}]}"#,
+                r#"{"step":2,"pc":6,"opcode":4,"instruction":"output","operands":[12],"writes":[],"output":12}"#,
+                r#"{"step":3,"pc":8,"opcode":3,"instruction":"input","operands":[],"writes":[]}"#,
+                r#"{"step":4,"pc":10,"opcode":4,"instruction":"output","operands":[51],"writes":[],"output":51}"#,
+                r#"{"step":5,"pc":12,"opcode":99,"instruction":"halt","operands":[],"writes":[]}"#,
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a device can't be mapped at 5..7")]
+    fn test_device_clash() {
+        let _ = MachineState::<i64>::new()
+            .with_device(0..6, Arc::new(Mutex::new(Counter(0))))
+            .with_device(5..7, Arc::new(Mutex::new(Counter(0))));
+    }
+
+    #[test]
+    #[should_panic(expected = "opcode 4 can't be an extension")]
+    fn test_extension_clash() {
+        let _ = MachineState::<i64>::new().with_opcode(4, |_, _| Ok(StepResult::Stepped));
+    }
+
+    #[test]
+    fn day_9_2() {
+        let program: [u64; 8] = [1102, 34915192, 34915192, 7, 4, 7, 99, 0];
+        assert_machines_eq(
+            &program,
+            None,
+            &mut std::iter::empty(),
+            &[program[1] * program[2]],
+        );
+    }
+
+    #[test]
+    fn day_9_3() {
+        let program: [u64; 3] = [104, 1125899906842624, 99];
+        assert_machines_eq(&program, None, &mut std::iter::empty(), &[program[1]]);
+    }
+
+    proptest! {
+        #[test]
+        fn straight_line_programs(line in straight_line_program(20)) {
+            let mut machine = MachineState::new_with_memory(&line.program);
+            let outputs = machine.execute_to_end(&mut std::iter::empty()).unwrap();
+            prop_assert_eq!(outputs, line.outputs);
+        }
+    }
+}
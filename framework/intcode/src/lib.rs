@@ -1,3 +1,5 @@
+pub mod ascii;
 pub mod ast;
 pub mod intcode;
 pub mod linked_list;
+pub mod network;
@@ -0,0 +1,257 @@
+use std::ops::{Add, Mul};
+
+use crate::intcode::{MachineExecutionError, MachineState, Num, StepIoResult, StepResult};
+
+/// How long each machine's turn in a `Cluster` lasts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Schedule {
+    /// A machine's turn lasts until it asks for input which its queue doesn't have, or
+    /// terminates.
+    #[default]
+    UntilBlocked,
+    /// As `UntilBlocked`, but a machine's turn also ends once it has executed `quantum`
+    /// instructions, so that no machine can run far ahead of the others.
+    RoundRobin { quantum: u64 },
+}
+
+/// Several machines, each reading from its own queue of inputs (see `MachineState::push_input`),
+/// run one after another in a fixed order: by index, unless `with_order` says otherwise.
+/// How long each machine's turn lasts is up to the `Schedule`. When a machine asks for input
+/// which its queue doesn't have, what happens depends on the default input: without one, the
+/// machine blocks until something is sent to it; with one, the machine reads the default and its
+/// turn ends, so that it can poll its queue again next turn.
+/// Nothing else decides who runs when, so a cluster runs the same way every time.
+pub struct Cluster<T> {
+    machines: Vec<MachineState<T>>,
+    schedule: Schedule,
+    order: Vec<usize>,
+    /// Whether each machine has been sent the input it was blocked on since its last turn.
+    woken: Vec<bool>,
+    terminated: Vec<bool>,
+    default_input: Option<T>,
+}
+
+/// What happened in one round of every machine taking its turn.
+pub struct Round<T> {
+    /// Every output, labelled with the index of the machine which produced it, in the order
+    /// they were produced.
+    pub outputs: Vec<(usize, T)>,
+    /// Whether no machine read anything from its queue, produced any output, or had its turn cut
+    /// short by the schedule's quantum.
+    pub idle: bool,
+}
+
+impl<T> Cluster<T> {
+    pub fn new(machines: Vec<MachineState<T>>) -> Cluster<T> {
+        let count = machines.len();
+        Cluster {
+            machines,
+            schedule: Schedule::default(),
+            order: (0..count).collect(),
+            woken: vec![false; count],
+            terminated: vec![false; count],
+            default_input: None,
+        }
+    }
+
+    /// Makes input non-blocking: a machine whose queue is empty reads this value instead.
+    pub fn with_default_input(mut self, value: T) -> Cluster<T> {
+        self.default_input = Some(value);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the schedule's quantum is zero, which would let no machine do anything.
+    pub fn with_schedule(mut self, schedule: Schedule) -> Cluster<T> {
+        assert!(
+            schedule != Schedule::RoundRobin { quantum: 0 },
+            "a machine's turn can't be zero instructions long"
+        );
+        self.schedule = schedule;
+        self
+    }
+
+    /// Makes the machines take their turns in each round in the order of the indices in `order`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` doesn't name every machine exactly once.
+    pub fn with_order(mut self, order: Vec<usize>) -> Cluster<T> {
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert!(
+            sorted.iter().copied().eq(0..self.machines.len()),
+            "{order:?} isn't an order of {} machines",
+            self.machines.len()
+        );
+        self.order = order;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    pub fn send(&mut self, machine: usize, value: T) {
+        self.woken[machine] |= self.machines[machine].is_awaiting_input();
+        self.machines[machine].push_input(value);
+    }
+
+    /// Whether `machine` is waiting for something to be sent to it.
+    pub fn is_blocked(&self, machine: usize) -> bool {
+        self.machines[machine].is_awaiting_input()
+    }
+
+    pub fn all_terminated(&self) -> bool {
+        self.terminated.iter().all(|&t| t)
+    }
+
+    /// Gives every machine which hasn't terminated its turn.
+    pub fn round(&mut self) -> Result<Round<T>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Ord + Num,
+    {
+        let mut outputs = vec![];
+        let mut idle = true;
+        for turn in 0..self.order.len() {
+            let i = self.order[turn];
+            if self.terminated[i] || self.machines[i].is_awaiting_input() {
+                continue;
+            }
+            // The machine reads what it's sent by itself, so whether it read anything is told by
+            // how much of its queue is left, or by its having been woken.
+            let queued = self.machines[i].queued_inputs();
+            if std::mem::take(&mut self.woken[i]) {
+                idle = false;
+            }
+            let mut steps = 0;
+            loop {
+                match self.run(i, &mut steps)? {
+                    None => {
+                        idle = false;
+                        break;
+                    }
+                    Some(StepIoResult::Terminated) => {
+                        self.terminated[i] = true;
+                        break;
+                    }
+                    Some(StepIoResult::Output(value)) => {
+                        idle = false;
+                        outputs.push((i, value));
+                    }
+                    Some(StepIoResult::AwaitingInput(_)) => {
+                        if let Some(default) = &self.default_input {
+                            self.machines[i].push_input(default.clone());
+                        }
+                        break;
+                    }
+                }
+            }
+            if self.machines[i].queued_inputs() < queued {
+                idle = false;
+            }
+        }
+        Ok(Round { outputs, idle })
+    }
+
+    /// Runs machine `i` until its next input or output, as the schedule allows: None if its turn
+    /// ran out first. `steps` counts the instructions it has executed this turn.
+    fn run(
+        &mut self,
+        i: usize,
+        steps: &mut u64,
+    ) -> Result<Option<StepIoResult<T>>, MachineExecutionError>
+    where
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Ord + Num,
+    {
+        let quantum = match self.schedule {
+            Schedule::UntilBlocked => return self.machines[i].execute_until_input().map(Some),
+            Schedule::RoundRobin { quantum } => quantum,
+        };
+        while *steps < quantum {
+            *steps += 1;
+            if let StepResult::Io(io) = self.machines[i].one_step()? {
+                return Ok(Some(io));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round() {
+        // Reads a number and outputs one more than it, forever.
+        let program = [3_i64, 11, 1001, 11, 1, 11, 4, 11, 1105, 1, 0];
+        let machine = MachineState::new_with_memory(&program.iter().copied());
+        let mut cluster = Cluster::new(vec![machine.clone(), machine]);
+
+        cluster.send(1, 10);
+        cluster.send(1, 20);
+        let round = cluster.round().unwrap();
+        assert_eq!(round.outputs, vec![(1, 11), (1, 21)]);
+        assert!(!round.idle);
+        let round = cluster.round().unwrap();
+        assert!(round.outputs.is_empty());
+        assert!(round.idle);
+
+        cluster.send(0, 5);
+        assert_eq!(cluster.round().unwrap().outputs, vec![(0, 6)]);
+        assert!(!cluster.all_terminated());
+    }
+
+    #[test]
+    fn test_schedule() {
+        // Reads a number and outputs one more than it, forever: four instructions a number.
+        let program = [3_i64, 11, 1001, 11, 1, 11, 4, 11, 1105, 1, 0];
+        let machine = MachineState::new_with_memory(&program.iter().copied());
+        let mut cluster = Cluster::new(vec![machine.clone(), machine])
+            .with_schedule(Schedule::RoundRobin { quantum: 2 })
+            .with_order(vec![1, 0]);
+
+        cluster.send(0, 10);
+        cluster.send(1, 20);
+        // Each machine's turn ends before it gets as far as the output, but it wasn't idle.
+        let round = cluster.round().unwrap();
+        assert!(round.outputs.is_empty());
+        assert!(!round.idle);
+        let round = cluster.round().unwrap();
+        assert_eq!(round.outputs, vec![(1, 21), (0, 11)]);
+        let round = cluster.round().unwrap();
+        assert!(round.outputs.is_empty());
+        assert!(round.idle);
+        assert!(cluster.is_blocked(0) && cluster.is_blocked(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "[0, 0] isn't an order of 2 machines")]
+    fn test_bad_order() {
+        let machine = MachineState::new_with_memory(&[99_i64]);
+        let _ = Cluster::new(vec![machine.clone(), machine]).with_order(vec![0, 0]);
+    }
+
+    #[test]
+    fn test_default_input() {
+        // Outputs each number it reads, forever.
+        let program = [3_i64, 7, 4, 7, 1105, 1, 0];
+        let machine = MachineState::new_with_memory(&program.iter().copied());
+        let mut cluster = Cluster::new(vec![machine]).with_default_input(-1);
+
+        cluster.send(0, 3);
+        let round = cluster.round().unwrap();
+        // Reading the default ends the turn, so it is only echoed in the next round.
+        assert_eq!(round.outputs, vec![(0, 3)]);
+        assert!(!round.idle);
+        let round = cluster.round().unwrap();
+        assert_eq!(round.outputs, vec![(0, -1)]);
+        assert!(!round.idle);
+    }
+}
@@ -0,0 +1,79 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// The environment variable naming a directory of inputs, named like `day_13.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directory, at the top of the workspace, holding the crates of the days whose inputs this
+/// finds.
+pub const YEAR_DIR: &str = "year2019";
+
+/// The top of the workspace, which this crate is inside `framework`.
+pub fn workspace_root() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", ".."].iter().collect()
+}
+
+/// The directory of `day`'s crate, which holds its examples and perhaps its input.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_root().join(YEAR_DIR).join(format!("day_{day}"))
+}
+
+/// The directory at the root of the workspace into which `aoc fetch` downloads inputs.
+pub fn cache_dir() -> PathBuf {
+    workspace_root().join("inputs")
+}
+
+/// The places the input for `day` might be, most preferred first: the directory named by
+/// `AOC_INPUT_DIR` if it's set, then the download cache, then the day's own `input.txt`.
+pub fn candidates(day: u8) -> Vec<PathBuf> {
+    let file = format!("day_{day}.txt");
+    let mut result = Vec::with_capacity(3);
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        result.push(PathBuf::from(dir).join(&file));
+    }
+    result.push(cache_dir().join(&file));
+    result.push(day_dir(day).join("input.txt"));
+    result
+}
+
+/// The first of the `candidates` for `day` which exists.
+pub fn path(day: u8) -> Option<PathBuf> {
+    candidates(day).into_iter().find(|path| path.is_file())
+}
+
+/// The input for `day`, or `None` if there isn't one, in which case this says on stderr that
+/// whatever needed it is being skipped. Tests and benchmarks against the real inputs should
+/// return early on `None` rather than fail.
+///
+/// # Panics
+///
+/// Panics if the input exists but can't be read.
+pub fn load(day: u8) -> Option<String> {
+    match path(day) {
+        Some(path) => match fs::read_to_string(&path) {
+            Ok(input) => Some(input),
+            Err(e) => panic!("could not read {}: {e}", path.display()),
+        },
+        None => {
+            eprintln!(
+                "skipping: no input for day {day}; set {INPUT_DIR_VAR} or run `aoc fetch --day {day}`"
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidates() {
+        let candidates = candidates(13);
+        let n = candidates.len();
+        assert!(candidates[n - 2].ends_with("inputs/day_13.txt"));
+        assert!(candidates[n - 1].ends_with("year2019/day_13/input.txt"));
+        assert_eq!(load(24), None);
+    }
+}
@@ -0,0 +1,136 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// The environment variable saying what to log, like `debug` or `warn,day_19=debug`.
+pub const FILTER_VAR: &str = "RUST_LOG";
+
+/// The flag which turns on debug messages when `FILTER_VAR` isn't set.
+pub const VERBOSE_FLAG: &str = "--verbose";
+
+/// What to log: a level for everything, and levels for particular modules and those inside them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl Filter {
+    /// Parses comma-separated directives, each either a level or `module=level`; later
+    /// directives win, and any which don't parse are ignored. Without a bare level, `default`
+    /// applies to modules not mentioned.
+    pub fn parse(spec: &str, default: LevelFilter) -> Filter {
+        let mut filter = Filter {
+            default,
+            modules: vec![],
+        };
+        for directive in spec.split(',').map(str::trim) {
+            match directive.split_once('=') {
+                None => {
+                    if let Ok(level) = directive.parse() {
+                        filter.default = level;
+                    }
+                }
+                Some((module, level)) => {
+                    if let Ok(level) = level.trim().parse() {
+                        filter.modules.push((module.trim().to_string(), level));
+                    }
+                }
+            }
+        }
+        filter
+    }
+
+    /// The level for messages from `target`, which is a module path: that of the last directive
+    /// naming the module or one containing it.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .rev()
+            .find(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    /// The most verbose level logged anywhere.
+    fn max(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+/// Writes each message to stderr as "[LEVEL target] message".
+struct Logger(Filter);
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.0.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Sends log messages to stderr. `FILTER_VAR` says which if it's set; otherwise warnings and
+/// errors are logged, and debug messages too if `VERBOSE_FLAG` is among the arguments. The flag
+/// is removed from `args`, so the binary's own argument parsing needn't know about it.
+///
+/// Only the first call installs a logger; later ones just remove the flag.
+pub fn init(args: &mut Vec<String>) {
+    let verbose = args.iter().any(|arg| arg == VERBOSE_FLAG);
+    args.retain(|arg| arg != VERBOSE_FLAG);
+
+    let default = if verbose {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Warn
+    };
+    let spec = std::env::var(FILTER_VAR).unwrap_or_default();
+    let filter = Filter::parse(&spec, default);
+    let max = filter.max();
+    if log::set_boxed_logger(Box::new(Logger(filter))).is_ok() {
+        log::set_max_level(max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter::parse("", LevelFilter::Warn);
+        assert_eq!(filter.level("day_19::day_19"), LevelFilter::Warn);
+        assert_eq!(filter.max(), LevelFilter::Warn);
+
+        let filter = Filter::parse("info, day_19=debug,day_1=trace,nonsense", LevelFilter::Warn);
+        assert_eq!(filter.level("day_19::day_19"), LevelFilter::Debug);
+        assert_eq!(filter.level("day_19"), LevelFilter::Debug);
+        assert_eq!(filter.level("day_1::day_1"), LevelFilter::Trace);
+        assert_eq!(filter.level("day_17::day_17"), LevelFilter::Info);
+        assert_eq!(filter.max(), LevelFilter::Trace);
+
+        let filter = Filter::parse("day_1=off,day_1::day_1=error", LevelFilter::Debug);
+        assert_eq!(filter.level("day_1::day_1"), LevelFilter::Error);
+        assert_eq!(filter.level("day_1::other"), LevelFilter::Off);
+    }
+
+    #[test]
+    fn test_init() {
+        let mut args = vec![
+            "day_1".to_string(),
+            "--verbose".to_string(),
+            "input.txt".to_string(),
+        ];
+        init(&mut args);
+        assert_eq!(args, vec!["day_1", "input.txt"]);
+    }
+}
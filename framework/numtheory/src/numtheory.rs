@@ -0,0 +1,266 @@
+use std::ops::{Div, Mul, Rem};
+
+/// The primitive integer types, as far as `gcd` and `lcm` need them.
+pub trait Integer:
+    Copy + PartialEq + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    /// The absolute value; unsigned types are their own.
+    fn magnitude(self) -> Self;
+}
+
+macro_rules! integers {
+    ($magnitude:expr; $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: $t = 0;
+            fn magnitude(self) -> $t {
+                $magnitude(self)
+            }
+        }
+    )*};
+}
+
+integers!(|x: Self| x.abs(); i8, i16, i32, i64, i128, isize);
+integers!(|x: Self| x; u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor, which is never negative; `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.magnitude()
+}
+
+/// The least common multiple, which is never negative; it is 0 if either number is.
+///
+/// # Panics
+///
+/// Panics in debug builds if the result overflows, as multiplication does.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).magnitude()
+}
+
+/// The extended Euclidean algorithm: `(g, x, y)` such that `a * x + b * y == g`, where `g` is
+/// `gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` reduced into `0..modulus`.
+///
+/// # Panics
+///
+/// Panics if the modulus is 0.
+pub fn residue(x: i128, modulus: u128) -> u128 {
+    assert!(modulus > 0, "modulus must be positive");
+    match i128::try_from(modulus) {
+        Ok(modulus) => x.rem_euclid(modulus) as u128,
+        // The modulus is bigger than any i128, so only negative numbers need changing.
+        Err(_) if x >= 0 => x as u128,
+        Err(_) => modulus - x.unsigned_abs(),
+    }
+}
+
+/// The inverse of `a` modulo `modulus`, in `0..modulus`, if they are coprime.
+///
+/// # Panics
+///
+/// Panics if the modulus is not positive.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "modulus must be positive");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `(a + b) % modulus`, for `a` and `b` already reduced, without overflowing.
+pub fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    debug_assert!(a < modulus && b < modulus);
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// `(a * b) % modulus`, without overflowing however big the modulus.
+///
+/// # Panics
+///
+/// Panics if the modulus is 0.
+pub fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    assert!(modulus > 0, "modulus must be positive");
+    let (a, b) = (a % modulus, b % modulus);
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // Double and add, from the top bit of `b` down.
+    let mut result = 0;
+    for bit in (0..u128::BITS - b.leading_zeros()).rev() {
+        result = add_mod(result, result, modulus);
+        if b >> bit & 1 == 1 {
+            result = add_mod(result, a, modulus);
+        }
+    }
+    result
+}
+
+/// `base.pow(exponent) % modulus`, by repeated squaring.
+///
+/// # Panics
+///
+/// Panics if the modulus is 0.
+pub fn pow_mod(base: u128, exponent: u128, modulus: u128) -> u128 {
+    let mut result = 1 % modulus;
+    let mut square = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = mul_mod(result, square, modulus);
+        }
+        square = mul_mod(square, square, modulus);
+        exponent /= 2;
+    }
+    result
+}
+
+/// The Chinese remainder theorem: given congruences `x ≡ r (mod m)` as pairs `(r, m)`, the
+/// `(x, lcm of the moduli)` with `x` the smallest non-negative solution, or None if there is no
+/// solution. The moduli need not be coprime. No congruences at all are solved by `(0, 1)`.
+///
+/// # Panics
+///
+/// Panics if any modulus is not positive, or if the least common multiple of the moduli does not
+/// fit in an i128.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut solution = (0, 1);
+    for &(r2, m2) in congruences {
+        assert!(m2 > 0, "modulus must be positive");
+        let (r1, m1) = solution;
+        let r2 = r2.rem_euclid(m2);
+        let g = gcd(m1, m2);
+        if (r2 - r1).rem_euclid(g) != 0 {
+            return None;
+        }
+        let modulus = (m1 / g)
+            .checked_mul(m2)
+            .expect("lcm of the moduli must fit in an i128");
+        // x = r1 + m1 * t, where (m1 / g) * t ≡ (r2 - r1) / g (mod m2 / g).
+        let step = m2 / g;
+        let inverse = mod_inverse(m1 / g, step).expect("m1 / g and m2 / g are coprime");
+        let t = mul_mod(
+            residue((r2 - r1) / g, step as u128),
+            inverse as u128,
+            step as u128,
+        );
+        // m1 * t < m1 * step == modulus, so none of this overflows.
+        solution = (
+            add_mod(r1 as u128, (m1 as u128) * t, modulus as u128) as i128,
+            modulus,
+        );
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0i32, -5), 5);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(-4i64, 6), 12);
+        assert_eq!(lcm(0u32, 6), 0);
+        assert_eq!([18u64, 28, 44].into_iter().fold(1, lcm), 2772);
+    }
+
+    #[test]
+    fn test_inverses() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 10), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+        assert_eq!(residue(-1, 10), 9);
+        assert_eq!(residue(-1, u128::MAX), u128::MAX - 1);
+        assert_eq!(residue(i128::MIN, 1 << 127), 0);
+    }
+
+    #[test]
+    fn test_big_moduli() {
+        // u128::MAX is 1 more than a multiple of u128::MAX - 1.
+        assert_eq!(mul_mod(u128::MAX, u128::MAX, u128::MAX - 1), 1);
+        assert_eq!(
+            add_mod(u128::MAX - 2, u128::MAX - 2, u128::MAX - 1),
+            u128::MAX - 3
+        );
+
+        // Fermat's little theorem for the Mersenne prime 2^127 - 1.
+        let p = (1 << 127) - 1;
+        assert_eq!(pow_mod(3, p - 1, p), 1);
+        let inverse = mod_inverse(3, p as i128).unwrap() as u128;
+        assert_eq!(mul_mod(3, inverse, p), 1);
+        assert_eq!(pow_mod(3, p - 2, p), inverse);
+        assert_eq!(pow_mod(0, 0, 7), 1);
+        assert_eq!(pow_mod(5, 3, 1), 0);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        // Two primes near 2^62, whose product is near 2^124.
+        let (p, q) = (4_611_686_018_427_387_847, 4_611_686_018_427_387_817);
+        let (x, modulus) = crt(&[(1, p), (2, q)]).unwrap();
+        assert_eq!(modulus, p * q);
+        assert_eq!((x % p, x % q), (1, 2));
+    }
+
+    /// A u128 which is often bigger than a u64.
+    fn wide() -> impl Strategy<Value = u128> {
+        (any::<u64>(), any::<u64>(), any::<bool>()).prop_map(|(high, low, wide)| {
+            u128::from(high) << if wide { 64 } else { 0 } | u128::from(low)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn mul_mod_matches_bigint(a in wide(), b in wide(), m in wide()) {
+            prop_assume!(m > 0);
+            let expected = BigInt::from(a) * BigInt::from(b) % BigInt::from(m);
+            prop_assert_eq!(BigInt::from(mul_mod(a, b, m)), expected);
+        }
+
+        #[test]
+        fn bezout(a in -1_000_000i64..1_000_000, b in -1_000_000i64..1_000_000) {
+            let (a, b) = (i128::from(a), i128::from(b));
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(g, gcd(a, b));
+            prop_assert_eq!(a * x + b * y, g);
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use grid::grid::Grid;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum OcrError {
+    #[error(
+        "letters are {SMALL_HEIGHT} or {LARGE_HEIGHT} pixels high, but the image is {actual} pixels high"
+    )]
+    BadHeight { actual: usize },
+    #[error("unrecognised glyph starting at column {col}")]
+    UnknownGlyph { col: usize },
+}
+
+const SMALL_HEIGHT: usize = 6;
+const LARGE_HEIGHT: usize = 10;
+
+/// The letters of the standard 4x6 Advent of Code font which have been observed in the wild. Each
+/// sits in a cell five pixels wide, which a Y fills, leaving no blank column after it.
+const SMALL: [(char, [&str; SMALL_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The letters of the larger 6x10 Advent of Code font which have been observed in the wild.
+const LARGE: [(char, [&str; LARGE_HEIGHT]); 15] = [
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+fn lit<const HEIGHT: usize>(glyph: &[&str; HEIGHT], row: usize, col: usize) -> bool {
+    glyph[row].as_bytes()[col] == b'#'
+}
+
+/// The first column of `glyph` with a lit pixel, and how many columns on its last lit one is.
+fn glyph_extent<const HEIGHT: usize>(glyph: &[&str; HEIGHT]) -> Option<(usize, usize)> {
+    let glyph_width = glyph[0].len();
+    let first = (0..glyph_width).find(|&col| (0..HEIGHT).any(|row| lit(glyph, row, col)))?;
+    let last = (0..glyph_width).rfind(|&col| (0..HEIGHT).any(|row| lit(glyph, row, col)))?;
+    Some((first, last - first + 1))
+}
+
+/// Whether `glyph`, with its blank margins removed, is exactly the `width` columns of `image`
+/// starting at `start`.
+fn glyph_matches<const HEIGHT: usize>(
+    glyph: &[&str; HEIGHT],
+    image: &Grid<bool>,
+    start: usize,
+    width: usize,
+) -> bool {
+    match glyph_extent(glyph) {
+        Some((first, glyph_width)) if glyph_width == width => (0..HEIGHT).all(|row| {
+            (0..width).all(|dc| lit(glyph, row, first + dc) == image[(row, start + dc)])
+        }),
+        _ => false,
+    }
+}
+
+fn recognize_font<const HEIGHT: usize>(
+    font: &[(char, [&str; HEIGHT])],
+    image: &Grid<bool>,
+) -> Result<String, OcrError> {
+    let column_lit = |col: usize| image.column(col).any(|lit| *lit);
+    let width = image.col_count();
+    let mut result = String::new();
+    let mut col = 0;
+    while col < width {
+        if !column_lit(col) {
+            col += 1;
+            continue;
+        }
+        let mut start = col;
+        while col < width && column_lit(col) {
+            col += 1;
+        }
+        while start < col {
+            // A letter which fills its cell runs straight into the next one, so failing the
+            // whole run, take the widest letter at its start.
+            let whole = font
+                .iter()
+                .find(|(_, glyph)| glyph_matches(glyph, image, start, col - start))
+                .map(|(letter, _)| (letter, col - start));
+            let (letter, letter_width) = whole
+                .or_else(|| {
+                    font.iter()
+                        .filter_map(|(letter, glyph)| {
+                            let (_, glyph_width) = glyph_extent(glyph)?;
+                            (glyph_width < col - start
+                                && glyph_matches(glyph, image, start, glyph_width))
+                            .then_some((letter, glyph_width))
+                        })
+                        .max_by_key(|&(_, glyph_width)| glyph_width)
+                })
+                .ok_or(OcrError::UnknownGlyph { col: start })?;
+            result.push(*letter);
+            start += letter_width;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Read the letters out of an image rendered in one of the standard Advent of Code fonts, which
+/// is chosen by the height of the image. Letters are delimited by columns with no lit pixels.
+pub fn recognize(image: &Grid<bool>) -> Result<String, OcrError> {
+    match image.row_count() {
+        SMALL_HEIGHT => recognize_font(&SMALL, image),
+        LARGE_HEIGHT => recognize_font(&LARGE, image),
+        actual => Err(OcrError::BadHeight { actual }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small() {
+        let image = Grid::parse(
+            "\
+#..#.####..##..####.#..#.
+#..#....#.#..#....#.#..#.
+####...#..#......#..#..#.
+#..#..#...#.....#...#..#.
+#..#.#....#..#.#....#..#.
+#..#.####..##..####..##..",
+        )
+        .unwrap();
+        assert_eq!(recognize(&image), Ok("HZCZU".to_string()));
+
+        // Each Y runs into the letter after it.
+        let image = Grid::parse(
+            "\
+#...#.##..###..#...#####.
+#...##..#.#..#.#...#...#.
+.#.#.#....#..#..#.#...#..
+..#..#.##.###....#...#...
+..#..#..#.#.#....#..#....
+..#...###.#..#...#..####.",
+        )
+        .unwrap();
+        assert_eq!(recognize(&image), Ok("YGRYZ".to_string()));
+
+        let alphabet = Grid::from_fn(SMALL_HEIGHT, SMALL.len() * 6, |(row, col)| {
+            let (_, glyph) = SMALL[col / 6];
+            glyph[row].as_bytes().get(col % 6) == Some(&b'#')
+        });
+        let letters: String = SMALL.iter().map(|(c, _)| c).collect();
+        assert_eq!(recognize(&alphabet), Ok(letters));
+    }
+
+    #[test]
+    fn test_large() {
+        let alphabet = Grid::from_fn(LARGE_HEIGHT, LARGE.len() * 8, |(row, col)| {
+            let (_, glyph) = LARGE[col / 8];
+            glyph[row].as_bytes().get(col % 8) == Some(&b'#')
+        });
+        let letters: String = LARGE.iter().map(|(c, _)| c).collect();
+        assert_eq!(recognize(&alphabet), Ok(letters));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            recognize(&Grid::new(2, 2, true)),
+            Err(OcrError::BadHeight { actual: 2 })
+        );
+        assert_eq!(
+            recognize(&Grid::from_fn(6, 3, |(_, col)| col == 1)),
+            Err(OcrError::UnknownGlyph { col: 1 })
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use grid::grid::{Grid, ParseError as GridError};
+use thiserror::Error;
+
+/// Lines and columns count from 1, and columns are in characters.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("input was empty")]
+    Empty,
+    #[error("line {line}, column {column}: {message}")]
+    Invalid {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    #[error("line {line} had length {actual}, but previous lines had length {expected}")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("expected {expected} {what}, but found {actual}")]
+    WrongCount {
+        what: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// Rows of the grid count from the first line which isn't blank.
+impl From<GridError> for ParseError {
+    fn from(e: GridError) -> Self {
+        match e {
+            GridError::Empty => ParseError::Empty,
+            GridError::Ragged {
+                row,
+                expected,
+                actual,
+            } => ParseError::Ragged {
+                line: row + 1,
+                expected,
+                actual,
+            },
+            GridError::InvalidChar { row, col, c } => ParseError::Invalid {
+                line: row + 1,
+                column: col + 1,
+                message: format!("unexpected character {c:?}"),
+            },
+        }
+    }
+}
+
+/// The (line, column) at which `token` starts within `input`.
+///
+/// # Panics
+///
+/// Panics if `token` is not a slice of `input`.
+pub fn locate(input: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset + token.len() <= input.len())
+        .expect("token must be a slice of the input");
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// An error about `token`, which must be a slice of `input`.
+pub fn error_at<M: Display>(input: &str, token: &str, message: M) -> ParseError {
+    let (line, column) = locate(input, token);
+    ParseError::Invalid {
+        line,
+        column,
+        message: message.to_string(),
+    }
+}
+
+/// Parses `token`, which must be a slice of `input`, saying where it was if it can't be parsed.
+pub fn token<T>(input: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|e| error_at(input, token, format!("could not parse {token:?}: {e}")))
+}
+
+/// Parses a single line of values separated by commas, ignoring surrounding whitespace.
+pub fn comma_separated<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    trimmed.split(',').map(|t| token(input, t.trim())).collect()
+}
+
+/// Parses each line with `parse_line`, which is given the line without its surrounding
+/// whitespace; blank lines at the start and end are ignored.
+pub fn lines_with<'a, T, F>(input: &'a str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    trimmed.lines().map(|l| parse_line(l.trim())).collect()
+}
+
+/// Parses one value per line; see `lines_with`.
+pub fn lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lines_with(input, |l| token(input, l))
+}
+
+/// Parses a string of decimal digits, ignoring surrounding whitespace.
+pub fn digits(input: &str) -> Result<Vec<u8>, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    trimmed
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                error_at(
+                    input,
+                    &trimmed[i..i + c.len_utf8()],
+                    format!("expected a digit, but found {c:?}"),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Parses a grid with one character per cell; see `Grid::parse_with`.
+pub fn grid<T, F>(input: &str, parse_cell: F) -> Result<Grid<T>, ParseError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    Grid::parse_with(input, parse_cell).map_err(ParseError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use strategies::strategies::{format_digits, format_program};
+
+    #[test]
+    fn test_comma_separated() {
+        assert_eq!(comma_separated::<i64>("1,-2, 3\n"), Ok(vec![1, -2, 3]));
+        assert_eq!(comma_separated::<i64>(" \n"), Err(ParseError::Empty));
+        assert_eq!(
+            comma_separated::<u8>("\n1,2,x3"),
+            Err(ParseError::Invalid {
+                line: 2,
+                column: 5,
+                message: "could not parse \"x3\": invalid digit found in string".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines::<u32>("12\n 14 \n1969\n"), Ok(vec![12, 14, 1969]));
+        let error = lines::<u32>("12\n\n1969").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: could not parse \"\": cannot parse integer from empty string"
+        );
+
+        let input = "a)b\nb-c";
+        let edges = lines_with(input, |l| {
+            l.split_once(')')
+                .ok_or_else(|| error_at(input, l, "expected ')'"))
+        });
+        assert_eq!(
+            edges,
+            Err(ParseError::Invalid {
+                line: 2,
+                column: 1,
+                message: "expected ')'".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "héllo\nwörld";
+        assert_eq!(locate(input, &input[7..]), (2, 1));
+        assert_eq!(locate(input, &input[10..]), (2, 3));
+        assert_eq!(locate(input, &input[3..]), (1, 3));
+    }
+
+    #[test]
+    fn test_digits_and_grid() {
+        assert_eq!(digits("0123\n"), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(
+            digits("01a3"),
+            Err(ParseError::Invalid {
+                line: 1,
+                column: 3,
+                message: "expected a digit, but found 'a'".to_string()
+            })
+        );
+        let parsed = grid(".#\n#.", |c| {
+            (c == '#').then_some(true).or((c == '.').then_some(false))
+        });
+        assert_eq!(parsed.unwrap().to_string(), ".#\n#.\n");
+        assert_eq!(
+            grid("..\n.", |_| Some(())),
+            Err(ParseError::Ragged {
+                line: 2,
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn comma_separated_roundtrips(values in prop::collection::vec(any::<i64>(), 1..20)) {
+            prop_assert_eq!(comma_separated::<i64>(&format_program(&values)), Ok(values));
+        }
+
+        #[test]
+        fn digits_roundtrip(values in strategies::strategies::digits(1..=50)) {
+            prop_assert_eq!(digits(&format_digits(&values)), Ok(values));
+        }
+    }
+}
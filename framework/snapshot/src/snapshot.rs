@@ -0,0 +1,209 @@
+use std::fmt::Display;
+
+pub use insta;
+
+/// The name a day's snapshot is stored under: `day_8_part_2`, or `day_8_part_2_example` with a
+/// label. Snapshots are named this way rather than after the test and module which took them,
+/// so that tidying up the tests doesn't orphan the snapshots.
+pub fn name(day: u8, part: u8, label: Option<&str>) -> String {
+    match label {
+        None => format!("day_{day}_part_{part}"),
+        Some(label) => format!("day_{day}_part_{part}_{label}"),
+    }
+}
+
+/// The value's rendering as a grid: one line per row, without the final newline.
+///
+/// # Panics
+///
+/// Panics if the rows are not all the same length.
+pub fn grid_text<T: Display>(grid: T) -> String {
+    let text = grid.to_string();
+    let rows: Vec<&str> = text.lines().collect();
+    if let Some(first) = rows.first() {
+        let width = first.chars().count();
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(
+                row.chars().count(),
+                width,
+                "row {i} is a different length from the first row:\n{text}"
+            );
+        }
+    }
+    rows.join("\n")
+}
+
+enum Rule {
+    Literal { from: String, to: String },
+    Durations,
+}
+
+/// What to hide in a snapshot because it changes from run to run, like timings or paths.
+/// Redactions apply in the order they were added.
+#[derive(Default)]
+pub struct Redactions {
+    rules: Vec<Rule>,
+}
+
+pub const DURATION_PLACEHOLDER: &str = "[duration]";
+
+/// The units `Duration`'s Debug output uses, longest first so that "ms" isn't read as "m".
+const DURATION_UNITS: [&str; 4] = ["ns", "µs", "ms", "s"];
+
+/// If a duration like "12.5ms" starts at this byte, the byte just past it.
+fn duration_end(text: &str, start: usize) -> Option<usize> {
+    let rest = &text[start..];
+    let number = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(rest.len());
+    if number == 0 || !rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let unit = DURATION_UNITS
+        .iter()
+        .find(|unit| rest[number..].starts_with(*unit))?;
+    let end = start + number + unit.len();
+    match text[end..].chars().next() {
+        Some(c) if is_word_char(c) => None,
+        _ => Some(end),
+    }
+}
+
+/// Characters which, next to a number, make it part of something other than a duration.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.'
+}
+
+fn redact_durations(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    let mut previous = None;
+    while let Some(c) = text[i..].chars().next() {
+        let at_boundary = !previous.is_some_and(is_word_char);
+        match duration_end(text, i).filter(|_| at_boundary) {
+            Some(end) => {
+                result.push_str(DURATION_PLACEHOLDER);
+                previous = text[..end].chars().next_back();
+                i = end;
+            }
+            None => {
+                result.push(c);
+                previous = Some(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+    result
+}
+
+impl Redactions {
+    pub fn new() -> Redactions {
+        Redactions::default()
+    }
+
+    /// Replace every occurrence of `from` with `to`.
+    pub fn literal<S, T>(mut self, from: S, to: T) -> Redactions
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.rules.push(Rule::Literal {
+            from: from.into(),
+            to: to.into(),
+        });
+        self
+    }
+
+    /// Replace durations as `Duration`'s Debug output writes them, like "1.2ms", with
+    /// `DURATION_PLACEHOLDER`.
+    pub fn durations(mut self) -> Redactions {
+        self.rules.push(Rule::Durations);
+        self
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        self.rules
+            .iter()
+            .fold(text.to_string(), |text, rule| match rule {
+                Rule::Literal { from, to } => text.replace(from.as_str(), to),
+                Rule::Durations => redact_durations(&text),
+            })
+    }
+}
+
+/// Check a value against the snapshot of the given name, which is the whole of the file name:
+/// it isn't prefixed with the module the test is in.
+#[macro_export]
+macro_rules! assert_named_snapshot {
+    ($name:expr, $value:expr $(,)?) => {{
+        let name: &str = &$name;
+        let mut settings = $crate::snapshot::insta::Settings::clone_current();
+        settings.set_prepend_module_to_snapshot(false);
+        settings.bind(|| $crate::snapshot::insta::assert_snapshot!(name, $value));
+    }};
+}
+
+/// Check a grid, or anything which displays as one, against the day and part's snapshot; see
+/// `name` and `grid_text`.
+#[macro_export]
+macro_rules! assert_grid_snapshot {
+    ($day:expr, $part:expr, $grid:expr $(,)?) => {
+        $crate::assert_named_snapshot!(
+            $crate::snapshot::name($day, $part, None),
+            $crate::snapshot::grid_text($grid)
+        )
+    };
+    ($day:expr, $part:expr, $label:expr, $grid:expr $(,)?) => {
+        $crate::assert_named_snapshot!(
+            $crate::snapshot::name($day, $part, Some($label)),
+            $crate::snapshot::grid_text($grid)
+        )
+    };
+}
+
+/// Check text against the named snapshot once the redactions have been applied.
+#[macro_export]
+macro_rules! assert_redacted_snapshot {
+    ($name:expr, $value:expr, $redactions:expr $(,)?) => {
+        $crate::assert_named_snapshot!($name, ($redactions).apply(&$value.to_string()))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(name(8, 2, None), "day_8_part_2");
+        assert_eq!(name(11, 1, Some("example")), "day_11_part_1_example");
+    }
+
+    #[test]
+    fn grids() {
+        assert_eq!(grid_text(".X\nX.\n"), ".X\nX.");
+        assert_eq!(grid_text(""), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 is a different length")]
+    fn ragged_grids() {
+        grid_text(".X\nX\n");
+    }
+
+    #[test]
+    fn redaction() {
+        let redactions = Redactions::new()
+            .literal("/home/someone", "[home]")
+            .durations();
+        assert_eq!(
+            redactions.apply("day 1 in 12.5ms, day 2 in 3s and 150µs, from /home/someone/in"),
+            "day 1 in [duration], day 2 in [duration] and [duration], from [home]/in"
+        );
+        // Not durations: part of a word, a bare number, or an unknown unit.
+        assert_eq!(
+            redactions.apply("day_12s 3 parts 4m 2.5"),
+            "day_12s 3 parts 4m 2.5"
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::fmt::{Debug, Display};
+
+use intcode::intcode::MachineExecutionError;
+pub use parse::parse::ParseError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SolveError {
+    #[error("{0}")]
+    Failed(Box<dyn Error + Send + Sync>),
+    #[error("there is no answer: {0}")]
+    NoAnswer(&'static str),
+    #[error("this puzzle has no such part")]
+    NoSuchPart,
+}
+
+/// What can go wrong in the days without an error type of their own: mostly inputs which aren't
+/// what the puzzle promised.
+#[derive(Error, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    Machine(#[from] MachineExecutionError),
+    #[error("the program terminated while {0}")]
+    Terminated(&'static str),
+    #[error("the program asked for input while {0}")]
+    UnexpectedInput(&'static str),
+    #[error("the program output {0} while {1}")]
+    UnexpectedOutput(i64, &'static str),
+    #[error("expected {expected} outputs, but the program gave {actual}")]
+    OutputCount { expected: usize, actual: usize },
+    #[error("the input is invalid: {0}")]
+    InvalidInput(String),
+    #[error("there is no answer: {0}")]
+    NoAnswer(&'static str),
+}
+
+impl From<AocError> for SolveError {
+    fn from(e: AocError) -> SolveError {
+        SolveError::Failed(Box::new(e))
+    }
+}
+
+/// What a binary's `main` returns on failure, so that the error is reported by its message
+/// rather than by its `Debug` form. Anything displayable converts into one.
+pub struct Report(String);
+
+impl<E: Display> From<E> for Report {
+    fn from(e: E) -> Report {
+        Report(e.to_string())
+    }
+}
+
+impl Debug for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The answer to one part of a puzzle, rendered as the puzzle would want it typed in.
+pub type Answer = Result<String, SolveError>;
+
+/// Where a solution reports what lies behind its answers, such as the crossing it chose or the
+/// path it took.
+pub trait Explainer {
+    /// Report one named result. Some are pictures over several lines, like a game's board.
+    fn note(&mut self, name: &str, value: &dyn Display);
+}
+
+/// One result reported to an `Explainer`, as collected by `Vec<Note>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Note {
+    pub name: String,
+    pub value: String,
+}
+
+impl Explainer for Vec<Note> {
+    fn note(&mut self, name: &str, value: &dyn Display) {
+        self.push(Note {
+            name: name.to_string(),
+            value: value.to_string(),
+        });
+    }
+}
+
+/// One day's puzzle, solved against the puzzle's own parameters (such as the size of an image or
+/// the output to search for) wherever the day's functions would let them vary.
+pub trait Solution {
+    /// The input, parsed; it may borrow from the text.
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> Answer;
+
+    /// Day 25 has only one part.
+    fn part_2(_: &Self::Parsed<'_>) -> Answer {
+        Err(SolveError::NoSuchPart)
+    }
+
+    /// Report the workings behind the answers to `explainer`; most days report nothing.
+    fn explain(_: &Self::Parsed<'_>, _: &mut dyn Explainer) -> Result<(), SolveError> {
+        Ok(())
+    }
+}
+
+/// The contents of the input file whose path is a day binary's first argument.
+pub fn read_input(args: &[String]) -> Result<String, Report> {
+    let path = args
+        .get(1)
+        .ok_or_else(|| Report::from("Required the first arg to be a path to an input file"))?;
+    std::fs::read_to_string(path)
+        .map_err(|e| Report::from(format!("Error while accessing path {path} : {e}")))
+}
+
+/// Everything a day binary's `main` does when it has no options of its own: solve the input
+/// file named by its first argument, printing the answer to each part.
+pub fn run_main<S: Solution>(args: &[String]) -> Result<(), Report> {
+    let input_str = read_input(args)?;
+    let parsed = S::parse(&input_str)
+        .map_err(|e| Report::from(format!("Error while parsing input: {e}")))?;
+    println!("part 1 => {}", S::part_1(&parsed)?);
+    match S::part_2(&parsed) {
+        Err(SolveError::NoSuchPart) => {}
+        answer => println!("part 2 => {}", answer?),
+    }
+    Ok(())
+}
+
+/// Something to do with one day's `Solution`, whichever day that turns out to be: how a runner
+/// gets at a `Year`'s days without naming their types.
+pub trait Visit {
+    type Output;
+
+    fn visit<S: Solution>(self) -> Self::Output;
+}
+
+/// One year's puzzles, whose solutions a runner looks up by the number of the day.
+pub trait Year {
+    /// The year the puzzles were set, like 2019.
+    const YEAR: u16;
+
+    /// The days which have a solution, in order.
+    const DAYS: &'static [u8];
+
+    /// The parts which `day` has; Christmas Day has only one.
+    fn parts(day: u8) -> &'static [u8] {
+        if day == 25 {
+            &[1]
+        } else {
+            &[1, 2]
+        }
+    }
+
+    /// What `visitor` makes of `day`'s `Solution`; None if the year has no solution for `day`.
+    fn visit<V: Visit>(day: u8, visitor: V) -> Option<V::Output>;
+}
+
+/// An answer which is always available.
+pub fn answer<T: Display>(answer: T) -> Answer {
+    Ok(answer.to_string())
+}
+
+/// An answer whose computation can fail.
+pub fn solved<T, E>(result: Result<T, E>) -> Answer
+where
+    T: Display,
+    E: Error + Send + Sync + 'static,
+{
+    match result {
+        Ok(answer) => Ok(answer.to_string()),
+        Err(e) => Err(SolveError::Failed(Box::new(e))),
+    }
+}
+
+/// An answer which might not exist, for the reason given by `otherwise`.
+pub fn found<T: Display>(answer: Option<T>, otherwise: &'static str) -> Answer {
+    match answer {
+        Some(answer) => Ok(answer.to_string()),
+        None => Err(SolveError::NoAnswer(otherwise)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            parse::parse::comma_separated(input)
+        }
+
+        fn part_1(parsed: &Vec<u32>) -> Answer {
+            answer(parsed.iter().sum::<u32>())
+        }
+
+        fn explain(parsed: &Vec<u32>, explainer: &mut dyn Explainer) -> Result<(), SolveError> {
+            let largest = parsed
+                .iter()
+                .max()
+                .ok_or(AocError::NoAnswer("no numbers"))?;
+            explainer.note("largest", largest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        let parsed = Sum::parse("1,2,3").unwrap();
+        assert_eq!(Sum::part_1(&parsed).unwrap(), "6");
+        assert!(matches!(Sum::part_2(&parsed), Err(SolveError::NoSuchPart)));
+        assert!(Sum::parse("1,x").is_err());
+
+        let mut notes: Vec<Note> = vec![];
+        Sum::explain(&parsed, &mut notes).unwrap();
+        assert_eq!(
+            notes,
+            [Note {
+                name: "largest".to_string(),
+                value: "3".to_string()
+            }]
+        );
+        assert_eq!(
+            Sum::explain(&vec![], &mut notes).unwrap_err().to_string(),
+            "there is no answer: no numbers"
+        );
+
+        assert_eq!(
+            found(None::<u32>, "no route").unwrap_err().to_string(),
+            "there is no answer: no route"
+        );
+        let failed: Result<u32, std::fmt::Error> = Err(std::fmt::Error);
+        assert_eq!(
+            solved(failed).unwrap_err().to_string(),
+            "an error occurred when formatting an argument"
+        );
+    }
+
+    #[test]
+    fn test_run_main() {
+        let path = std::env::temp_dir().join(format!("solution-test-{}.txt", std::process::id()));
+        std::fs::write(&path, "1,2,3").unwrap();
+        let args = |rest: &[&str]| -> Vec<String> {
+            std::iter::once("day")
+                .chain(rest.iter().copied())
+                .map(String::from)
+                .collect()
+        };
+        let path_str = path.to_str().unwrap();
+        assert_eq!(read_input(&args(&[path_str])).unwrap(), "1,2,3");
+        assert!(run_main::<Sum>(&args(&[path_str])).is_ok());
+
+        std::fs::write(&path, "1,x").unwrap();
+        let error = run_main::<Sum>(&args(&[path_str])).unwrap_err();
+        assert!(format!("{error:?}").starts_with("Error while parsing input: "));
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            format!("{:?}", read_input(&args(&[])).unwrap_err()),
+            "Required the first arg to be a path to an input file"
+        );
+        assert!(format!("{:?}", read_input(&args(&[path_str])).unwrap_err())
+            .starts_with("Error while accessing path "));
+    }
+}
@@ -0,0 +1,232 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use std::ops::RangeInclusive;
+
+/// How many memory cells a straight-line program reads and writes, just after its code.
+pub const SCRATCH_CELLS: usize = 8;
+
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    Immediate(i64),
+    Scratch(usize),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Instruction {
+    Add(Operand, Operand, usize),
+    /// Multiplying only by small constants keeps the values from overflowing.
+    Multiply(Operand, i64, usize),
+    Output(Operand),
+}
+
+impl Instruction {
+    fn len(&self) -> usize {
+        match self {
+            Instruction::Output(_) => 2,
+            _ => 4,
+        }
+    }
+}
+
+/// An intcode program which runs straight through and halts, and the outputs it should give.
+#[derive(Clone, Debug)]
+pub struct StraightLine {
+    pub program: Vec<i64>,
+    pub outputs: Vec<i64>,
+}
+
+fn operand() -> impl Strategy<Value = Operand> {
+    prop_oneof![
+        (-100i64..=100).prop_map(Operand::Immediate),
+        (0..SCRATCH_CELLS).prop_map(Operand::Scratch),
+    ]
+}
+
+fn instruction() -> impl Strategy<Value = Instruction> {
+    prop_oneof![
+        (operand(), operand(), 0..SCRATCH_CELLS)
+            .prop_map(|(a, b, dest)| Instruction::Add(a, b, dest)),
+        (operand(), -3i64..=3, 0..SCRATCH_CELLS)
+            .prop_map(|(a, b, dest)| Instruction::Multiply(a, b, dest)),
+        operand().prop_map(Instruction::Output),
+    ]
+}
+
+/// Lay out the instructions, then a halt, then the scratch cells; and work out what the program
+/// outputs by running the instructions directly.
+fn assemble(instructions: &[Instruction], initial: Vec<i64>) -> StraightLine {
+    let base = instructions.iter().map(Instruction::len).sum::<usize>() + 1;
+    let address = |cell: usize| (base + cell) as i64;
+    // The mode digit and the parameter for an operand.
+    let encode = |operand| match operand {
+        Operand::Immediate(value) => (1, value),
+        Operand::Scratch(cell) => (0, address(cell)),
+    };
+    let mut scratch = initial.clone();
+    let value = |scratch: &[i64], operand| match operand {
+        Operand::Immediate(value) => value,
+        Operand::Scratch(cell) => scratch[cell],
+    };
+
+    let mut program = Vec::with_capacity(base + SCRATCH_CELLS);
+    let mut outputs = vec![];
+    for instruction in instructions {
+        match *instruction {
+            Instruction::Add(a, b, dest) => {
+                let ((mode_a, a_param), (mode_b, b_param)) = (encode(a), encode(b));
+                program.extend([
+                    1 + 100 * mode_a + 1000 * mode_b,
+                    a_param,
+                    b_param,
+                    address(dest),
+                ]);
+                scratch[dest] = value(&scratch, a) + value(&scratch, b);
+            }
+            Instruction::Multiply(a, b, dest) => {
+                let (mode_a, a_param) = encode(a);
+                program.extend([2 + 100 * mode_a + 1000, a_param, b, address(dest)]);
+                scratch[dest] = value(&scratch, a) * b;
+            }
+            Instruction::Output(a) => {
+                let (mode, param) = encode(a);
+                program.extend([4 + 100 * mode, param]);
+                outputs.push(value(&scratch, a));
+            }
+        }
+    }
+    program.push(99);
+    program.extend(initial);
+    StraightLine { program, outputs }
+}
+
+/// Intcode programs of additions, multiplications and outputs, in both position and immediate
+/// modes, which read and write only the `SCRATCH_CELLS` cells after their code and then halt.
+/// Each instruction at most triples the largest value around, so up to thirty instructions
+/// stay well within an i64.
+pub fn straight_line_program(max_instructions: usize) -> impl Strategy<Value = StraightLine> {
+    (
+        vec(instruction(), 0..=max_instructions),
+        vec(-100i64..=100, SCRATCH_CELLS),
+    )
+        .prop_map(|(instructions, scratch)| assemble(&instructions, scratch))
+}
+
+/// The program as it appears in a puzzle input.
+pub fn format_program(program: &[i64]) -> String {
+    program
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Day 3's wires: between one and `max_moves` moves up, down, left or right, each between one
+/// and `max_distance` steps long.
+pub fn wire(max_moves: usize, max_distance: u32) -> impl Strategy<Value = Vec<(char, u32)>> {
+    vec(
+        (select(vec!['U', 'D', 'L', 'R']), 1..=max_distance),
+        1..=max_moves,
+    )
+}
+
+/// The wire as it appears in a puzzle input, like "R8,U5,L5".
+pub fn format_wire(wire: &[(char, u32)]) -> String {
+    wire.iter()
+        .map(|(direction, distance)| format!("{direction}{distance}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The name of the `i`th body of an orbit tree: "COM" and then "B1", "B2" and so on.
+pub fn body(i: usize) -> String {
+    if i == 0 {
+        "COM".to_string()
+    } else {
+        format!("B{i}")
+    }
+}
+
+/// Day 6's orbit trees, as (centre, satellite) pairs in no particular order. "COM" is at the
+/// root, and each of between one and `max_satellites` other bodies orbits one named before it.
+pub fn orbits(max_satellites: usize) -> impl Strategy<Value = Vec<(String, String)>> {
+    vec(any::<usize>(), 1..=max_satellites)
+        .prop_map(|choices| {
+            choices
+                .iter()
+                .enumerate()
+                .map(|(i, choice)| (body(choice % (i + 1)), body(i + 1)))
+                .collect::<Vec<_>>()
+        })
+        .prop_shuffle()
+}
+
+/// The orbits as they appear in a puzzle input, one "A)B" per line.
+pub fn format_orbits(orbits: &[(String, String)]) -> String {
+    orbits
+        .iter()
+        .map(|(centre, satellite)| format!("{centre}){satellite}\n"))
+        .collect()
+}
+
+/// Sequences of decimal digits, like day 16's signals.
+pub fn digits(len: RangeInclusive<usize>) -> impl Strategy<Value = Vec<u8>> {
+    vec(0u8..=9, len)
+}
+
+pub fn format_digits(digits: &[u8]) -> String {
+    digits.iter().map(|d| char::from(b'0' + d)).collect()
+}
+
+/// Non-empty rectangular grids, as rows, of up to `max_rows` by `max_cols` cells drawn from
+/// `alphabet`.
+pub fn grid(
+    max_rows: usize,
+    max_cols: usize,
+    alphabet: &'static [char],
+) -> impl Strategy<Value = Vec<Vec<char>>> {
+    (1..=max_rows, 1..=max_cols)
+        .prop_flat_map(move |(rows, cols)| vec(vec(select(alphabet.to_vec()), cols), rows))
+}
+
+/// The grid as it appears in a puzzle input, one line per row.
+pub fn format_grid(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().chain(std::iter::once(&'\n')).collect::<String>())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    proptest! {
+        #[test]
+        fn orbits_form_a_tree(orbits in orbits(30)) {
+            let satellites: HashSet<&String> = orbits.iter().map(|(_, s)| s).collect();
+            prop_assert_eq!(satellites.len(), orbits.len());
+            prop_assert!(!satellites.contains(&body(0)));
+            for (centre, satellite) in &orbits {
+                prop_assert!(centre == &body(0) || satellites.contains(centre));
+                prop_assert_ne!(centre, satellite);
+            }
+        }
+
+        #[test]
+        fn programs_halt_after_their_code(line in straight_line_program(10)) {
+            let halt = line.program.len() - SCRATCH_CELLS - 1;
+            prop_assert_eq!(line.program[halt], 99);
+            prop_assert!(line.outputs.len() <= 10);
+        }
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(format_wire(&[('R', 8), ('U', 5)]), "R8,U5");
+        assert_eq!(format_digits(&[0, 1, 9]), "019");
+        assert_eq!(format_grid(&[vec!['#', '.'], vec!['.', '#']]), "#.\n.#\n");
+        assert_eq!(format_program(&[1, -2, 99]), "1,-2,99");
+        assert_eq!(format_orbits(&[(body(0), body(1))]), "COM)B1\n".to_string());
+    }
+}
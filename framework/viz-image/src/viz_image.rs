@@ -0,0 +1,134 @@
+use grid::grid::Grid;
+use std::{fs::File, io::BufWriter, path::Path};
+use thiserror::Error;
+
+pub use image::{Rgba, RgbaImage};
+
+pub const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+pub const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+pub const GREY: Rgba<u8> = Rgba([128, 128, 128, 255]);
+pub const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+pub const BLUE: Rgba<u8> = Rgba([0, 128, 255, 255]);
+pub const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error(transparent)]
+    Image(#[from] image::ImageError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// How to colour each kind of cell. Any `Fn(&T) -> Rgba<u8>` is a palette.
+pub trait Palette<T> {
+    fn colour(&self, cell: &T) -> Rgba<u8>;
+}
+
+impl<T, F> Palette<T> for F
+where
+    F: Fn(&T) -> Rgba<u8>,
+{
+    fn colour(&self, cell: &T) -> Rgba<u8> {
+        self(cell)
+    }
+}
+
+/// A palette for boolean cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TwoTone {
+    pub on: Rgba<u8>,
+    pub off: Rgba<u8>,
+}
+
+/// White on black.
+impl Default for TwoTone {
+    fn default() -> TwoTone {
+        TwoTone {
+            on: WHITE,
+            off: BLACK,
+        }
+    }
+}
+
+impl Palette<bool> for TwoTone {
+    fn colour(&self, cell: &bool) -> Rgba<u8> {
+        if *cell {
+            self.on
+        } else {
+            self.off
+        }
+    }
+}
+
+/// An image `rows` cells high and `cols` cells wide, with each cell drawn as a `scale`-by-`scale`
+/// square coloured `pixel(row, col)`.
+pub fn draw<F>(rows: usize, cols: usize, scale: u32, mut pixel: F) -> RgbaImage
+where
+    F: FnMut(usize, usize) -> Rgba<u8>,
+{
+    RgbaImage::from_fn(cols as u32 * scale, rows as u32 * scale, |x, y| {
+        pixel((y / scale) as usize, (x / scale) as usize)
+    })
+}
+
+/// The grid with each cell drawn as a `scale`-by-`scale` square.
+pub fn grid_image<T, P>(grid: &Grid<T>, palette: &P, scale: u32) -> RgbaImage
+where
+    P: Palette<T>,
+{
+    draw(grid.row_count(), grid.col_count(), scale, |row, col| {
+        palette.colour(&grid[(row, col)])
+    })
+}
+
+pub fn save_png<P>(image: &RgbaImage, path: P) -> Result<(), ExportError>
+where
+    P: AsRef<Path>,
+{
+    Ok(image.save(path)?)
+}
+
+/// Save the frames as a looping GIF, showing each for `frame_delay_ms` milliseconds.
+pub fn save_gif<I, P>(frames: I, frame_delay_ms: u32, path: P) -> Result<(), ExportError>
+where
+    I: IntoIterator<Item = RgbaImage>,
+    P: AsRef<Path>,
+{
+    let delay = image::Delay::from_numer_denom_ms(frame_delay_ms, 1);
+    let frames = frames
+        .into_iter()
+        .map(|frame| image::Frame::from_parts(frame, 0, 0, delay));
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = image::codecs::gif::GifEncoder::new(file);
+    encoder.set_repeat(image::codecs::gif::Repeat::Infinite)?;
+    encoder.encode_frames(frames)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_scales_cells() {
+        let image = draw(1, 2, 3, |_, col| if col == 0 { RED } else { BLUE });
+        assert_eq!(image.dimensions(), (6, 3));
+        assert_eq!(*image.get_pixel(2, 2), RED);
+        assert_eq!(*image.get_pixel(3, 0), BLUE);
+    }
+
+    #[test]
+    fn grid_palettes() {
+        let grid = Grid::parse("#.\n..").unwrap();
+        let image = grid_image(&grid, &TwoTone::default(), 1);
+        assert_eq!(image.dimensions(), (2, 2));
+        assert_eq!(*image.get_pixel(0, 0), WHITE);
+        assert_eq!(*image.get_pixel(1, 0), BLACK);
+
+        let inverted = |lit: &bool| if *lit { BLACK } else { GREY };
+        let image = grid_image(&grid, &inverted, 2);
+        assert_eq!(image.dimensions(), (4, 4));
+        assert_eq!(*image.get_pixel(1, 1), BLACK);
+        assert_eq!(*image.get_pixel(0, 3), GREY);
+    }
+}
@@ -0,0 +1,339 @@
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyEvent, KeyEventKind},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use grid::grid::{Cell, Grid};
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+pub use crossterm::event::KeyCode;
+
+/// A picture of characters, `width` columns by `height` rows, to be shown on the terminal.
+/// Anything drawn outside it is clipped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+impl Frame {
+    /// A blank frame.
+    pub fn new(width: usize, height: usize) -> Frame {
+        Frame {
+            width,
+            height,
+            cells: vec![' '; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        if x < self.width && y < self.height {
+            Some(self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn put(&mut self, x: usize, y: usize, c: char) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = c;
+        }
+    }
+
+    /// Write a single line of text starting at (x, y).
+    pub fn text(&mut self, x: usize, y: usize, text: &str) {
+        for (i, c) in text.chars().enumerate() {
+            self.put(x + i, y, c);
+        }
+    }
+
+    /// Draw a picture of one or more lines with its top left at (x, y). Spaces in the sprite are
+    /// transparent, leaving whatever was there before.
+    pub fn sprite(&mut self, x: usize, y: usize, sprite: &str) {
+        for (row, line) in sprite.lines().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                if c != ' ' {
+                    self.put(x + col, y + row, c);
+                }
+            }
+        }
+    }
+
+    /// Draw a grid with its top left at (x, y), one character per cell.
+    pub fn grid<T: Cell>(&mut self, x: usize, y: usize, grid: &Grid<T>) {
+        for ((row, col), cell) in grid.iter() {
+            self.put(x + col, y + row, cell.to_char());
+        }
+    }
+}
+
+/// A run of characters which differ between two frames, starting at (x, y).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub x: usize,
+    pub y: usize,
+    pub text: String,
+}
+
+/// What needs printing to turn `before` into `after`, which must be the same size: one
+/// `Change` per maximal run of differing characters in each row.
+pub fn changes(before: &Frame, after: &Frame) -> Vec<Change> {
+    assert_eq!(
+        (before.width, before.height),
+        (after.width, after.height),
+        "frames must be the same size"
+    );
+    let mut result = vec![];
+    for y in 0..after.height {
+        let mut run: Option<Change> = None;
+        for x in 0..after.width {
+            let c = after.cells[y * after.width + x];
+            if before.cells[y * before.width + x] == c {
+                result.extend(run.take());
+            } else {
+                run.get_or_insert_with(|| Change {
+                    x,
+                    y,
+                    text: String::new(),
+                })
+                .text
+                .push(c);
+            }
+        }
+        result.extend(run);
+    }
+    result
+}
+
+/// Double-buffered output: remembers the frame most recently shown, and only prints what has
+/// changed since then. A frame of a different size is drawn afresh.
+pub struct Screen<W: Write> {
+    out: W,
+    shown: Option<Frame>,
+}
+
+impl<W: Write> Screen<W> {
+    pub fn new(out: W) -> Screen<W> {
+        Screen { out, shown: None }
+    }
+
+    pub fn present(&mut self, frame: Frame) -> io::Result<()> {
+        match &self.shown {
+            Some(shown) if (shown.width, shown.height) == (frame.width, frame.height) => {
+                for change in changes(shown, &frame) {
+                    queue!(
+                        self.out,
+                        MoveTo(change.x as u16, change.y as u16),
+                        Print(change.text)
+                    )?;
+                }
+            }
+            _ => {
+                queue!(self.out, MoveTo(0, 0), Clear(ClearType::All))?;
+                for (y, row) in frame.cells.chunks(frame.width.max(1)).enumerate() {
+                    queue!(
+                        self.out,
+                        MoveTo(0, y as u16),
+                        Print(row.iter().collect::<String>())
+                    )?;
+                }
+            }
+        }
+        self.out.flush()?;
+        self.shown = Some(frame);
+        Ok(())
+    }
+}
+
+/// Whether the key is one which should stop a visualisation: q or Esc.
+pub fn is_quit(key: &KeyCode) -> bool {
+    matches!(key, KeyCode::Char('q') | KeyCode::Esc)
+}
+
+/// Wait out the given time, returning the keys pressed meanwhile in order. Returns as soon as
+/// a quit key is pressed.
+pub fn keys_within(timeout: Duration) -> io::Result<Vec<KeyCode>> {
+    let deadline = Instant::now() + timeout;
+    let mut keys = vec![];
+    loop {
+        let now = Instant::now();
+        if now >= deadline || !event::poll(deadline - now)? {
+            return Ok(keys);
+        }
+        if let Event::Key(KeyEvent {
+            code,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        {
+            keys.push(code);
+            if is_quit(&code) {
+                return Ok(keys);
+            }
+        }
+    }
+}
+
+/// Block until a key is pressed.
+pub fn wait_for_key() -> io::Result<KeyCode> {
+    loop {
+        if let Event::Key(KeyEvent {
+            code,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        {
+            return Ok(code);
+        }
+    }
+}
+
+/// How `Terminal::run` ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// There were no more frames.
+    Finished,
+    /// The viewer pressed a quit key.
+    Quit,
+}
+
+/// The terminal in raw mode on the alternate screen, with the cursor hidden. Puts the terminal
+/// back how we found it when dropped, however we exit.
+pub struct Terminal {
+    screen: Screen<io::Stdout>,
+}
+
+impl Terminal {
+    pub fn new() -> io::Result<Terminal> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Terminal {
+            screen: Screen::new(io::stdout()),
+        })
+    }
+
+    pub fn present(&mut self, frame: Frame) -> io::Result<()> {
+        self.screen.present(frame)
+    }
+
+    /// Show a frame every `frame_time` until `step` runs out of frames or the viewer quits.
+    /// `step` is told which keys were pressed since the previous frame.
+    pub fn run<F>(&mut self, frame_time: Duration, mut step: F) -> io::Result<Outcome>
+    where
+        F: FnMut(&[KeyCode]) -> Option<Frame>,
+    {
+        let mut keys = vec![];
+        while let Some(frame) = step(&keys) {
+            self.present(frame)?;
+            keys = keys_within(frame_time)?;
+            if keys.iter().any(is_quit) {
+                return Ok(Outcome::Quit);
+            }
+        }
+        Ok(Outcome::Finished)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drawing_is_clipped() {
+        let mut frame = Frame::new(4, 2);
+        frame.text(2, 0, "abc");
+        frame.put(0, 5, 'x');
+        frame.sprite(0, 1, "d e\nfgh");
+        assert_eq!(frame.to_string(), "  ab\nd e \n");
+    }
+
+    #[test]
+    fn sprite_spaces_are_transparent() {
+        let mut frame = Frame::new(3, 1);
+        frame.text(0, 0, "abc");
+        frame.sprite(0, 0, "x z");
+        assert_eq!(frame.to_string(), "xbz\n");
+    }
+
+    #[test]
+    fn draws_grids() {
+        let grid = Grid::parse("#.\n.#").unwrap();
+        let mut frame = Frame::new(3, 3);
+        frame.grid(1, 1, &grid);
+        assert_eq!(frame.to_string(), "   \n #.\n .#\n");
+    }
+
+    #[test]
+    fn changes_are_runs() {
+        let mut before = Frame::new(5, 2);
+        before.text(0, 0, "abcde");
+        let mut after = before.clone();
+        after.text(1, 0, "XY");
+        after.put(4, 0, 'Z');
+        after.put(0, 1, 'W');
+        assert_eq!(
+            changes(&before, &after),
+            vec![
+                Change {
+                    x: 1,
+                    y: 0,
+                    text: "XY".to_string()
+                },
+                Change {
+                    x: 4,
+                    y: 0,
+                    text: "Z".to_string()
+                },
+                Change {
+                    x: 0,
+                    y: 1,
+                    text: "W".to_string()
+                },
+            ]
+        );
+        assert_eq!(changes(&after, &after), vec![]);
+    }
+
+    #[test]
+    fn unchanged_frames_print_nothing() {
+        let mut frame = Frame::new(3, 2);
+        frame.text(0, 0, "abc");
+        let mut screen = Screen::new(vec![]);
+        screen.present(frame.clone()).unwrap();
+        let printed = screen.out.len();
+        assert!(printed > 0);
+        screen.present(frame).unwrap();
+        assert_eq!(screen.out.len(), printed);
+    }
+}
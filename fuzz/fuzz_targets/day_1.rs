@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_1::day_1::Day1;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day1::parse(input);
+});
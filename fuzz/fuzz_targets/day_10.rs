@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_10::day_10::Day10;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day10::parse(input);
+});
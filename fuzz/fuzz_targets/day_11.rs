@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_11::day_11::Day11;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day11::parse(input);
+});
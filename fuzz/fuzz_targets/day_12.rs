@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_12::day_12::Day12;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day12::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_13::day_13::Day13;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day13::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_15::day_15::Day15;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day15::parse(input);
+});
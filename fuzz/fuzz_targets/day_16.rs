@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_16::day_16::Day16;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day16::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_17::day_17::Day17;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day17::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_18::day_18::Day18;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day18::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_19::day_19::Day19;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day19::parse(input);
+});
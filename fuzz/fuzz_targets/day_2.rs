@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_2::day_2::Day2;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day2::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_20::day_20::Day20;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day20::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_22::day_22::Day22;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day22::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_25::day_25::Day25;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day25::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_3::day_3::Day3;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day3::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_4::day_4::Day4;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day4::parse(input);
+});
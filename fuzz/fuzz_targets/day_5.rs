@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_5::day_5::Day5;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day5::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_6::day_6::Day6;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day6::parse(input);
+});
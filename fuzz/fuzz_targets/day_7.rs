@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_7::day_7::Day7;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day7::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_8::day_8::Day8;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day8::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_9::day_9::Day9;
+use libfuzzer_sys::fuzz_target;
+use solution::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day9::parse(input);
+});
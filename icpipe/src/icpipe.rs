@@ -0,0 +1,137 @@
+use std::io::{self, BufRead, Write};
+
+use intcode::intcode::{MachineExecutionError, MachineState, Program, StepIoResult};
+use thiserror::Error;
+
+/// How values cross the pipe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// One integer per line, both ways; blank lines of input are skipped.
+    Integers,
+    /// Each byte of input is one value, and each output below 128 is written as a character, as
+    /// the ASCII-speaking programs of days 17, 21 and 25 expect. Anything else is written as an
+    /// integer on a line of its own, like the hull damage which day 21 reports.
+    Ascii,
+}
+
+#[derive(Error, Debug)]
+pub enum PipeError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Machine(#[from] MachineExecutionError),
+    #[error("could not parse input line {line:?} as an integer")]
+    BadInput { line: String },
+    #[error("the program asked for input after the input ran out")]
+    InputEnded,
+}
+
+/// The next value of input, or None at the end of it.
+fn next_input(mode: Mode, input: &mut impl BufRead) -> Result<Option<i64>, PipeError> {
+    match mode {
+        Mode::Integers => {
+            let mut line = String::new();
+            loop {
+                line.clear();
+                if input.read_line(&mut line)? == 0 {
+                    return Ok(None);
+                }
+                let trimmed = line.trim();
+                if !trimmed.is_empty() {
+                    return trimmed.parse().map(Some).map_err(|_| PipeError::BadInput {
+                        line: trimmed.to_string(),
+                    });
+                }
+            }
+        }
+        Mode::Ascii => {
+            let Some(&byte) = input.fill_buf()?.first() else {
+                return Ok(None);
+            };
+            input.consume(1);
+            Ok(Some(i64::from(byte)))
+        }
+    }
+}
+
+fn write_output(mode: Mode, value: i64, output: &mut impl Write) -> io::Result<()> {
+    match mode {
+        Mode::Ascii if (0..128).contains(&value) => output.write_all(&[value as u8]),
+        _ => writeln!(output, "{value}"),
+    }
+}
+
+/// Run `program` until it halts, feeding it from `input` and writing what it outputs to
+/// `output` as it goes, so that one pipe's output can be another's input. Output is flushed
+/// whenever the program waits for input.
+pub fn pipe(
+    program: &Program<i64>,
+    mode: Mode,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> Result<(), PipeError> {
+    let mut machine = MachineState::from_program(program);
+    loop {
+        match machine.execute_until_input()? {
+            StepIoResult::Output(value) => write_output(mode, value, &mut output)?,
+            StepIoResult::AwaitingInput(_) => {
+                output.flush()?;
+                let value = next_input(mode, &mut input)?.ok_or(PipeError::InputEnded)?;
+                machine.push_input(value);
+            }
+            StepIoResult::Terminated => {
+                output.flush()?;
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(program: &[i64], mode: Mode, input: &str) -> Result<String, PipeError> {
+        let mut output = vec![];
+        pipe(
+            &Program::new(program.iter().copied()),
+            mode,
+            input.as_bytes(),
+            &mut output,
+        )?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_integers() {
+        // Outputs each input doubled, until it reads a zero.
+        let doubler = [
+            3, 20, 1006, 20, 15, 1002, 20, 2, 21, 4, 21, 1105, 1, 0, 0, 99,
+        ];
+        let once = run(&doubler, Mode::Integers, "3\n\n-5\n0\n").unwrap();
+        assert_eq!(once, "6\n-10\n");
+        // Composes, as in a shell pipeline.
+        assert_eq!(
+            run(&doubler, Mode::Integers, &(once + "0\n")).unwrap(),
+            "12\n-20\n"
+        );
+
+        assert!(matches!(
+            run(&doubler, Mode::Integers, "3\n"),
+            Err(PipeError::InputEnded)
+        ));
+        assert_eq!(
+            run(&doubler, Mode::Integers, "three\n")
+                .unwrap_err()
+                .to_string(),
+            "could not parse input line \"three\" as an integer"
+        );
+    }
+
+    #[test]
+    fn test_ascii() {
+        // Echoes one character, then prints "!\n" and a large number.
+        let program = [3, 11, 4, 11, 104, 33, 104, 10, 104, 1000, 99, 0];
+        assert_eq!(run(&program, Mode::Ascii, "hi").unwrap(), "h!\n1000\n");
+    }
+}
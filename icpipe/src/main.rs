@@ -0,0 +1,30 @@
+use std::fs;
+use std::io;
+
+use icpipe::icpipe::{pipe, Mode};
+use intcode::intcode::Program;
+use parse::parse::comma_separated;
+use solution::solution::Report;
+
+const USAGE: &str = "Usage: icpipe [--ascii] <path to program>";
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let (mode, path) = match &args[1..] {
+        [path] => (Mode::Integers, path),
+        [flag, path] if flag == "--ascii" => (Mode::Ascii, path),
+        _ => return Err(USAGE.into()),
+    };
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("Error while accessing path {path} : {e}"))?;
+    let program: Vec<i64> =
+        comma_separated(&text).map_err(|e| format!("Error while parsing program: {e}"))?;
+    pipe(
+        &Program::from(program),
+        mode,
+        io::stdin().lock(),
+        io::stdout().lock(),
+    )?;
+    Ok(())
+}
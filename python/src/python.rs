@@ -0,0 +1,163 @@
+use aoc::run::{self, RunError};
+use intcode::intcode::{MachineExecutionError, MachineState, StepIoResult};
+use parse::parse::{comma_separated, ParseError};
+use thiserror::Error;
+
+#[cfg(feature = "python")]
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// Everything which can go wrong, raised in Python as a `ValueError` with the error's message.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Run(#[from] RunError),
+    #[error("could not parse the program: {0}")]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Machine(#[from] MachineExecutionError),
+}
+
+#[cfg(feature = "python")]
+impl From<Error> for PyErr {
+    fn from(e: Error) -> PyErr {
+        PyValueError::new_err(e.to_string())
+    }
+}
+
+/// Solve one part of one day's puzzle, given the contents of its input file.
+#[cfg_attr(feature = "python", pyfunction)]
+pub fn solve(day: u8, part: u8, input_text: &str) -> Result<String, Error> {
+    Ok(run::run(day, part, input_text)?)
+}
+
+/// The days which have solutions, in order.
+#[cfg_attr(feature = "python", pyfunction)]
+pub fn days() -> Vec<u8> {
+    run::DAYS.to_vec()
+}
+
+/// The parts which `day` has.
+#[cfg_attr(feature = "python", pyfunction)]
+pub fn parts(day: u8) -> Vec<u8> {
+    run::parts(day).to_vec()
+}
+
+/// An intcode machine with a queue of inputs, which runs until it halts or needs an input that
+/// hasn't been given yet.
+#[cfg_attr(feature = "python", pyclass)]
+pub struct Vm {
+    machine: MachineState<i64>,
+    halted: bool,
+}
+
+impl Vm {
+    pub fn new(program: Vec<i64>) -> Vm {
+        Vm {
+            machine: MachineState::new_with_memory(&program.into_iter()),
+            halted: false,
+        }
+    }
+
+    /// A machine running the program in a puzzle input, like "1,0,0,3,99".
+    pub fn parse(text: &str) -> Result<Vm, Error> {
+        Ok(Vm::new(comma_separated(text)?))
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Vm {
+    /// Queue an input for the machine to read when it next asks.
+    pub fn push_input(&mut self, value: i64) {
+        self.machine.push_input(value);
+    }
+
+    /// Run until the machine halts or asks for an input when none are queued, returning what it
+    /// output along the way.
+    pub fn run(&mut self) -> Result<Vec<i64>, Error> {
+        let mut outputs = vec![];
+        while !self.halted && !self.machine.is_awaiting_input() {
+            match self.machine.execute_until_input()? {
+                StepIoResult::Terminated => self.halted = true,
+                StepIoResult::Output(output) => outputs.push(output),
+                StepIoResult::AwaitingInput(_) => {}
+            }
+        }
+        Ok(outputs)
+    }
+
+    pub fn halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Whether the machine has stopped to wait for an input.
+    pub fn awaiting_input(&self) -> bool {
+        self.machine.is_awaiting_input()
+    }
+
+    pub fn read(&self, address: usize) -> i64 {
+        self.machine.read_mem_elt(address)
+    }
+
+    pub fn write(&mut self, address: usize, value: i64) {
+        self.machine.set_mem_elt(address, value);
+    }
+
+    /// The machine's memory, not including any cells written beyond the end of the program.
+    pub fn memory(&self) -> Vec<i64> {
+        self.machine.dump_memory().collect()
+    }
+}
+
+/// A machine running the program in a puzzle input; this is how Python makes a `Vm`.
+#[cfg_attr(feature = "python", pyfunction)]
+pub fn intcode(program_text: &str) -> Result<Vm, Error> {
+    Vm::parse(program_text)
+}
+
+#[cfg(feature = "python")]
+#[pymodule]
+fn advent_of_code_2019(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+    m.add_function(wrap_pyfunction!(parts, m)?)?;
+    m.add_function(wrap_pyfunction!(intcode, m)?)?;
+    m.add_class::<Vm>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(1, 2, "14\n").unwrap(), "2");
+        assert_eq!(
+            solve(26, 1, "").unwrap_err().to_string(),
+            "there is no solution for day 26"
+        );
+        assert_eq!(parts(1), vec![1, 2]);
+        assert_eq!(days()[0], 1);
+    }
+
+    #[test]
+    fn test_vm() {
+        // Output the sum of two inputs, then halt.
+        let mut vm = intcode("3,11,3,12,1,11,12,13,4,13,99,0,0,0").unwrap();
+        vm.push_input(3);
+        assert_eq!(vm.run().unwrap(), Vec::<i64>::new());
+        assert!(vm.awaiting_input() && !vm.halted());
+
+        vm.push_input(4);
+        assert_eq!(vm.run().unwrap(), vec![7]);
+        assert!(vm.halted() && !vm.awaiting_input());
+        assert_eq!(vm.read(13), 7);
+        assert_eq!(vm.run().unwrap(), Vec::<i64>::new());
+
+        let mut vm = Vm::new(vec![1, 0, 0, 0, 99]);
+        vm.write(1, 4);
+        vm.run().unwrap();
+        assert_eq!(vm.memory(), vec![100, 4, 0, 0, 99]);
+        assert!(Vm::parse("1,x").is_err());
+    }
+}
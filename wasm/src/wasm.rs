@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use aoc::run;
+use day_11::day_11;
+use day_13::day_13;
+use day_15::day_15;
+use day_19::day_19;
+use day_8::day_8;
+use parse::parse::ParseError;
+use solution::solution::AocError;
+use thiserror::Error;
+use viz_image::viz_image::RgbaImage;
+use wasm_bindgen::prelude::*;
+
+/// The days which can draw a picture of their puzzle.
+pub const VISUAL_DAYS: [u8; 5] = [8, 11, 13, 15, 19];
+
+/// The size of the square which day 19's part 2 fits into the beam.
+const SQUARE_SIZE: i64 = 100;
+
+#[derive(Error, Debug)]
+pub enum RenderError {
+    #[error("day {0} has no picture")]
+    NotVisual(u8),
+    #[error("could not parse the input: {0}")]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Image(#[from] day_8::ImageError),
+    #[error(transparent)]
+    Robot(#[from] day_11::RobotError),
+    #[error(transparent)]
+    Droid(#[from] day_15::DroidError),
+    #[error(transparent)]
+    Program(#[from] AocError),
+    #[error("the game drew nothing")]
+    NoFrames,
+    #[error("no {SQUARE_SIZE}-by-{SQUARE_SIZE} square fits in the beam")]
+    NoSquare,
+}
+
+/// The days which have solutions, in order.
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    run::DAYS.to_vec()
+}
+
+/// The parts which `day` has.
+#[wasm_bindgen]
+pub fn parts(day: u8) -> Vec<u8> {
+    run::parts(day).to_vec()
+}
+
+#[wasm_bindgen]
+pub fn is_visual(day: u8) -> bool {
+    VISUAL_DAYS.contains(&day)
+}
+
+/// Solve one part of one day's puzzle, given the contents of its input file. Errors are thrown
+/// to JavaScript as their messages.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    run::run(day, part, input).map_err(|e| e.to_string())
+}
+
+/// An RGBA image, laid out as a canvas's `ImageData` expects.
+#[wasm_bindgen]
+pub struct Picture {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl Picture {
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The pixels row by row, four bytes (red, green, blue, alpha) each.
+    #[wasm_bindgen(getter)]
+    pub fn pixels(&self) -> Vec<u8> {
+        self.pixels.clone()
+    }
+}
+
+impl From<RgbaImage> for Picture {
+    fn from(image: RgbaImage) -> Picture {
+        let (width, height) = image.dimensions();
+        Picture {
+            width,
+            height,
+            pixels: image.into_raw(),
+        }
+    }
+}
+
+/// A picture of one of the `VISUAL_DAYS`, with each cell drawn as a `scale`-by-`scale` block:
+/// day 8's decoded message, day 11's registration identifier, day 13's screen as part 2's game
+/// begins, day 15's map of the area, and day 19's beam down to part 2's square.
+pub fn image(day: u8, input: &str, scale: u32) -> Result<RgbaImage, RenderError> {
+    match day {
+        8 => {
+            let image = day_8::Image::parse(input, day_8::PUZZLE_WIDTH, day_8::PUZZLE_HEIGHT)?;
+            Ok(image.decode()?.to_image(scale))
+        }
+        11 => {
+            let program = day_11::input(input)?;
+            Ok(day_11::hull_image(
+                &program,
+                &day_11::registration_hull(),
+                scale,
+            )?)
+        }
+        13 => {
+            let program = day_13::input(input)?;
+            let (_, frames) = day_13::play_recording(&program)?;
+            let first = frames.first().ok_or(RenderError::NoFrames)?;
+            Ok(day_13::frame_image(&first.state, first.state.size(), scale))
+        }
+        15 => {
+            let program = day_15::input(input)?;
+            let map = day_15::explore(day_15::IntcodeDroid::new(&program))?;
+            let frame = day_15::AnimationFrame {
+                cells: map.cells.clone(),
+                oxygenated: HashSet::new(),
+            };
+            Ok(day_15::frame_image(&frame, map.bounds(), scale))
+        }
+        19 => {
+            let program = day_19::input(input)?;
+            let square =
+                day_19::find_square(&program, SQUARE_SIZE)?.ok_or(RenderError::NoSquare)?;
+            Ok(day_19::scan_image(
+                &program,
+                0..square.x + SQUARE_SIZE,
+                0..square.y + SQUARE_SIZE,
+                Some(square),
+                scale,
+            )?)
+        }
+        _ => Err(RenderError::NotVisual(day)),
+    }
+}
+
+/// Draw one of the `VISUAL_DAYS`; see `image`. Errors are thrown to JavaScript as their messages.
+#[wasm_bindgen]
+pub fn render(day: u8, input: &str, scale: u32) -> Result<Picture, String> {
+    image(day, input, scale)
+        .map(Picture::from)
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(1, 1, "12\n14\n"), Ok("4".to_string()));
+        assert_eq!(
+            solve(24, 1, ""),
+            Err("there is no solution for day 24".to_string())
+        );
+        assert_eq!(parts(25), vec![1]);
+        assert_eq!(days().len(), 24);
+        assert!(is_visual(8) && !is_visual(1));
+    }
+
+    #[test]
+    fn test_render() {
+        // One white layer under one transparent layer, with the bottom row black.
+        let input = "2".repeat(150) + &"1".repeat(125) + &"0".repeat(25);
+        let picture = render(8, &input, 2).unwrap();
+        assert_eq!((picture.width(), picture.height()), (50, 12));
+        assert_eq!(picture.pixels().len(), 50 * 12 * 4);
+        assert_eq!(picture.pixels()[..4], [255, 255, 255, 255]);
+        assert_eq!(
+            picture.pixels()[picture.pixels().len() - 4..],
+            [0, 0, 0, 255]
+        );
+
+        assert_eq!(
+            render(1, "12", 1).err(),
+            Some("day 1 has no picture".to_string())
+        );
+        assert!(render(8, "123", 1).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+import init, { days, parts, is_visual, solve, render } from "./pkg/wasm.js";
+
+// How big to draw each cell of the visual days' pictures.
+const SCALES = { 8: 10, 11: 10, 13: 8, 15: 8, 19: 1 };
+
+await init();
+
+const daySelect = document.getElementById("day");
+const input = document.getElementById("input");
+const answers = document.getElementById("answers");
+const canvas = document.getElementById("picture");
+
+for (const day of days()) {
+    daySelect.add(new Option(`${day}`, `${day}`));
+}
+
+function show(text, isError) {
+    const item = document.createElement("li");
+    item.textContent = text;
+    if (isError) {
+        item.className = "error";
+    }
+    answers.append(item);
+}
+
+function draw(day) {
+    const picture = render(day, input.value, SCALES[day]);
+    const pixels = new Uint8ClampedArray(picture.pixels);
+    canvas.width = picture.width;
+    canvas.height = picture.height;
+    canvas.getContext("2d").putImageData(new ImageData(pixels, picture.width, picture.height), 0, 0);
+    picture.free();
+    canvas.hidden = false;
+}
+
+document.getElementById("solve").addEventListener("click", () => {
+    const day = Number(daySelect.value);
+    answers.replaceChildren();
+    canvas.hidden = true;
+    for (const part of parts(day)) {
+        try {
+            show(`Part ${part}: ${solve(day, part, input.value)}`, false);
+        } catch (error) {
+            show(`Part ${part}: ${error}`, true);
+        }
+    }
+    if (is_visual(day)) {
+        try {
+            draw(day);
+        } catch (error) {
+            show(`Picture: ${error}`, true);
+        }
+    }
+});
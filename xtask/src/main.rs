@@ -0,0 +1,24 @@
+use loader::loader;
+use solution::solution::Report;
+use xtask::xtask;
+
+const USAGE: &str = "Usage: cargo xtask new-day <day>";
+
+fn main() -> Result<(), Report> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let day = match &args[..] {
+        [command, day] if command == "new-day" => day
+            .parse()
+            .map_err(|e| format!("Could not parse day {day} : {e}"))?,
+        _ => return Err(USAGE.into()),
+    };
+    let root = loader::workspace_root();
+    for path in xtask::new_day(&root, day)? {
+        let path = path.strip_prefix(&root).unwrap_or(&path);
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "day_{day} is a workspace member; to run it through `aoc`, add it to aoc2019's features and to `Year2019`"
+    );
+    Ok(())
+}
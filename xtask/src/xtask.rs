@@ -0,0 +1,179 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use loader::loader::YEAR_DIR;
+use thiserror::Error;
+
+/// What the templates say wherever the day's number goes.
+pub const PLACEHOLDER: &str = "{{day}}";
+
+/// Each file of a new day crate, relative to the crate, and the template it's made from.
+pub const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    (
+        "benches/day_{{day}}.rs",
+        include_str!("../templates/bench.rs.tmpl"),
+    ),
+    (
+        "examples/expected.txt",
+        include_str!("../templates/expected.txt.tmpl"),
+    ),
+];
+
+#[derive(Error, Debug)]
+pub enum XtaskError {
+    #[error("there is no day {0}; days go from 1 to 25")]
+    UnknownDay(u8),
+    #[error("{} already exists", path.display())]
+    Exists { path: PathBuf },
+    #[error("could not {action} {}: {source}", path.display())]
+    Io {
+        action: &'static str,
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("could not find the workspace's members in {}", path.display())]
+    NoMembers { path: PathBuf },
+}
+
+pub fn render(template: &str, day: u8) -> String {
+    template.replace(PLACEHOLDER, &day.to_string())
+}
+
+/// The day of a line of the workspace's members, if it's a day crate.
+fn member_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix('"')?
+        .strip_prefix(YEAR_DIR)?
+        .strip_prefix("/day_")?
+        .strip_suffix("\",")?
+        .parse()
+        .ok()
+}
+
+/// The workspace manifest with `<YEAR_DIR>/day_<day>` added to its members, keeping the days in
+/// order.
+/// None if there's no `members` list to add it to.
+pub fn add_member(manifest: &str, day: u8) -> Option<String> {
+    let lines: Vec<&str> = manifest.lines().collect();
+    let start = lines.iter().position(|l| l.trim() == "members = [")?;
+    let end = start + lines[start..].iter().position(|l| l.trim() == "]")?;
+    // After the last earlier day; or before the first later one, if there are no earlier ones.
+    let members = start + 1..end;
+    let at = members
+        .clone()
+        .rev()
+        .find(|&i| member_day(lines[i]).is_some_and(|d| d < day))
+        .map(|i| i + 1)
+        .or_else(|| {
+            members
+                .clone()
+                .find(|&i| member_day(lines[i]).is_some_and(|d| d > day))
+        })
+        .unwrap_or(end);
+
+    let mut result: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    result.insert(at, format!("    \"{YEAR_DIR}/day_{day}\","));
+    let mut result = result.join("\n");
+    if manifest.ends_with('\n') {
+        result.push('\n');
+    }
+    Some(result)
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), XtaskError> {
+    let io_error = |action| {
+        move |source| XtaskError::Io {
+            action,
+            path: path.to_path_buf(),
+            source,
+        }
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error("create the directory for"))?;
+    }
+    std::fs::write(path, contents).map_err(io_error("write"))
+}
+
+/// Make a crate for `day` in `YEAR_DIR` under the workspace at `root` from the templates, and add it to the
+/// workspace's members. Gives the files written, the manifest last.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, XtaskError> {
+    if !(1..=25).contains(&day) {
+        return Err(XtaskError::UnknownDay(day));
+    }
+    let dir = root.join(YEAR_DIR).join(format!("day_{day}"));
+    if dir.exists() {
+        return Err(XtaskError::Exists { path: dir });
+    }
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&manifest_path).map_err(|source| XtaskError::Io {
+        action: "read",
+        path: manifest_path.clone(),
+        source,
+    })?;
+    let manifest = add_member(&manifest, day).ok_or_else(|| XtaskError::NoMembers {
+        path: manifest_path.clone(),
+    })?;
+
+    let mut written = vec![];
+    for (path, template) in TEMPLATES {
+        let path = dir.join(render(path, day));
+        write(&path, &render(template, day))?;
+        written.push(path);
+    }
+    write(&manifest_path, &manifest)?;
+    written.push(manifest_path);
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"xtask\",\n    \"year2019/day_1\",\n    \"year2019/day_9\",\n    \"year2019/day_10\",\n]\n";
+
+    #[test]
+    fn test_add_member() {
+        let added = add_member(MANIFEST, 4).unwrap();
+        assert_eq!(
+            added,
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"xtask\",\n    \"year2019/day_1\",\n    \"year2019/day_4\",\n    \"year2019/day_9\",\n    \"year2019/day_10\",\n]\n"
+        );
+        assert!(add_member(MANIFEST, 24)
+            .unwrap()
+            .ends_with("\"year2019/day_10\",\n    \"year2019/day_24\",\n]\n"));
+
+        let no_days = "[workspace]\nmembers = [\n    \"aoc\",\n]";
+        assert_eq!(
+            add_member(no_days, 3).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"year2019/day_3\",\n]"
+        );
+        assert_eq!(add_member("[package]\nname = \"x\"\n", 3), None);
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("xtask-test-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        let written = new_day(&root, 24).unwrap();
+        assert_eq!(written.len(), TEMPLATES.len() + 1);
+        let dir = root.join(YEAR_DIR).join("day_24");
+        let lib = std::fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.starts_with("pub mod day_24 {"));
+        assert!(!lib.contains(PLACEHOLDER));
+        assert!(dir.join("benches/day_24.rs").is_file());
+        let manifest = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("\"year2019/day_24\","));
+
+        assert!(matches!(new_day(&root, 24), Err(XtaskError::Exists { .. })));
+        assert!(matches!(
+            new_day(&root, 26),
+            Err(XtaskError::UnknownDay(26))
+        ));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,269 @@
+//! Every day's solutions under one roof. `aoc2019::day13` has everything which the `day_13`
+//! crate does, and also `part1` and `part2`, which answer the puzzle given the contents of its
+//! input file. Each day is behind a feature of the same name, all on by default. `Year2019` is
+//! the year as a runner sees it.
+
+pub use solution::solution::{Answer, ParseError, Solution, SolveError, Visit, Year};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("could not parse the input: {0}")]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Solve(#[from] SolveError),
+}
+
+#[doc(hidden)]
+pub fn solve<S: Solution>(
+    input: &str,
+    part: fn(&S::Parsed<'_>) -> Answer,
+) -> Result<String, Error> {
+    Ok(part(&S::parse(input)?)?)
+}
+
+// Unused only if every day is turned off.
+#[allow(unused_macros)]
+macro_rules! day {
+    ($krate:ident, $puzzle:ident) => {
+        pub use $krate::$krate::*;
+
+        /// The answer to part 1, given the contents of the input file.
+        pub fn part1(input: &str) -> Result<String, $crate::Error> {
+            $crate::solve::<$puzzle>(input, <$puzzle as $crate::Solution>::part_1)
+        }
+    };
+    ($krate:ident, $puzzle:ident, 2) => {
+        day!($krate, $puzzle);
+
+        /// The answer to part 2, given the contents of the input file.
+        pub fn part2(input: &str) -> Result<String, $crate::Error> {
+            $crate::solve::<$puzzle>(input, <$puzzle as $crate::Solution>::part_2)
+        }
+    };
+}
+
+#[cfg(feature = "day1")]
+pub mod day1 {
+    day!(day_1, Day1, 2);
+}
+
+#[cfg(feature = "day2")]
+pub mod day2 {
+    day!(day_2, Day2, 2);
+}
+
+#[cfg(feature = "day3")]
+pub mod day3 {
+    day!(day_3, Day3, 2);
+}
+
+#[cfg(feature = "day4")]
+pub mod day4 {
+    day!(day_4, Day4, 2);
+}
+
+#[cfg(feature = "day5")]
+pub mod day5 {
+    day!(day_5, Day5, 2);
+}
+
+#[cfg(feature = "day6")]
+pub mod day6 {
+    day!(day_6, Day6, 2);
+}
+
+#[cfg(feature = "day7")]
+pub mod day7 {
+    day!(day_7, Day7, 2);
+}
+
+#[cfg(feature = "day8")]
+pub mod day8 {
+    day!(day_8, Day8, 2);
+}
+
+#[cfg(feature = "day9")]
+pub mod day9 {
+    day!(day_9, Day9, 2);
+}
+
+#[cfg(feature = "day10")]
+pub mod day10 {
+    day!(day_10, Day10, 2);
+}
+
+#[cfg(feature = "day11")]
+pub mod day11 {
+    day!(day_11, Day11, 2);
+}
+
+#[cfg(feature = "day12")]
+pub mod day12 {
+    day!(day_12, Day12, 2);
+}
+
+#[cfg(feature = "day13")]
+pub mod day13 {
+    day!(day_13, Day13, 2);
+}
+
+#[cfg(feature = "day14")]
+pub mod day14 {
+    day!(day_14, Day14, 2);
+}
+
+#[cfg(feature = "day15")]
+pub mod day15 {
+    day!(day_15, Day15, 2);
+}
+
+#[cfg(feature = "day16")]
+pub mod day16 {
+    day!(day_16, Day16, 2);
+}
+
+#[cfg(feature = "day17")]
+pub mod day17 {
+    day!(day_17, Day17, 2);
+}
+
+#[cfg(feature = "day18")]
+pub mod day18 {
+    day!(day_18, Day18, 2);
+}
+
+#[cfg(feature = "day19")]
+pub mod day19 {
+    day!(day_19, Day19, 2);
+}
+
+#[cfg(feature = "day20")]
+pub mod day20 {
+    day!(day_20, Day20, 2);
+}
+
+#[cfg(feature = "day21")]
+pub mod day21 {
+    day!(day_21, Day21, 2);
+}
+
+#[cfg(feature = "day22")]
+pub mod day22 {
+    day!(day_22, Day22, 2);
+}
+
+#[cfg(feature = "day23")]
+pub mod day23 {
+    day!(day_23, Day23, 2);
+}
+
+#[cfg(feature = "day25")]
+pub mod day25 {
+    day!(day_25, Day25);
+}
+
+/// The 2019 puzzles. `DAYS` has every day with a solution, but `visit` gives None for the days
+/// whose features are turned off.
+pub struct Year2019;
+
+impl Year for Year2019 {
+    const YEAR: u16 = 2019;
+
+    const DAYS: &'static [u8] = &[
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 25,
+    ];
+
+    // Only the catch-all arm is reachable if every day is turned off.
+    #[allow(unused_variables)]
+    fn visit<V: Visit>(day: u8, visitor: V) -> Option<V::Output> {
+        match day {
+            #[cfg(feature = "day1")]
+            1 => Some(visitor.visit::<day1::Day1>()),
+            #[cfg(feature = "day2")]
+            2 => Some(visitor.visit::<day2::Day2>()),
+            #[cfg(feature = "day3")]
+            3 => Some(visitor.visit::<day3::Day3>()),
+            #[cfg(feature = "day4")]
+            4 => Some(visitor.visit::<day4::Day4>()),
+            #[cfg(feature = "day5")]
+            5 => Some(visitor.visit::<day5::Day5>()),
+            #[cfg(feature = "day6")]
+            6 => Some(visitor.visit::<day6::Day6>()),
+            #[cfg(feature = "day7")]
+            7 => Some(visitor.visit::<day7::Day7>()),
+            #[cfg(feature = "day8")]
+            8 => Some(visitor.visit::<day8::Day8>()),
+            #[cfg(feature = "day9")]
+            9 => Some(visitor.visit::<day9::Day9>()),
+            #[cfg(feature = "day10")]
+            10 => Some(visitor.visit::<day10::Day10>()),
+            #[cfg(feature = "day11")]
+            11 => Some(visitor.visit::<day11::Day11>()),
+            #[cfg(feature = "day12")]
+            12 => Some(visitor.visit::<day12::Day12>()),
+            #[cfg(feature = "day13")]
+            13 => Some(visitor.visit::<day13::Day13>()),
+            #[cfg(feature = "day14")]
+            14 => Some(visitor.visit::<day14::Day14>()),
+            #[cfg(feature = "day15")]
+            15 => Some(visitor.visit::<day15::Day15>()),
+            #[cfg(feature = "day16")]
+            16 => Some(visitor.visit::<day16::Day16>()),
+            #[cfg(feature = "day17")]
+            17 => Some(visitor.visit::<day17::Day17>()),
+            #[cfg(feature = "day18")]
+            18 => Some(visitor.visit::<day18::Day18>()),
+            #[cfg(feature = "day19")]
+            19 => Some(visitor.visit::<day19::Day19>()),
+            #[cfg(feature = "day20")]
+            20 => Some(visitor.visit::<day20::Day20>()),
+            #[cfg(feature = "day21")]
+            21 => Some(visitor.visit::<day21::Day21>()),
+            #[cfg(feature = "day22")]
+            22 => Some(visitor.visit::<day22::Day22>()),
+            #[cfg(feature = "day23")]
+            23 => Some(visitor.visit::<day23::Day23>()),
+            #[cfg(feature = "day25")]
+            25 => Some(visitor.visit::<day25::Day25>()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(all(feature = "day1", feature = "day2"))]
+    fn test_days() {
+        assert_eq!(crate::day1::part1("12\n14\n").unwrap(), "4");
+        assert_eq!(crate::day1::part2("14\n").unwrap(), "2");
+        // The days' own functions are there too.
+        assert_eq!(crate::day1::input("12\n14\n").unwrap(), vec![12, 14]);
+        assert!(matches!(
+            crate::day2::part1("1,x"),
+            Err(crate::Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn test_year() {
+        use crate::{Answer, Solution, Visit, Year, Year2019};
+
+        struct Part1(&'static str);
+
+        impl Visit for Part1 {
+            type Output = Answer;
+
+            fn visit<S: Solution>(self) -> Answer {
+                S::part_1(&S::parse(self.0).unwrap())
+            }
+        }
+
+        assert_eq!(Year2019::visit(1, Part1("12\n14\n")).unwrap().unwrap(), "4");
+        assert!(Year2019::visit(24, Part1("")).is_none());
+        assert_eq!(Year2019::parts(25), [1]);
+        assert!(Year2019::DAYS.windows(2).all(|w| w[0] < w[1]));
+    }
+}
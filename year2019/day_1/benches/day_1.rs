@@ -2,7 +2,10 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use day_1::day_1::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input(include_str!("../input.txt"));
+    let Some(input_str) = loader::loader::load(1) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
     c.bench_function("day 1 part 1", |b| {
         b.iter(|| {
             black_box(part_1(&input.iter().copied()));
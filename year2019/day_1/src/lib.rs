@@ -0,0 +1,55 @@
+pub mod day_1 {
+    use parse::parse::{lines, ParseError};
+    use solution::solution::{answer, Answer, Solution};
+
+    pub fn input(s: &str) -> Result<Vec<u32>, ParseError> {
+        lines(s)
+    }
+
+    pub fn part_1<T>(numbers: &T) -> u32
+    where
+        T: IntoIterator<Item = u32>,
+        T: Clone,
+    {
+        numbers.clone().into_iter().map(|n| (n / 3) - 2).sum()
+    }
+
+    pub fn part_2<T>(numbers: &T) -> u32
+    where
+        T: IntoIterator<Item = u32>,
+        T: Clone,
+    {
+        numbers
+            .clone()
+            .into_iter()
+            .map(|n| {
+                let mut ans = 0;
+                let mut n = n;
+                while n > 6 {
+                    let new = (n / 3) - 2;
+                    ans += new;
+                    n = new;
+                }
+                ans
+            })
+            .sum()
+    }
+
+    pub struct Day1;
+
+    impl Solution for Day1 {
+        type Parsed<'a> = Vec<u32>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_2(parsed))
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use day_1::day_1::Day1;
+use solution::solution::{run_main, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    run_main::<Day1>(&args)
+}
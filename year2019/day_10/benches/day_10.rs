@@ -0,0 +1,61 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_10::day_10::{input, part_1, part_2};
+
+const EXAMPLE: &str = ".#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##";
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let example = input(EXAMPLE).unwrap();
+    c.bench_function("day 10 part 1 example", |b| {
+        b.iter(|| {
+            black_box(part_1(&example));
+        })
+    });
+    let Some(input_str) = loader::loader::load(10) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 10 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&input));
+        })
+    });
+    c.bench_function("day 10 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&input).unwrap());
+        })
+    });
+
+    let mut group = c.benchmark_group("day 10 part 1 threads");
+    for threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(threads), &input, |b, input| {
+            b.iter(|| pool.install(|| black_box(part_1(input))))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
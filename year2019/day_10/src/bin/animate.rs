@@ -0,0 +1,108 @@
+//! Watch the laser sweep round from the best monitoring station, numbering the next nine
+//! asteroids it vaporises in each frame as the puzzle text does; q quits.
+//!
+//! Usage: day_10_animate <input file> [frames per second]
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use day_10::day_10;
+use solution::solution::{read_input, Report};
+use std::{
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+/// Puts the terminal back how we found it, however we exit.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new() -> io::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Wait out the rest of the frame, returning whether the viewer asked to quit.
+fn should_quit(frame: Duration) -> io::Result<bool> {
+    let deadline = Instant::now() + frame;
+    loop {
+        let now = Instant::now();
+        if now >= deadline || !event::poll(deadline - now)? {
+            return Ok(false);
+        }
+        if let Event::Key(KeyEvent {
+            code: KeyCode::Char('q') | KeyCode::Esc,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        {
+            return Ok(true);
+        }
+    }
+}
+
+fn draw(out: &mut impl Write, picture: &str, footer: &str) -> io::Result<()> {
+    queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+    let mut height = 0;
+    for (row, line) in picture.lines().enumerate() {
+        queue!(out, MoveTo(0, row as u16), Print(line))?;
+        height = row + 1;
+    }
+    queue!(out, MoveTo(0, height as u16 + 1), Print(footer))?;
+    out.flush()
+}
+
+fn animate(board: &day_10::Board, frame: Duration) -> Result<(), Report> {
+    let station =
+        day_10::best_station(board).ok_or_else(|| Report::from("No asteroids on the board"))?;
+    let total = day_10::vaporization_order(board, station.pos).count();
+    let frames = day_10::vaporization_frames(board, station.pos);
+
+    let _guard = TerminalGuard::new()?;
+    let mut out = io::stdout();
+    for (i, picture) in frames.iter().enumerate() {
+        let first = i * day_10::VAPORIZED_PER_FRAME + 1;
+        let last = total.min(first + day_10::VAPORIZED_PER_FRAME - 1);
+        let footer = format!("Vaporising asteroids {first} to {last} of {total}");
+        draw(&mut out, picture, &footer)?;
+        if should_quit(frame)? {
+            return Ok(());
+        }
+    }
+    loop {
+        if let Event::Key(_) = event::read()? {
+            return Ok(());
+        }
+    }
+}
+
+fn main() -> Result<(), Report> {
+    let args: Vec<String> = std::env::args().collect();
+    let input_str = read_input(&args)?;
+    let fps: u32 = match args.get(2) {
+        None => 2,
+        Some(fps) => fps
+            .parse()
+            .map_err(|e| format!("Could not parse frame rate {fps} : {e}"))?,
+    };
+    if fps == 0 {
+        return Err("Frame rate must be positive".into());
+    }
+    let input = day_10::input(&input_str)
+        .map_err(|e| Report::from(format!("Error while parsing input: {e}")))?;
+
+    animate(&input, Duration::from_secs(1) / fps)
+}
@@ -0,0 +1,333 @@
+pub mod day_10 {
+    use geometry::geometry::{clockwise_from_up, Point2};
+    use grid::grid::{Grid, ParseError};
+    use numtheory::numtheory::gcd;
+    use rayon::prelude::*;
+    use solution::solution::{
+        answer, solved, Answer, AocError, Explainer, ParseError as InputError, Solution, SolveError,
+    };
+    use std::collections::HashSet;
+
+    /// The asteroid field: true where there is an asteroid.
+    pub type Board = Grid<bool>;
+    pub type BoardParseError = ParseError;
+
+    pub fn input(s: &str) -> Result<Board, BoardParseError> {
+        Board::parse(s)
+    }
+
+    /// The offset from one position to another, divided through by its gcd so that every
+    /// asteroid along the same line of sight has the same direction.
+    fn direction(from: (usize, usize), to: (usize, usize)) -> (i32, i32) {
+        let d_row = to.0 as i32 - from.0 as i32;
+        let d_col = to.1 as i32 - from.1 as i32;
+        let divisor = gcd(d_row, d_col);
+        (d_row / divisor, d_col / divisor)
+    }
+
+    fn asteroids(input: &Board) -> Vec<(usize, usize)> {
+        input
+            .iter()
+            .filter(|(_, &asteroid)| asteroid)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// How many of the `asteroids` are visible from the one at `station`: that is, how many
+    /// distinct lines of sight there are.
+    fn visible_from(asteroids: &[(usize, usize)], station: (usize, usize)) -> u32 {
+        let directions: HashSet<(i32, i32)> = asteroids
+            .iter()
+            .filter(|&&pos| pos != station)
+            .map(|&pos| direction(station, pos))
+            .collect();
+        directions.len() as u32
+    }
+
+    /// An asteroid considered as the site of a monitoring station.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Station {
+        /// (row, col) of the asteroid.
+        pub pos: (usize, usize),
+        /// How many other asteroids are visible from here.
+        pub visible: u32,
+    }
+
+    /// Every asteroid on the board, in row-major order, with how many asteroids it can see.
+    /// Candidate stations are evaluated in parallel on the current rayon thread pool.
+    pub fn visibility_map(input: &Board) -> Vec<Station> {
+        let asteroids = asteroids(input);
+        asteroids
+            .par_iter()
+            .map(|&pos| Station {
+                pos,
+                visible: visible_from(&asteroids, pos),
+            })
+            .collect()
+    }
+
+    /// The asteroid which can see the most other asteroids; ties go to the first in row-major
+    /// order. `None` if there are no asteroids.
+    pub fn best_station(input: &Board) -> Option<Station> {
+        visibility_map(input)
+            .into_iter()
+            .fold(None, |best, station| match best {
+                Some(best @ Station { visible, .. }) if visible >= station.visible => Some(best),
+                _ => Some(station),
+            })
+    }
+
+    pub fn part_1(input: &Board) -> u32 {
+        best_station(input).map_or(0, |station| station.visible)
+    }
+
+    /// The asteroids other than `station` (a (row, col) position), in the order the laser
+    /// vaporises them: starting pointing up and rotating clockwise.
+    pub fn vaporization_order(
+        input: &Board,
+        station: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        // Each asteroid is hit on the sweep given by how many asteroids lie between it and the
+        // station along the same direction; within a sweep, the laser goes in angle order.
+        let mut by_direction: Vec<_> = asteroids(input)
+            .into_iter()
+            .filter(|&pos| pos != station)
+            .map(|pos| {
+                let (d_row, d_col) = direction(station, pos);
+                let distance = if d_row == 0 {
+                    (pos.1 as i32 - station.1 as i32) / d_col
+                } else {
+                    (pos.0 as i32 - station.0 as i32) / d_row
+                };
+                ((d_row, d_col), distance, pos)
+            })
+            .collect();
+        by_direction.sort_by_key(|&(direction, distance, _)| (direction, distance));
+
+        let mut order: Vec<(usize, Point2, (usize, usize))> =
+            Vec::with_capacity(by_direction.len());
+        let mut sweep = 0;
+        for (i, &(direction, _, pos)) in by_direction.iter().enumerate() {
+            if i > 0 && by_direction[i - 1].0 == direction {
+                sweep += 1;
+            } else {
+                sweep = 0;
+            }
+            // Rows increase downwards, but y increases upwards.
+            let direction = Point2::new(direction.1, -direction.0);
+            order.push((sweep, direction, pos));
+        }
+        order.sort_by(|a, b| a.0.cmp(&b.0).then(clockwise_from_up(a.1, b.1)));
+
+        order.into_iter().map(|(_, _, pos)| pos)
+    }
+
+    /// How many asteroids each frame of `vaporization_frames` numbers, as in the puzzle text.
+    pub const VAPORIZED_PER_FRAME: usize = 9;
+
+    /// The laser sweep as a sequence of pictures of the board: each numbers the next
+    /// `VAPORIZED_PER_FRAME` asteroids to be vaporised, 1 to 9, with those from earlier frames
+    /// gone and the station drawn as 'X'.
+    pub fn vaporization_frames(input: &Board, station: (usize, usize)) -> Vec<String> {
+        let order: Vec<_> = vaporization_order(input, station).collect();
+        let mut board = input.clone();
+        let mut frames = Vec::with_capacity(order.len().div_ceil(VAPORIZED_PER_FRAME));
+        for batch in order.chunks(VAPORIZED_PER_FRAME) {
+            let frame = Grid::from_fn(board.row_count(), board.col_count(), |pos| {
+                match batch.iter().position(|&hit| hit == pos) {
+                    Some(i) => char::from(b'1' + i as u8),
+                    None if pos == station => 'X',
+                    None if board[pos] => '#',
+                    None => '.',
+                }
+            });
+            frames.push(frame.to_string());
+            for &pos in batch {
+                board[pos] = false;
+            }
+        }
+        frames
+    }
+
+    pub fn part_2(input: &Board) -> Result<u32, AocError> {
+        let station = best_station(input).ok_or(AocError::NoAnswer("there are no asteroids"))?;
+        let (row, col) = vaporization_order(input, station.pos)
+            .nth(199)
+            .ok_or(AocError::NoAnswer("fewer than 200 asteroids are vaporised"))?;
+        Ok((col * 100 + row) as u32)
+    }
+
+    pub struct Day10;
+
+    impl Solution for Day10 {
+        type Parsed<'a> = Board;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, InputError> {
+            Ok(input(s)?)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+
+        /// Positions are given as the puzzle gives them: x across, then y down.
+        fn explain(
+            parsed: &Self::Parsed<'_>,
+            explainer: &mut dyn Explainer,
+        ) -> Result<(), SolveError> {
+            let station =
+                best_station(parsed).ok_or(SolveError::NoAnswer("there are no asteroids"))?;
+            let (row, col) = station.pos;
+            explainer.note(
+                "best station",
+                &format_args!("{col},{row}, seeing {} asteroids", station.visible),
+            );
+            if let Some((row, col)) = vaporization_order(parsed, station.pos).nth(199) {
+                explainer.note("200th vaporised", &format_args!("{col},{row}"));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_10::*;
+    use solution::solution::{Note, Solution};
+
+    #[test]
+    fn part1_known_1() {
+        let board = input(include_str!("../examples/small.txt")).unwrap();
+        assert_eq!(
+            best_station(&board),
+            Some(Station {
+                pos: (4, 3),
+                visible: 8
+            })
+        );
+        let visible: Vec<u32> = visibility_map(&board).iter().map(|s| s.visible).collect();
+        assert_eq!(visible, vec![7, 7, 6, 7, 7, 7, 5, 7, 8, 7]);
+        assert!(part_2(&board).is_err());
+        assert!(part_2(&input("...").unwrap()).is_err());
+    }
+
+    #[test]
+    fn part1_known_5() {
+        let board = input(include_str!("../examples/large.txt")).unwrap();
+        assert_eq!(best_station(&board).unwrap().pos, (13, 11));
+    }
+
+    #[test]
+    fn part2_known() {
+        let board = input(include_str!("../examples/large.txt")).unwrap();
+        let order: Vec<_> = vaporization_order(&board, (13, 11)).collect();
+        assert_eq!(order.len(), 299);
+        // (row, col) of the 1st, 2nd, 3rd, 10th, 20th, 50th, 100th, 199th and 299th.
+        assert_eq!(order[0], (12, 11));
+        assert_eq!(order[1], (1, 12));
+        assert_eq!(order[2], (2, 12));
+        assert_eq!(order[9], (8, 12));
+        assert_eq!(order[19], (0, 16));
+        assert_eq!(order[49], (9, 16));
+        assert_eq!(order[99], (16, 10));
+        assert_eq!(order[198], (6, 9));
+        assert_eq!(order[298], (1, 11));
+    }
+
+    #[test]
+    fn test_explain() {
+        let board = input(include_str!("../examples/large.txt")).unwrap();
+        let mut notes: Vec<Note> = vec![];
+        Day10::explain(&board, &mut notes).unwrap();
+        let values: Vec<_> = notes.iter().map(|n| n.value.as_str()).collect();
+        assert_eq!(values, ["11,13, seeing 210 asteroids", "8,2"]);
+
+        let mut notes: Vec<Note> = vec![];
+        Day10::explain(
+            &input(include_str!("../examples/small.txt")).unwrap(),
+            &mut notes,
+        )
+        .unwrap();
+        assert_eq!(notes.len(), 1);
+        assert!(Day10::explain(&input("...").unwrap(), &mut notes).is_err());
+    }
+
+    #[test]
+    fn test_vaporization_frames() {
+        // The station is at the X in the puzzle text.
+        let board = input(
+            ".#....#####...#..
+##...##.#####..##
+##...#...#.#####.
+..#.....#...###..
+..#.#.....#....##",
+        )
+        .unwrap();
+        let frames = vaporization_frames(&board, (3, 8));
+        assert_eq!(frames.len(), 4);
+        assert_eq!(
+            frames[0],
+            concat!(
+                ".#....###24...#..\n",
+                "##...##.13#67..9#\n",
+                "##...#...5.8####.\n",
+                "..#.....X...###..\n",
+                "..#.#.....#....##\n",
+            )
+        );
+        assert_eq!(
+            frames[1],
+            concat!(
+                ".#....###.....#..\n",
+                "##...##...#.....#\n",
+                "##...#......1234.\n",
+                "..#.....X...5##..\n",
+                "..#.9.....8....76\n",
+            )
+        );
+        assert_eq!(
+            frames[3],
+            concat!(
+                "......234.....6..\n",
+                "......1...5.....7\n",
+                ".................\n",
+                "........X....89..\n",
+                ".................\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let board = input(".#\r\n#.\r\n").unwrap();
+        assert_eq!(board.row_count(), 2);
+        assert_eq!(board.col_count(), 2);
+        assert_eq!(board.to_string(), ".#\n#.\n");
+
+        let board = input("..#").unwrap();
+        assert_eq!(board.row_count(), 1);
+        assert_eq!(board.col_count(), 3);
+
+        assert_eq!(
+            input(".#\n#..\n").unwrap_err(),
+            BoardParseError::Ragged {
+                row: 1,
+                expected: 2,
+                actual: 3
+            }
+        );
+        assert_eq!(
+            input("..\n.X\n").unwrap_err(),
+            BoardParseError::InvalidChar {
+                row: 1,
+                col: 1,
+                c: 'X'
+            }
+        );
+        assert_eq!(input("\n").unwrap_err(), BoardParseError::Empty);
+    }
+}
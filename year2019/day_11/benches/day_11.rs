@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_11::day_11::{input, paint_hull_on, part_1, part_2, Colour, Hull};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input_str) = loader::loader::load(11) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 11 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&input).unwrap());
+        })
+    });
+    c.bench_function("day 11 part 1 sparse", |b| {
+        b.iter(|| {
+            black_box(
+                paint_hull_on(&input, Hull::sparse())
+                    .unwrap()
+                    .panels()
+                    .len(),
+            );
+        })
+    });
+    c.bench_function("day 11 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&input).unwrap());
+        })
+    });
+    c.bench_function("day 11 part 2 sparse", |b| {
+        b.iter(|| {
+            let mut hull = Hull::sparse();
+            hull.insert((0, 0), Colour::White);
+            black_box(paint_hull_on(&input, hull).unwrap().into_panels());
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+//! Watch the robot paint the hull; q quits.
+//!
+//! Usage: day_11_animate <input file> [frames per second] [panels painted per frame] [black|white]
+//!
+//! The last argument is the colour of the panel the robot starts on: white (the default) paints
+//! the registration identifier, and black is part 1.
+
+use day_11::day_11;
+use solution::solution::{read_input, Report};
+use std::{collections::HashMap, time::Duration};
+use viz_term::viz_term::{self as viz, Frame, Outcome, Terminal};
+
+fn robot_glyph(heading: day_11::Direction) -> char {
+    match heading {
+        day_11::Direction::Up => '^',
+        day_11::Direction::Right => '>',
+        day_11::Direction::Down => 'v',
+        day_11::Direction::Left => '<',
+    }
+}
+
+fn animate(
+    input: &[i64],
+    initial: HashMap<(i32, i32), day_11::Colour>,
+    frame: Duration,
+    panels_per_frame: usize,
+) -> Result<(), Report> {
+    let steps = day_11::painting_steps(input, &initial)?;
+    let bounds @ (min_x, max_x, min_y, max_y) = day_11::painting_bounds(&steps);
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let footer = |painted: usize| format!("Panels painted: {painted} of {}", steps.len());
+    let frame_width = width.max(footer(steps.len()).len());
+
+    let mut terminal = Terminal::new()?;
+    let mut locations = initial;
+    let mut position = ((0, 0), day_11::Direction::Up);
+    let mut painted = 0;
+    let mut remaining = steps.chunks(panels_per_frame);
+    let mut started = false;
+    let outcome = terminal.run(frame, |_| {
+        // The first frame is the hull before anything is painted.
+        if started {
+            for step in remaining.next()? {
+                locations.insert(step.painted, step.colour);
+                position = (step.position, step.heading);
+                painted += 1;
+            }
+        }
+        started = true;
+
+        let mut frame = Frame::new(frame_width, height + 2);
+        frame.grid(0, 0, &day_11::layout(&locations, bounds));
+        let ((x, y), heading) = position;
+        frame.put(
+            (x - min_x) as usize,
+            (max_y - y) as usize,
+            robot_glyph(heading),
+        );
+        frame.text(0, height + 1, &footer(painted));
+        Some(frame)
+    })?;
+    if outcome == Outcome::Finished {
+        viz::wait_for_key()?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Report> {
+    let args: Vec<String> = std::env::args().collect();
+    let input_str = read_input(&args)?;
+    let fps: u32 = match args.get(2) {
+        None => 30,
+        Some(fps) => fps
+            .parse()
+            .map_err(|e| format!("Could not parse frame rate {fps} : {e}"))?,
+    };
+    if fps == 0 {
+        return Err("Frame rate must be positive".into());
+    }
+    let panels_per_frame: usize = match args.get(3) {
+        None => 5,
+        Some(panels) => panels
+            .parse()
+            .map_err(|e| format!("Could not parse panels per frame {panels} : {e}"))?,
+    };
+    if panels_per_frame == 0 {
+        return Err("Panels per frame must be positive".into());
+    }
+    let initial = match args.get(4).map(String::as_str) {
+        None | Some("white") => day_11::registration_hull(),
+        Some("black") => HashMap::new(),
+        Some(other) => {
+            return Err(format!("Starting colour must be black or white, but got {other}").into())
+        }
+    };
+    let input = day_11::input(&input_str)
+        .map_err(|e| Report::from(format!("Error while parsing input: {e}")))?;
+
+    animate(
+        &input,
+        initial,
+        Duration::from_secs(1) / fps,
+        panels_per_frame,
+    )
+}
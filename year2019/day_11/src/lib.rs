@@ -0,0 +1,770 @@
+pub mod day_11 {
+    use std::collections::HashMap;
+
+    use geometry::geometry::Point2;
+    use grid::grid::{Cell, Grid};
+    use intcode::intcode::{MachineExecutionError, MachineState};
+    use ocr::ocr::{recognize, OcrError};
+    use parse::parse::{comma_separated, ParseError};
+    use thiserror::Error;
+    #[cfg(feature = "image")]
+    use viz_image::viz_image::{self as viz, RgbaImage, BLACK, RED, WHITE};
+
+    pub use geometry::geometry::Direction;
+    use solution::solution::{solved, Answer, Solution};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Turn {
+        Left,
+        Right,
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Colour {
+        Black,
+        White,
+    }
+
+    impl Cell for Colour {
+        fn to_char(&self) -> char {
+            match self {
+                Colour::Black => '.',
+                Colour::White => 'X',
+            }
+        }
+    }
+
+    /// The most panels `Hull` will keep in a grid before moving them into a HashMap.
+    pub const MAX_DENSE_PANELS: usize = 1 << 22;
+
+    #[derive(Clone, Debug)]
+    enum Storage {
+        /// Row-major, with rows of `width` panels starting from `min_x`.
+        Dense {
+            min_x: i32,
+            min_y: i32,
+            width: usize,
+            height: usize,
+            cells: Vec<Option<Colour>>,
+        },
+        Sparse(HashMap<(i32, i32), Colour>),
+    }
+
+    /// The panels which have been painted, kept in a grid around the origin which grows as the
+    /// robot wanders off it. If the grid would grow beyond `MAX_DENSE_PANELS`, the panels move
+    /// into a HashMap instead.
+    #[derive(Clone, Debug)]
+    pub struct Hull {
+        storage: Storage,
+        painted: usize,
+    }
+
+    impl Default for Hull {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl From<HashMap<(i32, i32), Colour>> for Hull {
+        fn from(panels: HashMap<(i32, i32), Colour>) -> Self {
+            let mut hull = Hull::new();
+            for (pos, colour) in panels {
+                hull.insert(pos, colour);
+            }
+            hull
+        }
+    }
+
+    impl Hull {
+        pub fn new() -> Hull {
+            Hull {
+                storage: Storage::Dense {
+                    min_x: -8,
+                    min_y: -8,
+                    width: 16,
+                    height: 16,
+                    cells: vec![None; 16 * 16],
+                },
+                painted: 0,
+            }
+        }
+
+        /// A hull which keeps its panels in a HashMap from the start.
+        pub fn sparse() -> Hull {
+            Hull {
+                storage: Storage::Sparse(HashMap::new()),
+                painted: 0,
+            }
+        }
+
+        pub fn is_dense(&self) -> bool {
+            matches!(self.storage, Storage::Dense { .. })
+        }
+
+        /// How many panels have been painted.
+        pub fn len(&self) -> usize {
+            self.painted
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.painted == 0
+        }
+
+        pub fn get(&self, (x, y): (i32, i32)) -> Option<Colour> {
+            match &self.storage {
+                Storage::Dense {
+                    min_x,
+                    min_y,
+                    width,
+                    height,
+                    cells,
+                } => {
+                    let col = usize::try_from(x - min_x).ok().filter(|&c| c < *width)?;
+                    let row = usize::try_from(y - min_y).ok().filter(|&r| r < *height)?;
+                    cells[row * width + col]
+                }
+                Storage::Sparse(panels) => panels.get(&(x, y)).copied(),
+            }
+        }
+
+        pub fn insert(&mut self, (x, y): (i32, i32), colour: Colour) {
+            self.grow_to((x, y));
+            let previous = match &mut self.storage {
+                Storage::Dense {
+                    min_x,
+                    min_y,
+                    width,
+                    cells,
+                    ..
+                } => cells[(y - *min_y) as usize * *width + (x - *min_x) as usize].replace(colour),
+                Storage::Sparse(panels) => panels.insert((x, y), colour),
+            };
+            if previous.is_none() {
+                self.painted += 1;
+            }
+        }
+
+        /// Makes sure a dense grid covers the given panel, at least doubling it in each
+        /// direction that needs to grow, or gives up on the grid if that makes it too big.
+        fn grow_to(&mut self, (x, y): (i32, i32)) {
+            let (min_x, min_y, width, height) = match &self.storage {
+                Storage::Dense {
+                    min_x,
+                    min_y,
+                    width,
+                    height,
+                    ..
+                } => (*min_x, *min_y, *width as i32, *height as i32),
+                Storage::Sparse(_) => return,
+            };
+            let (mut new_min_x, mut new_max_x) = (min_x, min_x + width - 1);
+            let (mut new_min_y, mut new_max_y) = (min_y, min_y + height - 1);
+            if x < new_min_x {
+                new_min_x = x.min(min_x - width);
+            } else if x > new_max_x {
+                new_max_x = x.max(new_max_x + width);
+            }
+            if y < new_min_y {
+                new_min_y = y.min(min_y - height);
+            } else if y > new_max_y {
+                new_max_y = y.max(new_max_y + height);
+            }
+            if (new_min_x, new_max_x, new_min_y, new_max_y)
+                == (min_x, min_x + width - 1, min_y, min_y + height - 1)
+            {
+                return;
+            }
+
+            let new_width = (new_max_x - new_min_x + 1) as usize;
+            let new_height = (new_max_y - new_min_y + 1) as usize;
+            let panels: Vec<((i32, i32), Colour)> = self.iter().collect();
+            self.storage = if new_width * new_height > MAX_DENSE_PANELS {
+                Storage::Sparse(panels.into_iter().collect())
+            } else {
+                let mut cells = vec![None; new_width * new_height];
+                for ((x, y), colour) in panels {
+                    cells[(y - new_min_y) as usize * new_width + (x - new_min_x) as usize] =
+                        Some(colour);
+                }
+                Storage::Dense {
+                    min_x: new_min_x,
+                    min_y: new_min_y,
+                    width: new_width,
+                    height: new_height,
+                    cells,
+                }
+            };
+        }
+
+        /// Every painted panel and its colour, in no particular order.
+        pub fn iter(&self) -> Box<dyn Iterator<Item = ((i32, i32), Colour)> + '_> {
+            match &self.storage {
+                Storage::Dense {
+                    min_x,
+                    min_y,
+                    width,
+                    cells,
+                    ..
+                } => Box::new(cells.iter().enumerate().filter_map(move |(i, cell)| {
+                    let pos = (min_x + (i % width) as i32, min_y + (i / width) as i32);
+                    cell.map(|colour| (pos, colour))
+                })),
+                Storage::Sparse(panels) => Box::new(panels.iter().map(|(&pos, &c)| (pos, c))),
+            }
+        }
+
+        pub fn to_map(&self) -> HashMap<(i32, i32), Colour> {
+            self.iter().collect()
+        }
+    }
+
+    /// The emergency hull painting robot. It starts at the origin facing up; y increases upwards.
+    /// Panels which have never been painted are black.
+    #[derive(Clone, Debug)]
+    pub struct PaintingRobot {
+        position: (i32, i32),
+        heading: Direction,
+        panels: Hull,
+    }
+
+    impl PaintingRobot {
+        /// A robot on a hull whose panels are already painted as given.
+        pub fn new(panels: HashMap<(i32, i32), Colour>) -> PaintingRobot {
+            PaintingRobot::on(panels.into())
+        }
+
+        pub fn on(panels: Hull) -> PaintingRobot {
+            PaintingRobot {
+                position: (0, 0),
+                heading: Direction::Up,
+                panels,
+            }
+        }
+
+        pub fn position(&self) -> (i32, i32) {
+            self.position
+        }
+
+        pub fn heading(&self) -> Direction {
+            self.heading
+        }
+
+        /// Every panel which has been painted (or was painted to begin with).
+        pub fn panels(&self) -> &Hull {
+            &self.panels
+        }
+
+        pub fn into_panels(self) -> HashMap<(i32, i32), Colour> {
+            self.panels.to_map()
+        }
+
+        /// The colour of the panel the robot is standing on.
+        pub fn observe(&self) -> Colour {
+            self.panels.get(self.position).unwrap_or(Colour::Black)
+        }
+
+        /// Paint the panel the robot is standing on.
+        pub fn paint(&mut self, colour: Colour) {
+            self.panels.insert(self.position, colour);
+        }
+
+        /// Turn, and then move forward one panel.
+        pub fn turn(&mut self, turn: Turn) {
+            self.heading = match turn {
+                Turn::Left => self.heading.rotate_anticlockwise(),
+                Turn::Right => self.heading.rotate_clockwise(),
+            };
+            self.position = Point2::from(self.position).step(self.heading).into();
+        }
+    }
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    #[derive(Error, Debug)]
+    pub enum RobotError {
+        #[error("program asked to paint with unknown colour {0}")]
+        InvalidColour(i64),
+        #[error("program asked to turn in unknown direction {0}")]
+        InvalidTurn(i64),
+        #[error("program terminated after painting but before turning")]
+        TerminatedMidStep,
+        #[error("program asked for input after painting but before turning")]
+        UnexpectedInput,
+        #[error(transparent)]
+        Machine(#[from] MachineExecutionError),
+    }
+
+    /// One step of the painting: the panel painted, its new colour, and where the robot moved
+    /// to and which way it then faced.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct PaintStep {
+        pub painted: (i32, i32),
+        pub colour: Colour,
+        pub position: (i32, i32),
+        pub heading: Direction,
+    }
+
+    /// Drive the robot until the program terminates, telling `on_step` about each panel once it
+    /// is painted and the robot has moved.
+    fn run_machine<F>(
+        mut machine: MachineState<i64>,
+        robot: &mut PaintingRobot,
+        mut on_step: F,
+    ) -> Result<(), RobotError>
+    where
+        F: FnMut(PaintStep),
+    {
+        loop {
+            match machine.execute_until_input()? {
+                intcode::intcode::StepIoResult::Terminated => {
+                    break;
+                }
+                intcode::intcode::StepIoResult::Output(v) => {
+                    let colour = match v {
+                        0 => Colour::Black,
+                        1 => Colour::White,
+                        _ => return Err(RobotError::InvalidColour(v)),
+                    };
+                    let painted = robot.position();
+                    robot.paint(colour);
+                    match machine.execute_until_input()? {
+                        intcode::intcode::StepIoResult::Terminated => {
+                            return Err(RobotError::TerminatedMidStep);
+                        }
+                        intcode::intcode::StepIoResult::AwaitingInput(_) => {
+                            return Err(RobotError::UnexpectedInput);
+                        }
+                        intcode::intcode::StepIoResult::Output(v) => {
+                            match v {
+                                0 => robot.turn(Turn::Left),
+                                1 => robot.turn(Turn::Right),
+                                _ => return Err(RobotError::InvalidTurn(v)),
+                            }
+                            on_step(PaintStep {
+                                painted,
+                                colour,
+                                position: robot.position(),
+                                heading: robot.heading(),
+                            });
+                        }
+                    }
+                }
+                intcode::intcode::StepIoResult::AwaitingInput(_) => {
+                    machine.push_input((robot.observe() == Colour::White) as i64);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run the robot's program to completion on a hull whose panels are initially painted as
+    /// given (unmentioned panels are black), returning the robot in its final state.
+    pub fn paint_hull(
+        input: &[i64],
+        initial: HashMap<(i32, i32), Colour>,
+    ) -> Result<PaintingRobot, RobotError> {
+        paint_hull_on(input, initial.into())
+    }
+
+    /// As `paint_hull`, but with control over how the hull is stored.
+    pub fn paint_hull_on(input: &[i64], hull: Hull) -> Result<PaintingRobot, RobotError> {
+        let machine = MachineState::new_with_memory(&input.iter().copied());
+        let mut robot = PaintingRobot::on(hull);
+        run_machine(machine, &mut robot, |_| {})?;
+        Ok(robot)
+    }
+
+    /// Every step the robot takes painting a hull whose panels are initially painted as given.
+    pub fn painting_steps(
+        input: &[i64],
+        initial: &HashMap<(i32, i32), Colour>,
+    ) -> Result<Vec<PaintStep>, RobotError> {
+        let machine = MachineState::new_with_memory(&input.iter().copied());
+        let mut steps = Vec::new();
+        let mut robot = PaintingRobot::new(initial.clone());
+        run_machine(machine, &mut robot, |step| steps.push(step))?;
+        Ok(steps)
+    }
+
+    /// Part 2's hull: a single white panel under the robot.
+    pub fn registration_hull() -> HashMap<(i32, i32), Colour> {
+        HashMap::from([((0, 0), Colour::White)])
+    }
+
+    pub fn part_1(input: &[i64]) -> Result<u32, RobotError> {
+        let robot = paint_hull(input, HashMap::new())?;
+
+        Ok(robot.panels().len() as u32)
+    }
+
+    /// Inclusive (min_x, max_x, min_y, max_y) of the given positions; if there are none, the
+    /// minima are greater than the maxima.
+    fn bounds<I>(positions: I) -> (i32, i32, i32, i32)
+    where
+        I: IntoIterator<Item = (i32, i32)>,
+    {
+        positions.into_iter().fold(
+            (i32::MAX, i32::MIN, i32::MAX, i32::MIN),
+            |(min_x, max_x, min_y, max_y), (x, y)| {
+                (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+            },
+        )
+    }
+
+    /// Inclusive (min_x, max_x, min_y, max_y) of everywhere the robot went or painted, including
+    /// where it started.
+    pub fn painting_bounds(steps: &[PaintStep]) -> (i32, i32, i32, i32) {
+        bounds(
+            steps
+                .iter()
+                .flat_map(|step| [step.painted, step.position])
+                .chain(std::iter::once((0, 0))),
+        )
+    }
+
+    /// The panels within the inclusive (min_x, max_x, min_y, max_y) bounds as a grid, with up
+    /// towards the first row; unpainted panels are black.
+    pub fn layout(
+        map: &HashMap<(i32, i32), Colour>,
+        (min_x, max_x, min_y, max_y): (i32, i32, i32, i32),
+    ) -> Grid<Colour> {
+        let rows = if max_y < min_y { 0 } else { max_y - min_y + 1 };
+        let cols = if max_x < min_x { 0 } else { max_x - min_x + 1 };
+        Grid::from_fn(rows as usize, cols as usize, |(row, col)| {
+            map.get(&(min_x + col as i32, max_y - row as i32))
+                .copied()
+                .unwrap_or(Colour::Black)
+        })
+    }
+
+    fn format_map(map: &HashMap<(i32, i32), Colour>) -> String {
+        layout(map, bounds(map.keys().copied())).to_string()
+    }
+
+    #[derive(Error, Debug)]
+    pub enum RegistrationError {
+        #[error(transparent)]
+        Robot(#[from] RobotError),
+        #[error("could not read the registration identifier: {0}")]
+        Ocr(#[from] OcrError),
+    }
+
+    fn paint_registration(input: &[i64]) -> Result<HashMap<(i32, i32), Colour>, RobotError> {
+        Ok(paint_hull(input, registration_hull())?.into_panels())
+    }
+
+    /// The registration identifier as ASCII art, with painted panels drawn as 'X'.
+    pub fn render(input: &[i64]) -> Result<String, RobotError> {
+        Ok(format_map(&paint_registration(input)?))
+    }
+
+    /// Read the letters painted in white onto the hull.
+    fn identify(locations: &HashMap<(i32, i32), Colour>) -> Result<String, OcrError> {
+        let lit = locations
+            .iter()
+            .filter(|(_, &colour)| colour == Colour::White)
+            .map(|(pos, _)| *pos);
+        let grid = layout(locations, bounds(lit));
+        recognize(&grid.map(|colour| *colour == Colour::White))
+    }
+
+    pub fn part_2(input: &[i64]) -> Result<String, RegistrationError> {
+        Ok(identify(&paint_registration(input)?)?)
+    }
+
+    #[cfg(feature = "image")]
+    #[derive(Error, Debug)]
+    pub enum ExportError {
+        #[error(transparent)]
+        Robot(#[from] RobotError),
+        #[error(transparent)]
+        Export(#[from] viz::ExportError),
+    }
+
+    /// Draw the hull within the given bounds, with each panel drawn as a `scale`-by-`scale`
+    /// square; up is towards the top of the image.
+    #[cfg(feature = "image")]
+    fn draw_hull(
+        locations: &HashMap<(i32, i32), Colour>,
+        robot: Option<(i32, i32)>,
+        (min_x, max_x, min_y, max_y): (i32, i32, i32, i32),
+        scale: u32,
+    ) -> RgbaImage {
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        viz::draw(height, width, scale, |row, col| {
+            let pos = (min_x + col as i32, max_y - row as i32);
+            if robot == Some(pos) {
+                RED
+            } else if locations.get(&pos) == Some(&Colour::White) {
+                WHITE
+            } else {
+                BLACK
+            }
+        })
+    }
+
+    /// The hull once the robot has finished, with each panel drawn as a `scale`-by-`scale`
+    /// square. See `paint_hull` for the meaning of `initial`.
+    #[cfg(feature = "image")]
+    pub fn hull_image(
+        input: &[i64],
+        initial: &HashMap<(i32, i32), Colour>,
+        scale: u32,
+    ) -> Result<RgbaImage, RobotError> {
+        let locations = paint_hull(input, initial.clone())?.into_panels();
+        let bounds = bounds(locations.keys().copied().chain(std::iter::once((0, 0))));
+
+        Ok(draw_hull(&locations, None, bounds, scale))
+    }
+
+    /// Save the finished hull as a PNG, with each panel drawn as a `scale`-by-`scale` square.
+    #[cfg(feature = "image")]
+    pub fn to_png<P>(
+        input: &[i64],
+        initial: &HashMap<(i32, i32), Colour>,
+        path: P,
+        scale: u32,
+    ) -> Result<(), ExportError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Ok(viz::save_png(&hull_image(input, initial, scale)?, path)?)
+    }
+
+    /// Frames of the painting process: the starting hull, then one frame every `frame_every`
+    /// panels painted, and finally the finished hull. The robot is drawn in red.
+    #[cfg(feature = "image")]
+    pub fn painting_frames(
+        input: &[i64],
+        initial: &HashMap<(i32, i32), Colour>,
+        scale: u32,
+        frame_every: usize,
+    ) -> Result<Vec<RgbaImage>, RobotError> {
+        assert!(frame_every > 0, "frame_every must be positive");
+
+        let steps = painting_steps(input, initial)?;
+        let bounds = painting_bounds(&steps);
+        let frame = |locations: &HashMap<(i32, i32), Colour>, robot| {
+            draw_hull(locations, robot, bounds, scale)
+        };
+
+        let mut locations = initial.clone();
+        let mut frames = vec![frame(&locations, Some((0, 0)))];
+        for (i, step) in steps.iter().enumerate() {
+            locations.insert(step.painted, step.colour);
+            if (i + 1) % frame_every == 0 || i + 1 == steps.len() {
+                frames.push(frame(&locations, Some(step.position)));
+            }
+        }
+
+        Ok(frames)
+    }
+
+    /// Save an animation of the painting process as a looping GIF; see `painting_frames`.
+    #[cfg(feature = "image")]
+    pub fn to_gif<P>(
+        input: &[i64],
+        initial: &HashMap<(i32, i32), Colour>,
+        path: P,
+        scale: u32,
+        frame_every: usize,
+        frame_delay_ms: u32,
+    ) -> Result<(), ExportError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let frames = painting_frames(input, initial, scale, frame_every)?;
+        Ok(viz::save_gif(frames, frame_delay_ms, path)?)
+    }
+
+    pub struct Day11;
+
+    impl Solution for Day11 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_11::*;
+    use snapshot::assert_grid_snapshot;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_robot() {
+        // The example from the puzzle text.
+        let mut robot = PaintingRobot::new(Default::default());
+        for (colour, turn) in [
+            (Colour::White, Turn::Left),
+            (Colour::Black, Turn::Left),
+            (Colour::White, Turn::Left),
+            (Colour::White, Turn::Left),
+            (Colour::Black, Turn::Right),
+            (Colour::White, Turn::Left),
+            (Colour::White, Turn::Left),
+        ] {
+            robot.paint(colour);
+            robot.turn(turn);
+        }
+        assert_eq!(robot.position(), (0, 1));
+        assert_eq!(robot.heading(), Direction::Left);
+        assert_eq!(robot.observe(), Colour::Black);
+        assert_eq!(robot.panels().len(), 6);
+        let white = robot
+            .panels()
+            .iter()
+            .filter(|&(_, c)| c == Colour::White)
+            .count();
+        assert_eq!(white, 4);
+    }
+
+    #[test]
+    fn test_robot_errors() {
+        let run = |program: &str| paint_hull(&input(program).unwrap(), HashMap::new());
+        assert!(matches!(run("104,2,99"), Err(RobotError::InvalidColour(2))));
+        assert!(matches!(
+            run("104,1,104,5,99"),
+            Err(RobotError::InvalidTurn(5))
+        ));
+        assert!(matches!(
+            run("104,1,99"),
+            Err(RobotError::TerminatedMidStep)
+        ));
+        assert!(matches!(
+            run("104,1,3,0,99"),
+            Err(RobotError::UnexpectedInput)
+        ));
+    }
+
+    #[test]
+    fn test_initial_hull() {
+        // Repaint the current panel with its inverse, then turn right; four times.
+        let step = "3,100,1002,100,-1,100,1001,100,1,100,4,100,104,1";
+        let program = input(&format!("{step},{step},{step},{step},99")).unwrap();
+
+        let robot = paint_hull(&program, HashMap::new()).unwrap();
+        assert_eq!(robot.position(), (0, 0));
+        assert!(robot.panels().iter().all(|(_, c)| c == Colour::White));
+
+        let all_white = [(0, 0), (1, 0), (1, -1), (0, -1)]
+            .into_iter()
+            .map(|pos| (pos, Colour::White))
+            .collect();
+        let robot = paint_hull(&program, all_white).unwrap();
+        assert_eq!(robot.panels().len(), 4);
+        assert!(robot.panels().iter().all(|(_, c)| c == Colour::Black));
+
+        let robot = paint_hull(&program, registration_hull()).unwrap();
+        assert_eq!(robot.observe(), Colour::Black);
+        assert_eq!(robot.panels().get((1, -1)), Some(Colour::White));
+    }
+
+    #[test]
+    fn test_painting_steps() {
+        // Paint white, turn left; twice.
+        let program = input("3,100,104,1,104,0,3,100,104,1,104,0,99").unwrap();
+        let steps = painting_steps(&program, &HashMap::new()).unwrap();
+        assert_eq!(
+            steps,
+            vec![
+                PaintStep {
+                    painted: (0, 0),
+                    colour: Colour::White,
+                    position: (-1, 0),
+                    heading: Direction::Left,
+                },
+                PaintStep {
+                    painted: (-1, 0),
+                    colour: Colour::White,
+                    position: (-1, -1),
+                    heading: Direction::Down,
+                },
+            ]
+        );
+        assert_eq!(painting_bounds(&steps), (-1, 0, -1, 0));
+    }
+
+    #[test]
+    fn test_hull_growth() {
+        let mut hull = Hull::new();
+        let mut sparse = Hull::sparse();
+        let panels = [(0, 0), (100, 0), (-3, -250), (7, 7), (0, 0)];
+        for (i, &pos) in panels.iter().enumerate() {
+            let colour = if i % 2 == 0 {
+                Colour::White
+            } else {
+                Colour::Black
+            };
+            hull.insert(pos, colour);
+            sparse.insert(pos, colour);
+        }
+        assert!(hull.is_dense());
+        assert!(!sparse.is_dense());
+        assert_eq!(hull.len(), 4);
+        assert_eq!(hull.to_map(), sparse.to_map());
+        assert_eq!(hull.get((100, 0)), Some(Colour::Black));
+        assert_eq!(hull.get((100, 1)), None);
+        assert_eq!(hull.get((-1000, 0)), None);
+
+        // Too far to keep in a grid.
+        hull.insert((1 << 20, 1 << 20), Colour::White);
+        assert!(!hull.is_dense());
+        assert_eq!(hull.len(), 5);
+        assert_eq!(hull.get((-3, -250)), Some(Colour::White));
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_images() {
+        use viz_image::viz_image::{BLACK, RED, WHITE};
+
+        // Paint white, turn left; twice.
+        let program = input("3,100,104,1,104,0,3,100,104,1,104,0,99").unwrap();
+        let hull = hull_image(&program, &HashMap::new(), 2).unwrap();
+        assert_eq!(hull.dimensions(), (4, 2));
+        assert_eq!(*hull.get_pixel(0, 0), WHITE);
+        assert_eq!(*hull.get_pixel(3, 1), WHITE);
+
+        let frames = painting_frames(&program, &HashMap::new(), 1, 1).unwrap();
+        assert_eq!(frames.len(), 3);
+        // The robot ends up one panel below where it painted its second panel.
+        let last = &frames[2];
+        assert_eq!(last.dimensions(), (2, 2));
+        assert_eq!(*last.get_pixel(0, 0), WHITE);
+        assert_eq!(*last.get_pixel(1, 0), WHITE);
+        assert_eq!(*last.get_pixel(0, 1), RED);
+        assert_eq!(*last.get_pixel(1, 1), BLACK);
+
+        assert_eq!(
+            painting_frames(&program, &HashMap::new(), 1, 5)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_day_11() {
+        let Some(input_str) = loader::loader::load(11) else {
+            return;
+        };
+        let input = input(&input_str).unwrap();
+        assert_grid_snapshot!(11, 2, render(&input).unwrap());
+    }
+}
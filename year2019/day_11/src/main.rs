@@ -0,0 +1,23 @@
+use day_11::day_11;
+use solution::solution::{read_input, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let input_str = read_input(&args)?;
+    let input = match day_11::input(&input_str) {
+        Ok(input) => input,
+        Err(e) => return Err(format!("Error while parsing input: {e}").into()),
+    };
+
+    println!("part 1 => {}", day_11::part_1(&input)?);
+    match day_11::part_2(&input) {
+        Ok(identifier) => println!("part 2 => {identifier}"),
+        Err(day_11::RegistrationError::Ocr(e)) => {
+            println!("part 2 => (could not read letters: {e})");
+            println!("{}", day_11::render(&input)?);
+        }
+        Err(e) => return Err(e.into()),
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_12::day_12::{input, part_1, part_1_fast, part_2, part_2_fast};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input_str) = loader::loader::load(12) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 12 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&input));
+        })
+    });
+    c.bench_function("day 12 part 1 struct of arrays", |b| {
+        b.iter(|| {
+            black_box(part_1_fast(&input));
+        })
+    });
+    c.bench_function("day 12 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&input));
+        })
+    });
+    c.bench_function("day 12 part 2 struct of arrays", |b| {
+        b.iter(|| {
+            black_box(part_2_fast(&input));
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
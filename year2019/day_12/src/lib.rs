@@ -0,0 +1,353 @@
+pub mod day_12 {
+    use std::ops::{Add, AddAssign};
+
+    use numtheory::numtheory::lcm;
+    use parse::parse::{error_at, lines_with, token, ParseError};
+    use solution::solution::{answer, Answer, Solution};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Vector {
+        pub x: i32,
+        pub y: i32,
+        pub z: i32,
+    }
+
+    impl Add for Vector {
+        type Output = Vector;
+        fn add(self, other: Vector) -> Vector {
+            Vector {
+                x: self.x + other.x,
+                y: self.y + other.y,
+                z: self.z + other.z,
+            }
+        }
+    }
+
+    impl AddAssign for Vector {
+        fn add_assign(&mut self, other: Vector) {
+            *self = *self + other;
+        }
+    }
+
+    impl Vector {
+        fn manhattan(&self) -> i32 {
+            self.x.abs() + self.y.abs() + self.z.abs()
+        }
+
+        /// The pull on a moon at `self` towards a moon at `other`: one unit along each axis.
+        fn pull_towards(&self, other: &Vector) -> Vector {
+            Vector {
+                x: (other.x - self.x).signum(),
+                y: (other.y - self.y).signum(),
+                z: (other.z - self.z).signum(),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Moon {
+        pub position: Vector,
+        pub velocity: Vector,
+    }
+
+    impl Moon {
+        pub fn new(position: Vector) -> Moon {
+            Moon {
+                position,
+                velocity: Vector::default(),
+            }
+        }
+
+        pub fn energy(&self) -> i32 {
+            self.position.manhattan() * self.velocity.manhattan()
+        }
+    }
+
+    /// Parses lines of the form `<x=-1, y=0, z=2>`.
+    pub fn input(s: &str) -> Result<Vec<Vector>, ParseError> {
+        lines_with(s, |l| {
+            let inner = l
+                .strip_prefix('<')
+                .and_then(|l| l.strip_suffix('>'))
+                .ok_or_else(|| {
+                    error_at(s, l, "expected a position of the form <x=.., y=.., z=..>")
+                })?;
+            let mut coords = inner.split(',').map(str::trim);
+            let mut coord = |name| {
+                let c = coords.next().ok_or_else(|| {
+                    error_at(
+                        s,
+                        &inner[inner.len()..],
+                        format!("missing {name} coordinate"),
+                    )
+                })?;
+                match c.split_once('=') {
+                    Some((n, value)) if n == name => token(s, value),
+                    _ => Err(error_at(s, c, format!("expected {name}=.."))),
+                }
+            };
+            let position = Vector {
+                x: coord("x")?,
+                y: coord("y")?,
+                z: coord("z")?,
+            };
+            match coords.next() {
+                None => Ok(position),
+                Some(extra) => Err(error_at(
+                    s,
+                    extra,
+                    "expected nothing after the z coordinate",
+                )),
+            }
+        })
+    }
+
+    /// Applies gravity between every pair of moons, then moves each moon by its velocity.
+    pub fn step(moons: &mut [Moon]) {
+        for i in 0..moons.len() {
+            for j in i + 1..moons.len() {
+                let pull = moons[i].position.pull_towards(&moons[j].position);
+                moons[i].velocity += pull;
+                moons[j].velocity += Vector {
+                    x: -pull.x,
+                    y: -pull.y,
+                    z: -pull.z,
+                };
+            }
+        }
+        for moon in moons.iter_mut() {
+            moon.position += moon.velocity;
+        }
+    }
+
+    pub fn simulate(positions: &[Vector], steps: usize) -> Vec<Moon> {
+        let mut moons: Vec<Moon> = positions.iter().copied().map(Moon::new).collect();
+        for _ in 0..steps {
+            step(&mut moons);
+        }
+        moons
+    }
+
+    pub fn total_energy(positions: &[Vector], steps: usize) -> i32 {
+        simulate(positions, steps).iter().map(Moon::energy).sum()
+    }
+
+    pub fn part_1(positions: &[Vector]) -> i32 {
+        total_energy(positions, 1000)
+    }
+
+    /// The number of steps before the moons first return to their starting state.
+    /// The axes evolve independently, so this is the LCM of each axis's own period.
+    /// Each step is invertible, so the first repeated state is always the starting one.
+    pub fn period(positions: &[Vector]) -> u64 {
+        let axes: [fn(&Vector) -> i32; 3] = [|v| v.x, |v| v.y, |v| v.z];
+        let axis_state = |moons: &[Moon], axis: fn(&Vector) -> i32| -> Vec<(i32, i32)> {
+            moons
+                .iter()
+                .map(|m| (axis(&m.position), axis(&m.velocity)))
+                .collect()
+        };
+
+        let mut moons: Vec<Moon> = positions.iter().copied().map(Moon::new).collect();
+        let initial: Vec<Vec<(i32, i32)>> = axes.iter().map(|&a| axis_state(&moons, a)).collect();
+        let mut periods: [Option<u64>; 3] = [None; 3];
+        let mut steps = 0;
+        while periods.iter().any(Option::is_none) {
+            step(&mut moons);
+            steps += 1;
+            for (i, &axis) in axes.iter().enumerate() {
+                if periods[i].is_none() && axis_state(&moons, axis) == initial[i] {
+                    periods[i] = Some(steps);
+                }
+            }
+        }
+
+        periods.iter().flatten().fold(1, |acc, &p| lcm(acc, p))
+    }
+
+    pub fn part_2(positions: &[Vector]) -> u64 {
+        period(positions)
+    }
+
+    /// One axis of every moon's state, with the positions and velocities each stored
+    /// contiguously so that the pairwise gravity update is a tight loop over a slice.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Axis {
+        pub positions: Vec<i32>,
+        pub velocities: Vec<i32>,
+    }
+
+    impl Axis {
+        pub fn new(positions: Vec<i32>) -> Axis {
+            let velocities = vec![0; positions.len()];
+            Axis {
+                positions,
+                velocities,
+            }
+        }
+
+        pub fn step(&mut self) {
+            for (i, velocity) in self.velocities.iter_mut().enumerate() {
+                let position = self.positions[i];
+                *velocity += self
+                    .positions
+                    .iter()
+                    .map(|&other| (other - position).signum())
+                    .sum::<i32>();
+            }
+            for (position, velocity) in self.positions.iter_mut().zip(self.velocities.iter()) {
+                *position += velocity;
+            }
+        }
+    }
+
+    /// Splits the moons into their x, y and z axes.
+    pub fn axes(positions: &[Vector]) -> [Axis; 3] {
+        [
+            Axis::new(positions.iter().map(|v| v.x).collect()),
+            Axis::new(positions.iter().map(|v| v.y).collect()),
+            Axis::new(positions.iter().map(|v| v.z).collect()),
+        ]
+    }
+
+    /// Brent's algorithm: the length of the cycle which repeated application of `step` eventually
+    /// falls into, keeping only two states at a time.
+    pub fn cycle_length<T, F>(start: &T, step: &mut F) -> u64
+    where
+        T: Clone + PartialEq,
+        F: FnMut(&mut T),
+    {
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = start.clone();
+        let mut hare = start.clone();
+        step(&mut hare);
+        while tortoise != hare {
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+            step(&mut hare);
+            length += 1;
+        }
+        length
+    }
+
+    pub fn total_energy_fast(positions: &[Vector], steps: usize) -> i32 {
+        let mut axes = axes(positions);
+        for axis in axes.iter_mut() {
+            for _ in 0..steps {
+                axis.step();
+            }
+        }
+        (0..positions.len())
+            .map(|i| {
+                let potential: i32 = axes.iter().map(|a| a.positions[i].abs()).sum();
+                let kinetic: i32 = axes.iter().map(|a| a.velocities[i].abs()).sum();
+                potential * kinetic
+            })
+            .sum()
+    }
+
+    /// As `period`, but finding each axis's cycle with Brent's algorithm on the struct-of-arrays
+    /// representation.
+    pub fn period_fast(positions: &[Vector]) -> u64 {
+        axes(positions)
+            .iter()
+            .map(|axis| cycle_length(axis, &mut Axis::step))
+            .fold(1, lcm)
+    }
+
+    pub fn part_1_fast(positions: &[Vector]) -> i32 {
+        total_energy_fast(positions, 1000)
+    }
+
+    pub fn part_2_fast(positions: &[Vector]) -> u64 {
+        period_fast(positions)
+    }
+
+    pub struct Day12;
+
+    impl Solution for Day12 {
+        type Parsed<'a> = Vec<Vector>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_12::*;
+
+    const EXAMPLE_1: &str = include_str!("../examples/example-1.txt");
+
+    const EXAMPLE_2: &str = include_str!("../examples/example-2.txt");
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            input("<x=1, y=2, z=3>\n<x=1, z=2, y=3>")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 7: expected y=.."
+        );
+        assert_eq!(
+            input("<x=1, y=2>").unwrap_err().to_string(),
+            "line 1, column 10: missing z coordinate"
+        );
+        assert_eq!(
+            input("<x=1, y=2, z=3, w=4>").unwrap_err().to_string(),
+            "line 1, column 17: expected nothing after the z coordinate"
+        );
+    }
+
+    #[test]
+    fn part1_known() {
+        let positions = input(EXAMPLE_1).unwrap();
+        assert_eq!(
+            positions[1],
+            Vector {
+                x: 2,
+                y: -10,
+                z: -7
+            }
+        );
+
+        let moons = simulate(&positions, 10);
+        assert_eq!(moons[0].position, Vector { x: 2, y: 1, z: -3 });
+        assert_eq!(moons[0].velocity, Vector { x: -3, y: -2, z: 1 });
+        assert_eq!(total_energy(&positions, 10), 179);
+
+        assert_eq!(total_energy(&input(EXAMPLE_2).unwrap(), 100), 1940);
+        assert_eq!(total_energy_fast(&positions, 10), 179);
+        assert_eq!(total_energy_fast(&input(EXAMPLE_2).unwrap(), 100), 1940);
+    }
+
+    #[test]
+    fn part2_known() {
+        assert_eq!(part_2(&input(EXAMPLE_1).unwrap()), 2772);
+        assert_eq!(part_2(&input(EXAMPLE_2).unwrap()), 4686774924);
+        assert_eq!(part_2_fast(&input(EXAMPLE_1).unwrap()), 2772);
+        assert_eq!(part_2_fast(&input(EXAMPLE_2).unwrap()), 4686774924);
+    }
+
+    #[test]
+    fn test_cycle_length() {
+        // 0, 1, 2, 3, 4, 5, 2, 3, 4, 5, ...
+        let mut step = |x: &mut u32| *x = if *x < 5 { *x + 1 } else { 2 };
+        assert_eq!(cycle_length(&0, &mut step), 4);
+        assert_eq!(cycle_length(&3, &mut step), 4);
+        assert_eq!(cycle_length(&7, &mut |x: &mut u32| *x = 7), 1);
+    }
+}
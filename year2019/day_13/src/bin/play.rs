@@ -0,0 +1,101 @@
+//! Play the arcade game in the terminal: left and right arrows move the paddle, q quits.
+//!
+//! Usage: day_13_play <input file> [frames per second]
+
+use day_13::day_13;
+use solution::solution::{read_input, Report};
+use std::{io, time::Duration};
+use viz_term::viz_term::{self as viz, Frame, KeyCode, Terminal};
+
+fn draw(state: &day_13::GameState, footer: &str) -> Frame {
+    let picture = state.to_string();
+    let status = format!(
+        "Score: {}    Blocks: {}    {footer}",
+        state.score(),
+        state.blocks_remaining()
+    );
+    let (width, height) = state.size();
+    let (width, height) = (width as usize, height as usize);
+    let mut frame = Frame::new(width.max(status.len()), height + 2);
+    frame.sprite(0, 0, &picture);
+    frame.text(0, height + 1, &status);
+    frame
+}
+
+/// Draws each frame and takes the joystick position from the arrow keys.
+struct TerminalController {
+    terminal: Terminal,
+    frame: Duration,
+    quit: bool,
+    error: Option<io::Error>,
+}
+
+impl TerminalController {
+    fn step(&mut self, state: &day_13::GameState) -> io::Result<i32> {
+        self.terminal
+            .present(draw(state, "<- -> to move, q to quit"))?;
+        let mut joystick = 0;
+        for key in viz::keys_within(self.frame)? {
+            match key {
+                KeyCode::Left => joystick = -1,
+                KeyCode::Right => joystick = 1,
+                key if viz::is_quit(&key) => self.quit = true,
+                _ => {}
+            }
+        }
+        Ok(joystick)
+    }
+}
+
+impl day_13::Controller for TerminalController {
+    fn joystick(&mut self, state: &day_13::GameState) -> i32 {
+        self.step(state).unwrap_or_else(|e| {
+            self.error = Some(e);
+            0
+        })
+    }
+
+    fn should_stop(&self) -> bool {
+        self.quit || self.error.is_some()
+    }
+}
+
+fn play(input: &[i32], frame: Duration) -> Result<i32, Report> {
+    let mut controller = TerminalController {
+        terminal: Terminal::new()?,
+        frame,
+        quit: false,
+        error: None,
+    };
+    let state = day_13::run_game(input, &mut controller, None).map_err(|e| format!("{e:?}"))?;
+    if let Some(e) = controller.error {
+        return Err(e.into());
+    }
+    if !controller.quit {
+        controller
+            .terminal
+            .present(draw(&state, "Game over; press any key"))?;
+        viz::wait_for_key()?;
+    }
+    Ok(state.score())
+}
+
+fn main() -> Result<(), Report> {
+    let args: Vec<String> = std::env::args().collect();
+    let input_str = read_input(&args)?;
+    let fps: u32 = match args.get(2) {
+        None => 10,
+        Some(fps) => fps
+            .parse()
+            .map_err(|e| format!("Could not parse frame rate {fps} : {e}"))?,
+    };
+    if fps == 0 {
+        return Err("Frame rate must be positive".into());
+    }
+    let input = day_13::input(&input_str)
+        .map_err(|e| Report::from(format!("Error while parsing input: {e}")))?;
+
+    let score = play(&input, Duration::from_secs(1) / fps)?;
+    println!("Final score: {score}");
+    Ok(())
+}
@@ -0,0 +1,733 @@
+pub mod day_13 {
+    use std::collections::HashMap;
+    use std::io::Write;
+
+    use intcode::intcode::{MachineExecutionError, MachineState, Patch, Program};
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, AocError, Explainer, Solution, SolveError};
+    use thiserror::Error;
+    #[cfg(feature = "image")]
+    use viz_image::viz_image::{self as viz, RgbaImage, BLACK, BLUE, GREY, RED, WHITE};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Tile {
+        Empty,
+        Wall,
+        Block,
+        Paddle,
+        Ball,
+    }
+
+    impl Tile {
+        fn from_int(i: i32) -> Option<Tile> {
+            match i {
+                0 => Some(Tile::Empty),
+                1 => Some(Tile::Wall),
+                2 => Some(Tile::Block),
+                3 => Some(Tile::Paddle),
+                4 => Some(Tile::Ball),
+                _ => None,
+            }
+        }
+
+        pub fn to_char(self) -> char {
+            match self {
+                Tile::Empty => ' ',
+                Tile::Wall => '#',
+                Tile::Block => '=',
+                Tile::Paddle => '-',
+                Tile::Ball => 'o',
+            }
+        }
+    }
+
+    /// Everything the game has drawn so far.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct GameState {
+        tiles: HashMap<(i32, i32), Tile>,
+        score: i32,
+        ball: Option<(i32, i32)>,
+        paddle: Option<(i32, i32)>,
+    }
+
+    impl GameState {
+        pub fn score(&self) -> i32 {
+            self.score
+        }
+
+        pub fn tile(&self, x: i32, y: i32) -> Tile {
+            *self.tiles.get(&(x, y)).unwrap_or(&Tile::Empty)
+        }
+
+        pub fn ball(&self) -> Option<(i32, i32)> {
+            self.ball
+        }
+
+        pub fn paddle(&self) -> Option<(i32, i32)> {
+            self.paddle
+        }
+
+        pub fn blocks_remaining(&self) -> usize {
+            self.tiles.values().filter(|&&t| t == Tile::Block).count()
+        }
+
+        /// One more than the largest x and y coordinates drawn so far.
+        pub fn size(&self) -> (i32, i32) {
+            self.tiles
+                .keys()
+                .fold((0, 0), |(w, h), &(x, y)| (w.max(x + 1), h.max(y + 1)))
+        }
+
+        fn apply(&mut self, x: i32, y: i32, v: i32) -> Result<(), AocError> {
+            if x == -1 && y == 0 {
+                self.score = v;
+                return Ok(());
+            }
+            let tile =
+                Tile::from_int(v).ok_or(AocError::UnexpectedOutput(v as i64, "drawing a tile"))?;
+            match tile {
+                Tile::Ball => self.ball = Some((x, y)),
+                Tile::Paddle => self.paddle = Some((x, y)),
+                _ => {}
+            }
+            self.tiles.insert((x, y), tile);
+            Ok(())
+        }
+    }
+
+    impl std::fmt::Display for GameState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let (width, height) = self.size();
+            for y in 0..height {
+                for x in 0..width {
+                    write!(f, "{}", self.tile(x, y).to_char())?;
+                }
+                writeln!(f)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum GameStatus {
+        AwaitingJoystick,
+        GameOver,
+    }
+
+    /// Inserts the quarters which make the game playable.
+    pub const FREE_PLAY: Patch<i32> = Patch {
+        addr: 0,
+        value: 2,
+        label: "insert quarters",
+    };
+
+    /// A running game, which can be advanced one joystick input at a time.
+    #[derive(Clone)]
+    pub struct Game {
+        machine: MachineState<i32>,
+        state: GameState,
+    }
+
+    impl Game {
+        /// Start a game. With `free_play`, quarters are inserted so that the game is playable
+        /// rather than merely drawing the screen.
+        pub fn new(program: &[i32], free_play: bool) -> Game {
+            let mut program = Program::new(program.iter().copied());
+            if free_play {
+                program = program.with_patches(&[FREE_PLAY]);
+            }
+            Game {
+                machine: MachineState::from_program(&program),
+                state: GameState::default(),
+            }
+        }
+
+        pub fn state(&self) -> &GameState {
+            &self.state
+        }
+
+        fn next_output(&mut self) -> Result<i32, AocError> {
+            match self.machine.execute_until_input()? {
+                intcode::intcode::StepIoResult::Terminated => {
+                    Err(AocError::Terminated("partway through drawing a tile"))
+                }
+                intcode::intcode::StepIoResult::AwaitingInput(_) => {
+                    Err(AocError::UnexpectedInput("partway through drawing a tile"))
+                }
+                intcode::intcode::StepIoResult::Output(v) => Ok(v),
+            }
+        }
+
+        /// Run the game until it wants the joystick position or finishes.
+        pub fn advance(&mut self) -> Result<GameStatus, AocError> {
+            if self.machine.is_awaiting_input() {
+                return Ok(GameStatus::AwaitingJoystick);
+            }
+            loop {
+                match self.machine.execute_until_input()? {
+                    intcode::intcode::StepIoResult::Terminated => {
+                        return Ok(GameStatus::GameOver);
+                    }
+                    intcode::intcode::StepIoResult::Output(x) => {
+                        let y = self.next_output()?;
+                        let v = self.next_output()?;
+                        self.state.apply(x, y, v)?;
+                    }
+                    intcode::intcode::StepIoResult::AwaitingInput(_) => {
+                        return Ok(GameStatus::AwaitingJoystick);
+                    }
+                }
+            }
+        }
+
+        /// Tilt the joystick: -1 for left, 0 for neutral, 1 for right.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the game is not awaiting the joystick (see `advance`).
+        pub fn set_joystick(&mut self, position: i32) {
+            assert!(
+                self.machine.is_awaiting_input(),
+                "game was not awaiting the joystick"
+            );
+            self.machine.push_input(position);
+        }
+    }
+
+    pub fn input(s: &str) -> Result<Vec<i32>, ParseError> {
+        comma_separated(s)
+    }
+
+    pub fn part_1(input: &[i32]) -> Result<u32, AocError> {
+        // Without free play, the game just draws the screen; tiles are decoded as they are
+        // emitted.
+        let mut game = Game::new(input, false);
+        match game.advance()? {
+            GameStatus::GameOver => Ok(game.state().blocks_remaining() as u32),
+            GameStatus::AwaitingJoystick => Err(AocError::UnexpectedInput("drawing the screen")),
+        }
+    }
+
+    /// A snapshot of the screen, taken whenever the game asks for the joystick and once more
+    /// when it finishes.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Frame {
+        pub state: GameState,
+        /// Where the joystick was tilted in response to this frame; `None` for the final frame.
+        pub joystick: Option<i32>,
+    }
+
+    /// A strategy for playing the game.
+    pub trait Controller {
+        /// Where to tilt the joystick, given the screen: -1 for left, 0 for neutral, 1 for right.
+        fn joystick(&mut self, state: &GameState) -> i32;
+
+        /// Checked before each move; if true, the game is abandoned where it stands.
+        fn should_stop(&self) -> bool {
+            false
+        }
+    }
+
+    /// Keeps the paddle underneath the ball, which wins every time.
+    #[derive(Copy, Clone, Debug, Default)]
+    pub struct FollowBall;
+
+    impl Controller for FollowBall {
+        fn joystick(&mut self, state: &GameState) -> i32 {
+            let paddle_x = state.paddle().map_or(0, |(x, _)| x);
+            let ball_x = state.ball().map_or(0, |(x, _)| x);
+            match paddle_x.cmp(&ball_x) {
+                std::cmp::Ordering::Less => 1,
+                std::cmp::Ordering::Equal => 0,
+                std::cmp::Ordering::Greater => -1,
+            }
+        }
+    }
+
+    /// Play the game with free play until it ends or the controller stops, returning the final
+    /// screen. If `recording` is supplied, every frame is appended to it.
+    pub fn run_game<C>(
+        input: &[i32],
+        controller: &mut C,
+        recording: Option<&mut Vec<Frame>>,
+    ) -> Result<GameState, AocError>
+    where
+        C: Controller + ?Sized,
+    {
+        play(&mut Game::new(input, true), controller, recording)
+    }
+
+    fn play<C>(
+        game: &mut Game,
+        controller: &mut C,
+        mut recording: Option<&mut Vec<Frame>>,
+    ) -> Result<GameState, AocError>
+    where
+        C: Controller + ?Sized,
+    {
+        loop {
+            match game.advance()? {
+                GameStatus::GameOver => {
+                    if let Some(recording) = recording.as_mut() {
+                        recording.push(Frame {
+                            state: game.state().clone(),
+                            joystick: None,
+                        });
+                    }
+                    return Ok(game.state().clone());
+                }
+                GameStatus::AwaitingJoystick => {
+                    if controller.should_stop() {
+                        return Ok(game.state().clone());
+                    }
+                    let joystick = controller.joystick(game.state());
+                    if let Some(recording) = recording.as_mut() {
+                        recording.push(Frame {
+                            state: game.state().clone(),
+                            joystick: Some(joystick),
+                        });
+                    }
+                    game.set_joystick(joystick);
+                }
+            }
+        }
+    }
+
+    /// Passes another controller's decisions through unchanged, noting each one.
+    pub struct Recorder<C> {
+        pub inner: C,
+        pub inputs: Vec<i32>,
+    }
+
+    impl<C: Controller> Controller for Recorder<C> {
+        fn joystick(&mut self, state: &GameState) -> i32 {
+            let joystick = self.inner.joystick(state);
+            self.inputs.push(joystick);
+            joystick
+        }
+
+        fn should_stop(&self) -> bool {
+            self.inner.should_stop()
+        }
+    }
+
+    /// Feeds a fixed sequence of joystick positions to the game.
+    pub struct Replay<'a> {
+        inputs: std::slice::Iter<'a, i32>,
+        overran: bool,
+    }
+
+    impl<'a> Replay<'a> {
+        pub fn new(inputs: &'a [i32]) -> Replay<'a> {
+            Replay {
+                inputs: inputs.iter(),
+                overran: false,
+            }
+        }
+    }
+
+    impl Controller for Replay<'_> {
+        fn joystick(&mut self, _: &GameState) -> i32 {
+            match self.inputs.next() {
+                Some(&joystick) => joystick,
+                None => {
+                    self.overran = true;
+                    0
+                }
+            }
+        }
+
+        fn should_stop(&self) -> bool {
+            self.overran
+        }
+    }
+
+    /// The joystick inputs of a complete game, and the score they should achieve.
+    ///
+    /// The text format is the score on the first line, then the inputs separated by commas on
+    /// the second.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Transcript {
+        pub score: i32,
+        pub inputs: Vec<i32>,
+    }
+
+    impl std::fmt::Display for Transcript {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "{}", self.score)?;
+            for (i, input) in self.inputs.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                write!(f, "{input}")?;
+            }
+            writeln!(f)
+        }
+    }
+
+    #[derive(Error, Debug, PartialEq, Eq)]
+    pub enum TranscriptParseError {
+        #[error("transcript has no score line")]
+        MissingScore,
+        #[error("could not parse {0:?} as a number")]
+        BadNumber(String),
+    }
+
+    impl std::str::FromStr for Transcript {
+        type Err = TranscriptParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let parse = |s: &str| {
+                s.trim()
+                    .parse()
+                    .map_err(|_| TranscriptParseError::BadNumber(s.to_string()))
+            };
+            let mut lines = s.lines();
+            let score = parse(lines.next().ok_or(TranscriptParseError::MissingScore)?)?;
+            let inputs = match lines.next().map(str::trim) {
+                None | Some("") => Vec::new(),
+                Some(line) => line.split(',').map(parse).collect::<Result<_, _>>()?,
+            };
+            Ok(Transcript { score, inputs })
+        }
+    }
+
+    #[derive(Error, Debug)]
+    pub enum ReplayError {
+        #[error("the game wanted more inputs than the transcript has")]
+        TranscriptTooShort,
+        #[error("the game ended with {0} transcript inputs unused")]
+        UnusedInputs(usize),
+        #[error("transcript should score {expected}, but scored {actual}")]
+        ScoreMismatch { expected: i32, actual: i32 },
+        #[error(transparent)]
+        Game(#[from] AocError),
+    }
+
+    /// Win the game with the paddle following the ball, noting every input.
+    pub fn record_transcript(input: &[i32]) -> Result<Transcript, AocError> {
+        let mut recorder = Recorder {
+            inner: FollowBall,
+            inputs: Vec::new(),
+        };
+        let state = run_game(input, &mut recorder, None)?;
+        Ok(Transcript {
+            score: state.score(),
+            inputs: recorder.inputs,
+        })
+    }
+
+    /// Play the transcript's inputs back, checking that the game consumes exactly those inputs
+    /// and finishes with the recorded score.
+    pub fn replay(input: &[i32], transcript: &Transcript) -> Result<i32, ReplayError> {
+        let mut replay = Replay::new(&transcript.inputs);
+        let state = run_game(input, &mut replay, None)?;
+        if replay.overran {
+            return Err(ReplayError::TranscriptTooShort);
+        }
+        let unused = replay.inputs.len();
+        if unused > 0 {
+            return Err(ReplayError::UnusedInputs(unused));
+        }
+        if state.score() != transcript.score {
+            return Err(ReplayError::ScoreMismatch {
+                expected: transcript.score,
+                actual: state.score(),
+            });
+        }
+        Ok(state.score())
+    }
+
+    /// Play part 2, returning the final score and every frame of the game.
+    pub fn play_recording(input: &[i32]) -> Result<(i32, Vec<Frame>), AocError> {
+        let mut frames = Vec::new();
+        let state = run_game(input, &mut FollowBall, Some(&mut frames))?;
+        Ok((state.score(), frames))
+    }
+
+    pub fn part_2(input: &[i32]) -> Result<i32, AocError> {
+        Ok(run_game(input, &mut FollowBall, None)?.score())
+    }
+
+    /// Play part 2, writing a trace of every instruction the game executes to `out` as JSON
+    /// Lines (see `MachineState::trace_to`), and returning the final score.
+    pub fn trace_part_2<W>(input: &[i32], out: W) -> Result<i32, AocError>
+    where
+        W: Write + Send + 'static,
+    {
+        let mut game = Game::new(input, true);
+        game.machine.trace_to(out);
+        let state = play(&mut game, &mut FollowBall, None)?;
+        game.machine
+            .finish_trace()
+            .map_err(MachineExecutionError::Trace)?;
+        Ok(state.score())
+    }
+
+    #[cfg(feature = "image")]
+    #[derive(Error, Debug)]
+    pub enum ExportError {
+        #[error(transparent)]
+        Game(#[from] AocError),
+        #[error(transparent)]
+        Export(#[from] viz::ExportError),
+    }
+
+    /// The screen as `width` by `height` tiles, each drawn as a `scale`-by-`scale` block: walls
+    /// grey, blocks blue, the paddle white, the ball red, and empty space black.
+    #[cfg(feature = "image")]
+    pub fn frame_image(state: &GameState, (width, height): (i32, i32), scale: u32) -> RgbaImage {
+        viz::draw(
+            height as usize,
+            width as usize,
+            scale,
+            |row, col| match state.tile(col as i32, row as i32) {
+                Tile::Empty => BLACK,
+                Tile::Wall => GREY,
+                Tile::Block => BLUE,
+                Tile::Paddle => WHITE,
+                Tile::Ball => RED,
+            },
+        )
+    }
+
+    /// Save part 2's game, as played by following the ball, as a looping GIF.
+    #[cfg(feature = "image")]
+    pub fn to_gif<P>(
+        input: &[i32],
+        path: P,
+        scale: u32,
+        frame_delay_ms: u32,
+    ) -> Result<(), ExportError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let (_, frames) = play_recording(input)?;
+        let size = frames.iter().fold((0, 0), |(w, h), frame| {
+            let (width, height) = frame.state.size();
+            (w.max(width), h.max(height))
+        });
+        let images = frames
+            .iter()
+            .map(|frame| frame_image(&frame.state, size, scale))
+            .collect::<Vec<_>>();
+        Ok(viz::save_gif(images, frame_delay_ms, path)?)
+    }
+
+    pub struct Day13;
+
+    impl Solution for Day13 {
+        type Parsed<'a> = Vec<i32>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+
+        fn explain(
+            parsed: &Self::Parsed<'_>,
+            explainer: &mut dyn Explainer,
+        ) -> Result<(), SolveError> {
+            let state = run_game(parsed, &mut FollowBall, None)?;
+            explainer.note("final board", &state);
+            explainer.note("blocks remaining", &state.blocks_remaining());
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_13::*;
+    use solution::solution::{AocError, Note, Solution};
+
+    // Draws a paddle, a ball and a score of 7, then sets the score to the joystick position.
+    const TOY_GAME: &str = "2,0,0,100,104,1,104,2,104,3,104,3,104,1,104,4,104,-1,104,0,104,7,3,101,104,-1,104,0,4,101,99";
+
+    #[test]
+    fn test_game() {
+        let program = input(TOY_GAME).unwrap();
+        let mut game = Game::new(&program, true);
+        assert_eq!(game.advance().unwrap(), GameStatus::AwaitingJoystick);
+        assert_eq!(game.advance().unwrap(), GameStatus::AwaitingJoystick);
+        assert_eq!(game.state().score(), 7);
+        assert_eq!(game.state().paddle(), Some((1, 2)));
+        assert_eq!(game.state().ball(), Some((3, 1)));
+        assert_eq!(game.state().tile(3, 1), Tile::Ball);
+        assert_eq!(game.state().to_string(), "    \n   o\n -  \n");
+
+        game.set_joystick(-1);
+        assert_eq!(game.advance().unwrap(), GameStatus::GameOver);
+        assert_eq!(game.state().score(), -1);
+
+        // The paddle is left of the ball, so the joystick is tilted right.
+        assert_eq!(part_2(&program).unwrap(), 1);
+
+        let (score, frames) = play_recording(&program).unwrap();
+        assert_eq!(score, 1);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].state.score(), 7);
+        assert_eq!(frames[0].joystick, Some(1));
+        assert_eq!(frames[1].state.score(), 1);
+        assert_eq!(frames[1].state.ball(), Some((3, 1)));
+        assert_eq!(frames[1].joystick, None);
+
+        let path = std::env::temp_dir().join(format!("day-13-trace-{}.jsonl", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();
+        assert_eq!(trace_part_2(&program, file).unwrap(), 1);
+        let trace = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<_> = trace.lines().collect();
+        assert_eq!(lines.len(), 15);
+        assert!(lines[10].contains(
+            r#""instruction":"input","operands":[],"writes":[{"address":101,"value":1}],"input":1}"#
+        ));
+        assert!(lines[14].contains(r#""instruction":"halt""#));
+
+        struct AlwaysLeft(usize);
+        impl Controller for AlwaysLeft {
+            fn joystick(&mut self, _: &GameState) -> i32 {
+                self.0 += 1;
+                -1
+            }
+        }
+        let mut controller = AlwaysLeft(0);
+        let state = run_game(&program, &mut controller, None).unwrap();
+        assert_eq!(state.score(), -1);
+        assert_eq!(controller.0, 1);
+
+        struct Quitter;
+        impl Controller for Quitter {
+            fn joystick(&mut self, _: &GameState) -> i32 {
+                unreachable!()
+            }
+            fn should_stop(&self) -> bool {
+                true
+            }
+        }
+        assert_eq!(run_game(&program, &mut Quitter, None).unwrap().score(), 7);
+
+        let mut notes: Vec<Note> = vec![];
+        Day13::explain(&program, &mut notes).unwrap();
+        let notes: Vec<_> = notes
+            .iter()
+            .map(|n| (n.name.as_str(), n.value.as_str()))
+            .collect();
+        assert_eq!(
+            notes,
+            [
+                ("final board", "    \n   o\n -  \n"),
+                ("blocks remaining", "0")
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_frame_image() {
+        use viz_image::viz_image::{BLACK, RED, WHITE};
+
+        let (_, frames) = play_recording(&input(TOY_GAME).unwrap()).unwrap();
+        let state = &frames[0].state;
+        let image = frame_image(state, state.size(), 2);
+        assert_eq!(image.dimensions(), (8, 6));
+        // The paddle at (1, 2) and the ball at (3, 1).
+        assert_eq!(*image.get_pixel(2, 4), WHITE);
+        assert_eq!(*image.get_pixel(7, 3), RED);
+        assert_eq!(*image.get_pixel(0, 0), BLACK);
+    }
+
+    #[test]
+    fn test_part_1() {
+        // Two blocks, one of which is overdrawn by a wall, and a score.
+        let program =
+            input("104,0,104,0,104,2,104,1,104,0,104,2,104,-1,104,0,104,5,104,1,104,0,104,1,99")
+                .unwrap();
+        assert_eq!(part_1(&program).unwrap(), 1);
+
+        assert!(matches!(
+            part_1(&input(TOY_GAME).unwrap()),
+            Err(AocError::UnexpectedInput(_))
+        ));
+        // A tile which doesn't exist, and a tile missing its type.
+        assert!(matches!(
+            part_1(&input("104,0,104,0,104,7,99").unwrap()),
+            Err(AocError::UnexpectedOutput(7, _))
+        ));
+        assert!(matches!(
+            part_1(&input("104,0,104,0,99").unwrap()),
+            Err(AocError::Terminated(_))
+        ));
+    }
+
+    #[test]
+    fn test_transcript() {
+        let program = input(TOY_GAME).unwrap();
+        let transcript = record_transcript(&program).unwrap();
+        assert_eq!(
+            transcript,
+            Transcript {
+                score: 1,
+                inputs: vec![1]
+            }
+        );
+        assert_eq!(transcript.to_string(), "1\n1\n");
+        assert_eq!("1\n1\n".parse::<Transcript>().unwrap(), transcript);
+        assert_eq!(replay(&program, &transcript).unwrap(), 1);
+
+        let tampered = Transcript {
+            score: 1,
+            inputs: vec![-1],
+        };
+        assert!(matches!(
+            replay(&program, &tampered),
+            Err(ReplayError::ScoreMismatch {
+                expected: 1,
+                actual: -1
+            })
+        ));
+        let short = "1\n".parse().unwrap();
+        assert!(matches!(
+            replay(&program, &short),
+            Err(ReplayError::TranscriptTooShort)
+        ));
+        let long = "1\n1,0,0\n".parse().unwrap();
+        assert!(matches!(
+            replay(&program, &long),
+            Err(ReplayError::UnusedInputs(2))
+        ));
+
+        assert_eq!(
+            "".parse::<Transcript>(),
+            Err(TranscriptParseError::MissingScore)
+        );
+        assert_eq!(
+            "1\n1,x\n".parse::<Transcript>(),
+            Err(TranscriptParseError::BadNumber("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_day_13() {
+        let Some(input_str) = loader::loader::load(13) else {
+            return;
+        };
+        let input = input(&input_str).unwrap();
+        // The answers themselves are in answers.toml; recording and replaying must agree.
+        let expected = part_2(&input).unwrap();
+        let (score, frames) = play_recording(&input).unwrap();
+        assert_eq!(score, expected);
+        assert_eq!(frames.last().unwrap().state.blocks_remaining(), 0);
+        let transcript = record_transcript(&input).unwrap();
+        assert_eq!(transcript.score, expected);
+        let transcript = transcript.to_string().parse().unwrap();
+        assert_eq!(replay(&input, &transcript).unwrap(), expected);
+    }
+}
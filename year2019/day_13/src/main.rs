@@ -0,0 +1,60 @@
+use day_13::day_13;
+use solution::solution::{read_input, Report};
+use std::fs;
+use std::io::BufWriter;
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let input_str = read_input(&args)?;
+    let input = match day_13::input(&input_str) {
+        Ok(input) => input,
+        Err(e) => return Err(format!("Error while parsing input: {e}").into()),
+    };
+
+    // Optionally `--record <path>` to save the winning joystick inputs, `--replay <path>` to
+    // check that saved inputs still reach the same score, or `--trace <path>` to save every
+    // instruction part 2 executes as JSON Lines.
+    match (args.get(2).map(|s| s.as_str()), args.get(3)) {
+        (None, _) => {
+            println!("part 1 => {}", day_13::part_1(&input)?);
+            println!("part 2 => {}", day_13::part_2(&input)?);
+        }
+        (Some("--record"), Some(transcript_path)) => {
+            let transcript = day_13::record_transcript(&input)?;
+            if let Err(e) = fs::write(transcript_path, transcript.to_string()) {
+                return Err(
+                    format!("Error while writing transcript to {transcript_path} : {e}").into(),
+                );
+            }
+            println!("recorded score {}", transcript.score);
+        }
+        (Some("--replay"), Some(transcript_path)) => {
+            let transcript: day_13::Transcript = match fs::read_to_string(transcript_path) {
+                Ok(s) => s.parse().map_err(|e| Report::from(format!("{e}")))?,
+                Err(e) => {
+                    return Err(
+                        format!("Error while accessing path {transcript_path} : {e}").into(),
+                    )
+                }
+            };
+            println!("replayed score {}", day_13::replay(&input, &transcript)?);
+        }
+        (Some("--trace"), Some(trace_path)) => {
+            let file = match fs::File::create(trace_path) {
+                Ok(file) => file,
+                Err(e) => {
+                    return Err(format!("Error while creating trace {trace_path} : {e}").into())
+                }
+            };
+            let score = day_13::trace_part_2(&input, BufWriter::new(file))?;
+            println!("traced score {score}");
+        }
+        _ => {
+            return Err(
+                "Expected either no further args, or --record/--replay/--trace and a path".into(),
+            )
+        }
+    }
+    Ok(())
+}
@@ -1,14 +1,17 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_19::day_19::{input, part_1, part_2};
+use day_14::day_14::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input(include_str!("../input.txt"));
-    c.bench_function("day 19 part 1", |b| {
+    let Some(input_str) = loader::loader::load(14) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 14 part 1", |b| {
         b.iter(|| {
             black_box(part_1(&input).unwrap());
         })
     });
-    c.bench_function("day 19 part 2", |b| {
+    c.bench_function("day 14 part 2", |b| {
         b.iter(|| {
             black_box(part_2(&input).unwrap());
         })
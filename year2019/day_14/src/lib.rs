@@ -0,0 +1,322 @@
+pub mod day_14 {
+    use std::collections::{HashMap, HashSet};
+
+    use parse::parse::{error_at, lines_with, token, ParseError};
+    use solution::solution::{solved, Answer, AocError, Solution};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Reaction<'a> {
+        pub quantity: u64,
+        pub inputs: Vec<(u64, &'a str)>,
+    }
+
+    /// The reactions, keyed by the chemical each one produces.
+    pub type Reactions<'a> = HashMap<&'a str, Reaction<'a>>;
+
+    /// Parses `chemical`, a slice of `input` of the form `7 A`.
+    fn chemical<'a>(input: &str, chemical: &'a str) -> Result<(u64, &'a str), ParseError> {
+        let chemical = chemical.trim();
+        let (quantity, name) = chemical
+            .split_once(' ')
+            .ok_or_else(|| error_at(input, chemical, "expected a quantity and a chemical"))?;
+        match token(input, quantity)? {
+            0 => Err(error_at(input, quantity, "expected a positive quantity")),
+            quantity => Ok((quantity, name)),
+        }
+    }
+
+    /// Checks that nothing reachable from `chemical` is needed to make itself, reporting the
+    /// ingredient which closes the loop.
+    fn check_acyclic<'a>(
+        input: &str,
+        reactions: &Reactions<'a>,
+        chemical: &'a str,
+        path: &mut HashSet<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Result<(), ParseError> {
+        if done.contains(chemical) {
+            return Ok(());
+        }
+        path.insert(chemical);
+        if let Some(reaction) = reactions.get(chemical) {
+            for &(_, ingredient) in reaction.inputs.iter() {
+                if path.contains(ingredient) {
+                    return Err(error_at(
+                        input,
+                        ingredient,
+                        format!("{ingredient} is needed to make itself"),
+                    ));
+                }
+                check_acyclic(input, reactions, ingredient, path, done)?;
+            }
+        }
+        path.remove(chemical);
+        done.insert(chemical);
+        Ok(())
+    }
+
+    /// Parses lines of the form `7 A, 1 B => 1 C`.
+    /// Quantities must be positive, each chemical may be produced by at most one reaction, every
+    /// ingredient but ORE must be produced by one, and no chemical may be needed to make itself.
+    pub fn input(s: &str) -> Result<Reactions<'_>, ParseError> {
+        let reactions = lines_with(s, |l| {
+            let (inputs, output) = l
+                .split_once("=>")
+                .ok_or_else(|| error_at(s, l, "expected a reaction containing '=>'"))?;
+            let inputs = inputs
+                .split(',')
+                .map(|c| chemical(s, c))
+                .collect::<Result<_, _>>()?;
+            let (quantity, output) = chemical(s, output)?;
+            Ok((output, Reaction { quantity, inputs }))
+        })?;
+
+        let mut result = Reactions::new();
+        for (output, reaction) in reactions.iter() {
+            if result.insert(*output, reaction.clone()).is_some() {
+                return Err(error_at(
+                    s,
+                    output,
+                    format!("a second reaction producing {output}"),
+                ));
+            }
+        }
+        for (_, reaction) in reactions.iter() {
+            for &(_, ingredient) in reaction.inputs.iter() {
+                if ingredient != "ORE" && !result.contains_key(ingredient) {
+                    return Err(error_at(
+                        s,
+                        ingredient,
+                        format!("no reaction produces {ingredient}"),
+                    ));
+                }
+            }
+        }
+        let (mut path, mut done) = (HashSet::new(), HashSet::new());
+        for (output, _) in reactions.iter() {
+            check_acyclic(s, &result, output, &mut path, &mut done)?;
+        }
+        Ok(result)
+    }
+
+    fn visit<'a>(
+        reactions: &Reactions<'a>,
+        chemical: &'a str,
+        seen: &mut HashSet<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) {
+        if !seen.insert(chemical) {
+            return;
+        }
+        if let Some(reaction) = reactions.get(chemical) {
+            for (_, input) in reaction.inputs.iter() {
+                visit(reactions, input, seen, order);
+            }
+        }
+        order.push(chemical);
+    }
+
+    /// Every chemical from which FUEL is made, each appearing before all of its ingredients.
+    pub fn topological_order<'a>(reactions: &Reactions<'a>) -> Vec<&'a str> {
+        let mut order = vec![];
+        visit(reactions, "FUEL", &mut HashSet::new(), &mut order);
+        order.reverse();
+        order
+    }
+
+    pub struct Production<'a> {
+        pub ore: u64,
+        /// What is left over of each chemical after producing the FUEL, since reactions only run
+        /// in whole batches.
+        pub surplus: HashMap<&'a str, u64>,
+    }
+
+    /// Works out how to produce the given amount of FUEL, or fails if some chemical is needed in
+    /// an amount too large to count.
+    /// Each chemical is handled only once all its consumers have been, so by then we know the
+    /// total demand for it and can run its reaction in exactly as many batches as are needed.
+    pub fn produce<'a>(reactions: &Reactions<'a>, fuel: u64) -> Result<Production<'a>, AocError> {
+        let too_much = || AocError::NoAnswer("too much of some chemical is needed to count");
+        let mut demand: HashMap<&str, u64> = HashMap::new();
+        demand.insert("FUEL", fuel);
+        let mut surplus = HashMap::new();
+        for chemical in topological_order(reactions) {
+            let required = *demand.get(chemical).unwrap_or(&0);
+            let reaction = match reactions.get(chemical) {
+                // ORE, which is the only thing with no reaction.
+                None => continue,
+                Some(reaction) => reaction,
+            };
+            let batches = required.div_ceil(reaction.quantity);
+            let left_over = batches
+                .checked_mul(reaction.quantity)
+                .ok_or_else(too_much)?
+                - required;
+            if left_over > 0 {
+                surplus.insert(chemical, left_over);
+            }
+            for &(quantity, input) in reaction.inputs.iter() {
+                let needed = batches.checked_mul(quantity).ok_or_else(too_much)?;
+                let demand = demand.entry(input).or_insert(0);
+                *demand = demand.checked_add(needed).ok_or_else(too_much)?;
+            }
+        }
+
+        Ok(Production {
+            ore: *demand.get("ORE").unwrap_or(&0),
+            surplus,
+        })
+    }
+
+    pub fn part_1(reactions: &Reactions) -> Result<u64, AocError> {
+        Ok(produce(reactions, 1)?.ore)
+    }
+
+    /// The most FUEL which can be produced from the given amount of ORE.
+    /// There's no answer if FUEL costs no ORE at all, or if the amount is too large to count.
+    pub fn max_fuel(reactions: &Reactions, ore: u64) -> Result<u64, AocError> {
+        if produce(reactions, 1).is_ok_and(|p| p.ore == 0) {
+            return Err(AocError::NoAnswer("FUEL can be made without any ORE"));
+        }
+        // Needing too much of something to count is certainly needing more ORE than we have.
+        let affordable = |fuel| produce(reactions, fuel).is_ok_and(|p| p.ore <= ore);
+        // Invariant: lower is affordable, upper is not.
+        let mut lower = 0;
+        let mut upper = 1;
+        while affordable(upper) {
+            lower = upper;
+            upper = upper
+                .checked_mul(2)
+                .ok_or(AocError::NoAnswer("too much FUEL can be made to count"))?;
+        }
+        while lower + 1 < upper {
+            let midpoint = lower + (upper - lower) / 2;
+            if affordable(midpoint) {
+                lower = midpoint;
+            } else {
+                upper = midpoint;
+            }
+        }
+        Ok(lower)
+    }
+
+    pub fn part_2(reactions: &Reactions) -> Result<u64, AocError> {
+        max_fuel(reactions, 1_000_000_000_000)
+    }
+
+    pub struct Day14;
+
+    impl Solution for Day14 {
+        type Parsed<'a> = Reactions<'a>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_14::*;
+    use solution::solution::AocError;
+
+    const EXAMPLE_1: &str = include_str!("../examples/example-1.txt");
+
+    const EXAMPLE_2: &str = include_str!("../examples/example-2.txt");
+
+    #[test]
+    fn test_parse() {
+        let reactions = input(EXAMPLE_2).unwrap();
+        assert_eq!(reactions.len(), 7);
+        assert_eq!(
+            reactions["FUEL"],
+            Reaction {
+                quantity: 1,
+                inputs: vec![(2, "AB"), (3, "BC"), (4, "CA")]
+            }
+        );
+
+        let order = topological_order(&reactions);
+        assert_eq!(order.len(), 8);
+        assert_eq!(order[0], "FUEL");
+        assert_eq!(order[7], "ORE");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            input("10 ORE => 10 A\n7 ORE, x A => 1 B")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 8: could not parse \"x\": invalid digit found in string"
+        );
+        assert_eq!(
+            input("10 ORE -> 10 A").unwrap_err().to_string(),
+            "line 1, column 1: expected a reaction containing '=>'"
+        );
+        assert_eq!(
+            input("0 ORE => 0 FUEL").unwrap_err().to_string(),
+            "line 1, column 1: expected a positive quantity"
+        );
+        assert_eq!(
+            input("1 ORE => 1 A\n2 ORE => 1 A").unwrap_err().to_string(),
+            "line 2, column 12: a second reaction producing A"
+        );
+        assert_eq!(
+            input("1 A => 1 FUEL\n1 FUEL => 1 A")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: FUEL is needed to make itself"
+        );
+        assert_eq!(
+            input("1 ORE => 1 A\n1 B => 1 FUEL\n1 A, 2 FUEL => 1 B")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 8: FUEL is needed to make itself"
+        );
+        assert_eq!(
+            input("1 ORE, 5 Q => 1 FUEL").unwrap_err().to_string(),
+            "line 1, column 10: no reaction produces Q"
+        );
+    }
+
+    #[test]
+    fn part1_known() {
+        let reactions = input(EXAMPLE_1).unwrap();
+        let production = produce(&reactions, 1).unwrap();
+        assert_eq!(production.ore, 31);
+        assert_eq!(production.surplus.get("A"), Some(&2));
+        assert_eq!(production.surplus.len(), 1);
+    }
+
+    #[test]
+    fn part2_known() {
+        assert_eq!(max_fuel(&input(EXAMPLE_1).unwrap(), 30).unwrap(), 0);
+        assert_eq!(max_fuel(&input(EXAMPLE_1).unwrap(), 31).unwrap(), 1);
+
+        // FUEL which nothing makes is unlimited.
+        let reactions = input("1 ORE => 1 A").unwrap();
+        assert_eq!(part_1(&reactions).unwrap(), 0);
+        assert!(matches!(part_2(&reactions), Err(AocError::NoAnswer(_))));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Each step multiplies the demand by 2^32, so one FUEL needs 2^64 ORE.
+        let reactions = input("4294967296 ORE => 1 A\n4294967296 A => 1 FUEL").unwrap();
+        assert!(matches!(part_1(&reactions), Err(AocError::NoAnswer(_))));
+        assert_eq!(max_fuel(&reactions, u64::MAX).unwrap(), 0);
+
+        // Doubling the FUEL until it's unaffordable overflows the demand for ORE on the way.
+        let reactions = input("3 ORE => 1 FUEL").unwrap();
+        assert_eq!(max_fuel(&reactions, u64::MAX).unwrap(), u64::MAX / 3);
+    }
+}
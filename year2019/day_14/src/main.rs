@@ -0,0 +1,8 @@
+use day_14::day_14::Day14;
+use solution::solution::{run_main, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    run_main::<Day14>(&args)
+}
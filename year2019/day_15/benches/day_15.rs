@@ -1,14 +1,17 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_11::day_11::{input, part_1, part_2};
+use day_15::day_15::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input(include_str!("../input.txt"));
-    c.bench_function("day 11 part 1", |b| {
+    let Some(input_str) = loader::loader::load(15) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 15 part 1", |b| {
         b.iter(|| {
             black_box(part_1(&input).unwrap());
         })
     });
-    c.bench_function("day 11 part 2", |b| {
+    c.bench_function("day 15 part 2", |b| {
         b.iter(|| {
             black_box(part_2(&input).unwrap());
         })
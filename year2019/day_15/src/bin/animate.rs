@@ -0,0 +1,71 @@
+//! Watch the droid map the area and the oxygen spread through it; q quits.
+//!
+//! Usage: day_15_animate <input file> [frames per second] [cells revealed per frame]
+
+use day_15::day_15;
+use solution::solution::{read_input, Report};
+use std::time::Duration;
+use viz_term::viz_term::{self as viz, Frame, Outcome, Terminal};
+
+fn animate(map: &day_15::Map, frame: Duration, cells_per_frame: usize) -> Result<(), Report> {
+    let pictures = day_15::animation(map, cells_per_frame)?;
+    let exploring = map.discovered.len().div_ceil(cells_per_frame);
+    let bounds @ (min_x, max_x, min_y, max_y) = map.bounds();
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+
+    let footers: Vec<String> = pictures
+        .iter()
+        .enumerate()
+        .map(|(i, picture)| {
+            if i < exploring {
+                format!("Exploring: {} cells found", picture.cells.len())
+            } else {
+                format!("Oxygen spreading: minute {}", i - exploring)
+            }
+        })
+        .collect();
+    let frame_width = footers.iter().map(String::len).fold(width, usize::max);
+
+    let mut terminal = Terminal::new()?;
+    let mut frames = pictures.iter().zip(footers.iter());
+    let outcome = terminal.run(frame, |_| {
+        let (picture, footer) = frames.next()?;
+        let mut frame = Frame::new(frame_width, height + 2);
+        frame.sprite(0, 0, &picture.render(bounds));
+        frame.text(0, height + 1, footer);
+        Some(frame)
+    })?;
+    if outcome == Outcome::Finished {
+        viz::wait_for_key()?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Report> {
+    let args: Vec<String> = std::env::args().collect();
+    let input_str = read_input(&args)?;
+    let fps: u32 = match args.get(2) {
+        None => 30,
+        Some(fps) => fps
+            .parse()
+            .map_err(|e| format!("Could not parse frame rate {fps} : {e}"))?,
+    };
+    if fps == 0 {
+        return Err("Frame rate must be positive".into());
+    }
+    let cells_per_frame: usize = match args.get(3) {
+        None => 10,
+        Some(cells) => cells
+            .parse()
+            .map_err(|e| format!("Could not parse cells per frame {cells} : {e}"))?,
+    };
+    if cells_per_frame == 0 {
+        return Err("Cells per frame must be positive".into());
+    }
+    let input = day_15::input(&input_str)
+        .map_err(|e| Report::from(format!("Error while parsing input: {e}")))?;
+
+    let map = day_15::explore(day_15::IntcodeDroid::new(&input))?;
+    animate(&map, Duration::from_secs(1) / fps, cells_per_frame)
+}
@@ -0,0 +1,462 @@
+pub mod day_15 {
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    use graph::graph::bfs_distances;
+
+    use intcode::intcode::{MachineExecutionError, MachineState, StepIoResult};
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, Solution};
+    use thiserror::Error;
+    #[cfg(feature = "image")]
+    use viz_image::viz_image::{self as viz, RgbaImage, BLACK, BLUE, GREY, RED, WHITE};
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    /// Positions are (x, y), with y increasing southwards.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Direction {
+        North = 1,
+        South = 2,
+        West = 3,
+        East = 4,
+    }
+
+    impl Direction {
+        pub const ALL: [Direction; 4] = [
+            Direction::North,
+            Direction::South,
+            Direction::West,
+            Direction::East,
+        ];
+
+        pub fn step(self, (x, y): (i32, i32)) -> (i32, i32) {
+            match self {
+                Direction::North => (x, y - 1),
+                Direction::South => (x, y + 1),
+                Direction::West => (x - 1, y),
+                Direction::East => (x + 1, y),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Cell {
+        Wall,
+        Open,
+        Oxygen,
+    }
+
+    #[derive(Error, Debug)]
+    pub enum DroidError {
+        #[error("droid reported unknown status {0}")]
+        InvalidStatus(i64),
+        #[error("program terminated while the droid was exploring")]
+        Terminated,
+        #[error("program output {0} before being told where to move")]
+        UnexpectedOutput(i64),
+        #[error("program asked for input before reporting the result of a move")]
+        UnexpectedInput,
+        #[error("the droid explored the whole area without finding the oxygen system")]
+        NoOxygen,
+        #[error(transparent)]
+        Machine(#[from] MachineExecutionError),
+    }
+
+    /// Something which can be told to move, and which reports what it found there.
+    /// Cloning a droid must give an independent droid in the same place, so that exploration
+    /// can fork at each junction rather than walking back.
+    pub trait Droid: Clone {
+        /// Moves one step in the given direction, unless a wall is in the way.
+        /// Returns what is in that direction.
+        fn try_move(&mut self, direction: Direction) -> Result<Cell, DroidError>;
+    }
+
+    /// The repair droid, driven by the puzzle's program.
+    #[derive(Clone)]
+    pub struct IntcodeDroid {
+        machine: MachineState<i64>,
+    }
+
+    impl IntcodeDroid {
+        pub fn new(program: &[i64]) -> IntcodeDroid {
+            IntcodeDroid {
+                machine: MachineState::new_with_memory(&program.iter().copied()),
+            }
+        }
+    }
+
+    impl Droid for IntcodeDroid {
+        fn try_move(&mut self, direction: Direction) -> Result<Cell, DroidError> {
+            match self.machine.execute_until_input()? {
+                StepIoResult::Terminated => return Err(DroidError::Terminated),
+                StepIoResult::Output(v) => return Err(DroidError::UnexpectedOutput(v)),
+                StepIoResult::AwaitingInput(_) => {
+                    self.machine.push_input(direction as i64);
+                }
+            }
+            match self.machine.execute_until_input()? {
+                StepIoResult::Terminated => Err(DroidError::Terminated),
+                StepIoResult::AwaitingInput(_) => Err(DroidError::UnexpectedInput),
+                StepIoResult::Output(0) => Ok(Cell::Wall),
+                StepIoResult::Output(1) => Ok(Cell::Open),
+                StepIoResult::Output(2) => Ok(Cell::Oxygen),
+                StepIoResult::Output(v) => Err(DroidError::InvalidStatus(v)),
+            }
+        }
+    }
+
+    /// Everything the droid has found, relative to its starting point at the origin.
+    pub struct Map {
+        pub cells: HashMap<(i32, i32), Cell>,
+        pub oxygen: Option<(i32, i32)>,
+        /// Every cell in `cells`, in the order the droid found them.
+        pub discovered: Vec<(i32, i32)>,
+    }
+
+    impl Map {
+        /// The length of the shortest path from `from` to every reachable cell.
+        pub fn distances(&self, from: (i32, i32)) -> HashMap<(i32, i32), usize> {
+            bfs_distances(from, |&pos| {
+                Direction::ALL
+                    .into_iter()
+                    .map(move |direction| direction.step(pos))
+                    .filter(|next| matches!(self.cells.get(next), Some(Cell::Open | Cell::Oxygen)))
+            })
+        }
+    }
+
+    /// Maps the whole area breadth-first, forking a copy of the droid at each newly found cell
+    /// instead of walking it back and forth.
+    pub fn explore<D>(droid: D) -> Result<Map, DroidError>
+    where
+        D: Droid,
+    {
+        let mut cells = HashMap::new();
+        cells.insert((0, 0), Cell::Open);
+        let mut oxygen = None;
+        let mut discovered = vec![(0, 0)];
+        let mut queue = VecDeque::from([((0, 0), droid)]);
+        while let Some((pos, droid)) = queue.pop_front() {
+            for direction in Direction::ALL {
+                let next = direction.step(pos);
+                if cells.contains_key(&next) {
+                    continue;
+                }
+                let mut forked = droid.clone();
+                let cell = forked.try_move(direction)?;
+                cells.insert(next, cell);
+                discovered.push(next);
+                match cell {
+                    Cell::Wall => {}
+                    Cell::Open => queue.push_back((next, forked)),
+                    Cell::Oxygen => {
+                        oxygen = Some(next);
+                        queue.push_back((next, forked));
+                    }
+                }
+            }
+        }
+        Ok(Map {
+            cells,
+            oxygen,
+            discovered,
+        })
+    }
+
+    /// The fewest moves from the droid's start to the oxygen system.
+    pub fn shortest_path(map: &Map) -> Result<usize, DroidError> {
+        let oxygen = map.oxygen.ok_or(DroidError::NoOxygen)?;
+        Ok(map.distances((0, 0))[&oxygen])
+    }
+
+    /// The minutes taken for oxygen to spread from the oxygen system to the whole area.
+    pub fn fill_time(map: &Map) -> Result<usize, DroidError> {
+        let oxygen = map.oxygen.ok_or(DroidError::NoOxygen)?;
+        Ok(*map.distances(oxygen).values().max().unwrap())
+    }
+
+    pub fn part_1(input: &[i64]) -> Result<usize, DroidError> {
+        shortest_path(&explore(IntcodeDroid::new(input))?)
+    }
+
+    pub fn part_2(input: &[i64]) -> Result<usize, DroidError> {
+        fill_time(&explore(IntcodeDroid::new(input))?)
+    }
+
+    /// The cells reached by oxygen in each minute, starting with the oxygen system itself.
+    pub fn oxygen_spread(map: &Map) -> Result<Vec<Vec<(i32, i32)>>, DroidError> {
+        let oxygen = map.oxygen.ok_or(DroidError::NoOxygen)?;
+        let mut minutes: Vec<Vec<(i32, i32)>> = vec![];
+        for (pos, distance) in map.distances(oxygen) {
+            if minutes.len() <= distance {
+                minutes.resize(distance + 1, vec![]);
+            }
+            minutes[distance].push(pos);
+        }
+        for minute in minutes.iter_mut() {
+            minute.sort_unstable();
+        }
+        Ok(minutes)
+    }
+
+    /// A moment in the animation: what has been found so far, and where the oxygen has reached.
+    pub struct AnimationFrame {
+        pub cells: HashMap<(i32, i32), Cell>,
+        pub oxygenated: HashSet<(i32, i32)>,
+    }
+
+    impl AnimationFrame {
+        /// Draws the frame over the inclusive (min_x, max_x, min_y, max_y) bounds: '#' for walls,
+        /// '.' for open space, 'O' for oxygen, 'S' for the droid's start and ' ' for the unknown.
+        pub fn render(&self, (min_x, max_x, min_y, max_y): (i32, i32, i32, i32)) -> String {
+            let mut result = String::new();
+            for y in min_y..=max_y {
+                for x in min_x..=max_x {
+                    result.push(match self.cells.get(&(x, y)) {
+                        _ if self.oxygenated.contains(&(x, y)) => 'O',
+                        Some(Cell::Oxygen) => 'O',
+                        _ if (x, y) == (0, 0) => 'S',
+                        Some(Cell::Open) => '.',
+                        Some(Cell::Wall) => '#',
+                        None => ' ',
+                    });
+                }
+                result.push('\n');
+            }
+            result
+        }
+    }
+
+    impl Map {
+        /// Inclusive (min_x, max_x, min_y, max_y) of everything the droid found.
+        pub fn bounds(&self) -> (i32, i32, i32, i32) {
+            self.cells.keys().fold(
+                (i32::MAX, i32::MIN, i32::MAX, i32::MIN),
+                |(min_x, max_x, min_y, max_y), &(x, y)| {
+                    (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+                },
+            )
+        }
+    }
+
+    /// The exploration, revealing `cells_per_frame` more cells in each frame in the order the
+    /// droid found them, followed by one frame per minute of the oxygen spreading.
+    pub fn animation(map: &Map, cells_per_frame: usize) -> Result<Vec<AnimationFrame>, DroidError> {
+        assert!(cells_per_frame > 0, "cells_per_frame must be positive");
+        let spread = oxygen_spread(map)?;
+
+        let mut frames = vec![];
+        let mut cells = HashMap::new();
+        for chunk in map.discovered.chunks(cells_per_frame) {
+            for pos in chunk {
+                cells.insert(*pos, map.cells[pos]);
+            }
+            frames.push(AnimationFrame {
+                cells: cells.clone(),
+                oxygenated: HashSet::new(),
+            });
+        }
+
+        let mut oxygenated = HashSet::new();
+        for minute in spread {
+            oxygenated.extend(minute);
+            frames.push(AnimationFrame {
+                cells: cells.clone(),
+                oxygenated: oxygenated.clone(),
+            });
+        }
+        Ok(frames)
+    }
+
+    #[cfg(feature = "image")]
+    #[derive(Error, Debug)]
+    pub enum ExportError {
+        #[error(transparent)]
+        Droid(#[from] DroidError),
+        #[error(transparent)]
+        Export(#[from] viz::ExportError),
+    }
+
+    /// Each cell becomes a `scale`-by-`scale` block: walls grey, open space white, oxygen blue,
+    /// the droid's start red, and the unknown black.
+    #[cfg(feature = "image")]
+    pub fn frame_image(
+        frame: &AnimationFrame,
+        (min_x, max_x, min_y, max_y): (i32, i32, i32, i32),
+        scale: u32,
+    ) -> RgbaImage {
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        viz::draw(height, width, scale, |row, col| {
+            let pos = (min_x + col as i32, min_y + row as i32);
+            match frame.cells.get(&pos) {
+                _ if frame.oxygenated.contains(&pos) => BLUE,
+                Some(Cell::Oxygen) => BLUE,
+                _ if pos == (0, 0) => RED,
+                Some(Cell::Open) => WHITE,
+                Some(Cell::Wall) => GREY,
+                None => BLACK,
+            }
+        })
+    }
+
+    /// Save the exploration and oxygen spread as a looping GIF; see `animation`.
+    #[cfg(feature = "image")]
+    pub fn to_gif<P>(
+        input: &[i64],
+        path: P,
+        scale: u32,
+        cells_per_frame: usize,
+        frame_delay_ms: u32,
+    ) -> Result<(), ExportError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let map = explore(IntcodeDroid::new(input))?;
+        let bounds = map.bounds();
+        let frames = animation(&map, cells_per_frame)?
+            .iter()
+            .map(|frame| frame_image(frame, bounds, scale))
+            .collect::<Vec<_>>();
+        Ok(viz::save_gif(frames, frame_delay_ms, path)?)
+    }
+
+    pub struct Day15;
+
+    impl Solution for Day15 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_15::*;
+
+    /// A droid in a maze drawn with '#' for walls, '.' for open space, 'O' for the oxygen
+    /// system and 'D' for where the droid starts. Anything else is a wall.
+    #[derive(Clone)]
+    struct MazeDroid {
+        maze: Vec<Vec<char>>,
+        position: (i32, i32),
+    }
+
+    impl MazeDroid {
+        fn new(maze: &str) -> MazeDroid {
+            let maze: Vec<Vec<char>> = maze.lines().map(|l| l.chars().collect()).collect();
+            let position = maze
+                .iter()
+                .enumerate()
+                .find_map(|(y, row)| {
+                    row.iter()
+                        .position(|&c| c == 'D')
+                        .map(|x| (x as i32, y as i32))
+                })
+                .unwrap();
+            MazeDroid { maze, position }
+        }
+    }
+
+    impl Droid for MazeDroid {
+        fn try_move(&mut self, direction: Direction) -> Result<Cell, DroidError> {
+            let (x, y) = direction.step(self.position);
+            let c = self
+                .maze
+                .get(y as usize)
+                .and_then(|row| row.get(x as usize))
+                .copied()
+                .unwrap_or('#');
+            let cell = match c {
+                '.' | 'D' => Cell::Open,
+                'O' => Cell::Oxygen,
+                _ => return Ok(Cell::Wall),
+            };
+            self.position = (x, y);
+            Ok(cell)
+        }
+    }
+
+    const MAZE: &str = " ##
+#D.##
+#.#..#
+#.O.#
+ ###";
+
+    #[test]
+    fn test_explore() {
+        let map = explore(MazeDroid::new(MAZE)).unwrap();
+        assert_eq!(map.oxygen, Some((1, 2)));
+        assert_eq!(
+            map.cells
+                .values()
+                .filter(|&&cell| cell != Cell::Wall)
+                .count(),
+            8
+        );
+        assert_eq!(shortest_path(&map).unwrap(), 3);
+        assert_eq!(fill_time(&map).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_animation() {
+        let map = explore(MazeDroid::new(MAZE)).unwrap();
+        assert_eq!(map.discovered.len(), map.cells.len());
+        assert_eq!(map.discovered[0], (0, 0));
+        assert_eq!(map.bounds(), (-1, 4, -1, 3));
+
+        let spread = oxygen_spread(&map).unwrap();
+        assert_eq!(spread.len() - 1, fill_time(&map).unwrap());
+        assert_eq!(spread[0], vec![(1, 2)]);
+
+        let frames = animation(&map, 5).unwrap();
+        assert_eq!(frames.len(), map.cells.len().div_ceil(5) + spread.len());
+        assert_eq!(frames[0].cells.len(), 5);
+        let last = frames.last().unwrap();
+        let expected = concat!(" ##   \n", "#OO## \n", "#O#OO#\n", "#OOO# \n", " ###  \n",);
+        assert_eq!(last.render(map.bounds()), expected);
+        let first_spread = &frames[frames.len() - spread.len()];
+        let expected = concat!(" ##   \n", "#S.## \n", "#.#..#\n", "#.O.# \n", " ###  \n",);
+        assert_eq!(first_spread.render(map.bounds()), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_frame_image() {
+        use viz_image::viz_image::{BLACK, GREY, RED};
+
+        let map = explore(MazeDroid::new(MAZE)).unwrap();
+        let frames = animation(&map, 100).unwrap();
+        let image = frame_image(&frames[0], map.bounds(), 2);
+        assert_eq!(image.dimensions(), (12, 10));
+        // The droid's start, then the wall to its west.
+        assert_eq!(*image.get_pixel(2, 2), RED);
+        assert_eq!(*image.get_pixel(1, 3), GREY);
+        assert_eq!(*image.get_pixel(0, 0), BLACK);
+    }
+
+    #[test]
+    fn test_intcode_droid() {
+        // Reads a direction and reports a wall, forever.
+        let walled_in = input("3,100,104,0,1105,1,0").unwrap();
+        let map = explore(IntcodeDroid::new(&walled_in)).unwrap();
+        assert_eq!(map.cells.len(), 5);
+        assert!(matches!(part_1(&walled_in), Err(DroidError::NoOxygen)));
+
+        // Reads a direction and then stops.
+        let broken = input("3,100,99").unwrap();
+        assert!(matches!(part_2(&broken), Err(DroidError::Terminated)));
+    }
+}
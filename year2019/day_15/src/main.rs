@@ -0,0 +1,8 @@
+use day_15::day_15::Day15;
+use solution::solution::{run_main, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    run_main::<Day15>(&args)
+}
@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_16::day_16::{fft, fft_naive, input, part_1, part_2};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input_str) = loader::loader::load(16) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 16 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&input).unwrap());
+        })
+    });
+    c.bench_function("day 16 one phase", |b| {
+        b.iter(|| {
+            black_box(fft(&input, 1));
+        })
+    });
+    c.bench_function("day 16 one phase naive", |b| {
+        b.iter(|| {
+            black_box(fft_naive(&input, 1));
+        })
+    });
+    c.bench_function("day 16 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&input).unwrap());
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
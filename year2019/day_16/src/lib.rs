@@ -0,0 +1,263 @@
+pub mod day_16 {
+    use parse::parse::{digits, ParseError};
+    use solution::solution::{solved, Answer, AocError, Solution};
+
+    pub fn input(s: &str) -> Result<Vec<u8>, ParseError> {
+        digits(s)
+    }
+
+    const BASE_PATTERN: [i32; 4] = [0, 1, 0, -1];
+
+    fn phase_naive(signal: &[u8]) -> Vec<u8> {
+        (0..signal.len())
+            .map(|out_index| {
+                let total: i32 = signal
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &digit)| digit as i32 * BASE_PATTERN[((i + 1) / (out_index + 1)) % 4])
+                    .sum();
+                (total.abs() % 10) as u8
+            })
+            .collect()
+    }
+
+    /// Sum of `signal[out_index..]` weighted by output digit `out_index`'s pattern, given the
+    /// prefix sums of the signal. Output digit `k` repeats each pattern element `k + 1` times, so
+    /// this is a signed sum over about `n / (k + 1)` blocks of the input.
+    fn pattern_sum(prefix_sums: &[i32], out_index: usize) -> i32 {
+        let len = prefix_sums.len() - 1;
+        // Sum of signal[start..end], clamped to the signal.
+        let block =
+            |start: usize, end: usize| prefix_sums[end.min(len)] - prefix_sums[start.min(len)];
+        let width = out_index + 1;
+        // The pattern is 1 on [width * (4m + 1) - 1, width * (4m + 2) - 1) and -1 on
+        // [width * (4m + 3) - 1, width * (4m + 4) - 1).
+        let mut total = 0;
+        let mut start = out_index;
+        while start < len {
+            total += block(start, start + width);
+            total -= block(start + 2 * width, start + 3 * width);
+            start += 4 * width;
+        }
+        total
+    }
+
+    fn fill_prefix_sums(signal: &[u8], prefix_sums: &mut Vec<i32>) {
+        prefix_sums.clear();
+        prefix_sums.push(0);
+        let mut acc = 0;
+        for &digit in signal {
+            acc += digit as i32;
+            prefix_sums.push(acc);
+        }
+    }
+
+    /// One phase, overwriting the signal; the whole phase is O(n log n).
+    /// In the second half of the signal the pattern is 1 from each digit to the end, so those
+    /// digits are a suffix sum which can be taken in place from the back. Only the first half
+    /// needs the old digits after they would be overwritten, so only it goes via `scratch`.
+    fn phase(signal: &mut [u8], prefix_sums: &mut Vec<i32>, scratch: &mut Vec<u8>) {
+        fill_prefix_sums(signal, prefix_sums);
+        let half = signal.len().div_ceil(2);
+        scratch.clear();
+        scratch.extend(
+            (0..half).map(|out_index| (pattern_sum(prefix_sums, out_index).abs() % 10) as u8),
+        );
+
+        let mut acc = 0;
+        for digit in signal[half..].iter_mut().rev() {
+            acc = (acc + *digit) % 10;
+            *digit = acc;
+        }
+        signal[..half].copy_from_slice(scratch);
+    }
+
+    /// As `phase`, but writing every digit to a new buffer.
+    #[cfg(test)]
+    pub(crate) fn phase_double_buffered(signal: &[u8]) -> Vec<u8> {
+        let mut prefix_sums = Vec::with_capacity(signal.len() + 1);
+        fill_prefix_sums(signal, &mut prefix_sums);
+        (0..signal.len())
+            .map(|out_index| (pattern_sum(&prefix_sums, out_index).abs() % 10) as u8)
+            .collect()
+    }
+
+    /// Run the given number of FFT phases over the signal.
+    pub fn fft(signal: &[u8], phases: usize) -> Vec<u8> {
+        let mut signal = signal.to_vec();
+        let mut prefix_sums = Vec::with_capacity(signal.len() + 1);
+        let mut scratch = Vec::with_capacity(signal.len().div_ceil(2));
+        for _ in 0..phases {
+            phase(&mut signal, &mut prefix_sums, &mut scratch);
+        }
+        signal
+    }
+
+    /// As `fft`, but computing every output digit directly from the whole input, which is
+    /// O(n^2) per phase. Kept as a reference for testing and benchmarking.
+    pub fn fft_naive(signal: &[u8], phases: usize) -> Vec<u8> {
+        let mut signal = signal.to_vec();
+        for _ in 0..phases {
+            signal = phase_naive(&signal);
+        }
+        signal
+    }
+
+    /// The `len` digits starting at `offset`, as a string, or an error if the signal is not
+    /// long enough.
+    pub fn message(signal: &[u8], offset: usize, len: usize) -> Result<String, AocError> {
+        let digits = signal.get(offset..offset + len).ok_or_else(|| {
+            AocError::InvalidInput(format!(
+                "a signal of {} digits has no {len}-digit message at offset {offset}",
+                signal.len()
+            ))
+        })?;
+        Ok(digits.iter().map(|&d| char::from(b'0' + d)).collect())
+    }
+
+    /// The message offset given by the first seven digits of the signal.
+    pub fn message_offset(signal: &[u8]) -> Result<usize, AocError> {
+        let digits = signal.get(0..7).ok_or_else(|| {
+            AocError::InvalidInput(format!(
+                "a signal of {} digits is too short to give a message offset",
+                signal.len()
+            ))
+        })?;
+        Ok(digits.iter().fold(0, |acc, &d| acc * 10 + d as usize))
+    }
+
+    /// Run the given number of FFT phases over the signal repeated `repeats` times, returning
+    /// only the output digits from `offset` onwards.
+    /// The trick used here relies on the pattern being all 1s from each digit onwards, so there
+    /// is no answer if `offset` is in the first half of the repeated signal.
+    pub fn fft_tail(
+        signal: &[u8],
+        repeats: usize,
+        offset: usize,
+        phases: usize,
+    ) -> Result<Vec<u8>, AocError> {
+        let len = signal.len() * repeats;
+        if offset > len {
+            return Err(AocError::InvalidInput(format!(
+                "offset {offset} is past the end of a signal of length {len}"
+            )));
+        }
+        if offset < len / 2 {
+            return Err(AocError::NoAnswer(
+                "the message offset is in the first half of the signal",
+            ));
+        }
+
+        // In the second half of the signal, each output digit is the sum of the input digits
+        // from that position to the end, so a phase is just a suffix sum.
+        let mut tail: Vec<u8> = (offset..len).map(|i| signal[i % signal.len()]).collect();
+        for _ in 0..phases {
+            let mut acc = 0;
+            for digit in tail.iter_mut().rev() {
+                acc = (acc + *digit) % 10;
+                *digit = acc;
+            }
+        }
+        Ok(tail)
+    }
+
+    pub fn part_1(input: &[u8]) -> Result<String, AocError> {
+        message(&fft(input, 100), 0, 8)
+    }
+
+    pub fn part_2(input: &[u8]) -> Result<String, AocError> {
+        let offset = message_offset(input)?;
+        message(&fft_tail(input, 10_000, offset, 100)?, 0, 8)
+    }
+
+    pub struct Day16;
+
+    impl Solution for Day16 {
+        type Parsed<'a> = Vec<u8>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_16::*;
+    use proptest::prelude::*;
+    use solution::solution::AocError;
+    use strategies::strategies::{digits, format_digits};
+
+    #[test]
+    fn fft_known() {
+        let signal = input("12345678").unwrap();
+        assert_eq!(message(&fft(&signal, 1), 0, 8).unwrap(), "48226158");
+        assert_eq!(message(&fft(&signal, 2), 0, 8).unwrap(), "34040438");
+        assert_eq!(message(&fft(&signal, 3), 0, 8).unwrap(), "03415518");
+        assert_eq!(message(&fft(&signal, 4), 0, 8).unwrap(), "01029498");
+
+        for phases in 0..10 {
+            for example in [
+                "12345678",
+                "80871224585914546619083218645595",
+                "19617804207202209144916044189917",
+                "6931716349294860633599592431987",
+            ] {
+                let signal = input(example).unwrap();
+                assert_eq!(fft(&signal, phases), fft_naive(&signal, phases));
+            }
+        }
+
+        for example in ["1", "12", "12345678", "6931716349294860633599592431987"] {
+            let signal = input(example).unwrap();
+            assert_eq!(phase_double_buffered(&signal), fft(&signal, 1));
+        }
+
+        // The second half of a single copy can go through either implementation.
+        assert_eq!(fft_tail(&signal, 1, 4, 4).unwrap(), fft(&signal, 4)[4..]);
+        assert_eq!(
+            message_offset(&input("03036732577212944063491565474664").unwrap()).unwrap(),
+            303673
+        );
+    }
+
+    #[test]
+    fn test_short_signals() {
+        let signal = input("1").unwrap();
+        assert!(matches!(part_1(&signal), Err(AocError::InvalidInput(_))));
+        assert!(matches!(part_2(&signal), Err(AocError::InvalidInput(_))));
+        assert!(matches!(
+            message(&signal, 1, 1),
+            Err(AocError::InvalidInput(_))
+        ));
+
+        // Offset 1234567 is past the end of the 200,000-digit signal.
+        let signal = input("12345678901234567890").unwrap();
+        assert_eq!(part_1(&signal).unwrap().len(), 8);
+        assert!(matches!(part_2(&signal), Err(AocError::InvalidInput(_))));
+        // Offset 1 is in the first half.
+        let signal = input("00000010").unwrap();
+        assert!(matches!(part_2(&signal), Err(AocError::NoAnswer(_))));
+        assert!(matches!(
+            fft_tail(&signal, 1, 9, 1),
+            Err(AocError::InvalidInput(_))
+        ));
+        assert_eq!(fft_tail(&signal, 1, 8, 1).unwrap(), []);
+    }
+
+    proptest! {
+        #[test]
+        fn fft_matches_naive(signal in digits(1..=64), phases in 0usize..5) {
+            let signal = input(&format_digits(&signal)).unwrap();
+            prop_assert_eq!(fft(&signal, phases), fft_naive(&signal, phases));
+        }
+    }
+}
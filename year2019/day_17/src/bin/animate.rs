@@ -0,0 +1,57 @@
+//! Watch the vacuum robot walk the whole scaffold, leaving a trail of 'o' behind it; q quits.
+//!
+//! Usage: day_17_animate <input file> [frames per second]
+
+use day_17::day_17;
+use solution::solution::{read_input, Report};
+use std::time::Duration;
+use viz_term::viz_term::{self as viz, Frame, Outcome, Terminal};
+
+fn animate(input: &[i64], frame: Duration) -> Result<(), Report> {
+    let view = day_17::camera_view(input)?;
+    let route = day_17::Scaffold::parse(&view).route()?;
+    let width = view.lines().map(str::len).max().unwrap_or(0);
+    let height = view.lines().filter(|l| !l.is_empty()).count();
+    let footer = |step: usize| format!("Step {step} of {}", route.len() - 1);
+    let frame_width = width.max(footer(route.len() - 1).len());
+
+    let mut terminal = Terminal::new()?;
+    let mut step = 0;
+    let outcome = terminal.run(frame, |_| {
+        if step == route.len() {
+            return None;
+        }
+        let mut frame = Frame::new(frame_width, height + 2);
+        frame.sprite(0, 0, &view);
+        for &(row, col) in &route[..step] {
+            frame.put(col, row, 'o');
+        }
+        let (row, col) = route[step];
+        frame.put(col, row, '@');
+        frame.text(0, height + 1, &footer(step));
+        step += 1;
+        Some(frame)
+    })?;
+    if outcome == Outcome::Finished {
+        viz::wait_for_key()?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Report> {
+    let args: Vec<String> = std::env::args().collect();
+    let input_str = read_input(&args)?;
+    let fps: u32 = match args.get(2) {
+        None => 20,
+        Some(fps) => fps
+            .parse()
+            .map_err(|e| format!("Could not parse frame rate {fps} : {e}"))?,
+    };
+    if fps == 0 {
+        return Err("Frame rate must be positive".into());
+    }
+    let input = day_17::input(&input_str)
+        .map_err(|e| Report::from(format!("Error while parsing input: {e}")))?;
+
+    animate(&input, Duration::from_secs(1) / fps)
+}
@@ -0,0 +1,469 @@
+pub mod day_17 {
+    use std::{collections::HashSet, fmt::Display};
+
+    use intcode::intcode::{MachineExecutionError, MachineState, Patch, Program};
+    use log::debug;
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, Solution};
+    use thiserror::Error;
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    #[derive(Error, Debug)]
+    pub enum AsciiError {
+        #[error("program output {0}, which is not an ASCII character")]
+        NotAscii(i64),
+        #[error("no vacuum robot in the camera view")]
+        NoRobot,
+        #[error("could not split the walk into a main routine and three movement functions")]
+        NoCompression,
+        #[error("the scaffold loops back on itself, so the robot would walk it forever")]
+        EndlessWalk,
+        #[error("program finished without reporting how much dust was collected")]
+        NoDustReport,
+        #[error(transparent)]
+        Machine(#[from] MachineExecutionError),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Direction {
+        Up,
+        Right,
+        Down,
+        Left,
+    }
+
+    impl Direction {
+        fn turn_right(self) -> Direction {
+            match self {
+                Direction::Up => Direction::Right,
+                Direction::Right => Direction::Down,
+                Direction::Down => Direction::Left,
+                Direction::Left => Direction::Up,
+            }
+        }
+
+        fn turn_left(self) -> Direction {
+            self.turn_right().turn_right().turn_right()
+        }
+
+        fn step(self, (row, col): (usize, usize)) -> Option<(usize, usize)> {
+            match self {
+                Direction::Up => Some((row.checked_sub(1)?, col)),
+                Direction::Right => Some((row, col + 1)),
+                Direction::Down => Some((row + 1, col)),
+                Direction::Left => Some((row, col.checked_sub(1)?)),
+            }
+        }
+    }
+
+    /// The camera's view of the scaffolding.
+    pub struct Scaffold {
+        rows: Vec<Vec<u8>>,
+    }
+
+    impl Scaffold {
+        pub fn parse(view: &str) -> Scaffold {
+            Scaffold {
+                rows: view
+                    .lines()
+                    .filter(|l| !l.is_empty())
+                    .map(|l| l.as_bytes().to_vec())
+                    .collect(),
+            }
+        }
+
+        fn get(&self, (row, col): (usize, usize)) -> Option<u8> {
+            self.rows.get(row)?.get(col).copied()
+        }
+
+        /// Whether there is scaffolding at this point, including under the robot.
+        fn is_scaffold(&self, pos: (usize, usize)) -> bool {
+            matches!(self.get(pos), Some(b'#' | b'^' | b'v' | b'<' | b'>'))
+        }
+
+        fn robot(&self) -> Option<((usize, usize), Direction)> {
+            self.rows.iter().enumerate().find_map(|(row, line)| {
+                line.iter().enumerate().find_map(|(col, c)| {
+                    let direction = match c {
+                        b'^' => Direction::Up,
+                        b'>' => Direction::Right,
+                        b'v' => Direction::Down,
+                        b'<' => Direction::Left,
+                        _ => return None,
+                    };
+                    Some(((row, col), direction))
+                })
+            })
+        }
+
+        /// The scaffold points which have scaffold on all four sides.
+        pub fn intersections(&self) -> Vec<(usize, usize)> {
+            let mut result = vec![];
+            for (row, line) in self.rows.iter().enumerate() {
+                for col in 0..line.len() {
+                    if self.is_scaffold((row, col))
+                        && [
+                            Direction::Up,
+                            Direction::Right,
+                            Direction::Down,
+                            Direction::Left,
+                        ]
+                        .iter()
+                        .all(|d| d.step((row, col)).is_some_and(|p| self.is_scaffold(p)))
+                    {
+                        result.push((row, col));
+                    }
+                }
+            }
+            result
+        }
+
+        pub fn alignment_sum(&self) -> usize {
+            self.intersections()
+                .iter()
+                .map(|(row, col)| row * col)
+                .sum()
+        }
+
+        /// The robot's route over the whole scaffold: carry straight on wherever possible, and
+        /// otherwise turn whichever way the scaffold goes, until it runs out.
+        /// Fails if that never happens because the scaffold leads round in a circle.
+        pub fn walk(&self) -> Result<Vec<Command>, AsciiError> {
+            self.walk_visiting(|_| {})
+        }
+
+        /// Every point the robot passes through on its walk, in order, starting where it stands.
+        pub fn route(&self) -> Result<Vec<(usize, usize)>, AsciiError> {
+            let mut route = vec![];
+            self.walk_visiting(|pos| route.push(pos))?;
+            Ok(route)
+        }
+
+        fn walk_visiting<F>(&self, mut visit: F) -> Result<Vec<Command>, AsciiError>
+        where
+            F: FnMut((usize, usize)),
+        {
+            let (mut pos, mut direction) = self.robot().ok_or(AsciiError::NoRobot)?;
+            let ahead =
+                |pos, direction: Direction| direction.step(pos).filter(|&p| self.is_scaffold(p));
+
+            visit(pos);
+            let mut commands = vec![];
+            // The walk is fixed by where the robot is and which way it faces, so if it ever
+            // turns onto the same heading at the same point again, it's going round in a circle.
+            let mut turns = HashSet::new();
+            loop {
+                let mut steps = 0;
+                while let Some(next) = ahead(pos, direction) {
+                    pos = next;
+                    visit(pos);
+                    steps += 1;
+                }
+                if steps > 0 {
+                    commands.push(Command::Forward(steps));
+                }
+                if ahead(pos, direction.turn_left()).is_some() {
+                    direction = direction.turn_left();
+                    commands.push(Command::Left);
+                } else if ahead(pos, direction.turn_right()).is_some() {
+                    direction = direction.turn_right();
+                    commands.push(Command::Right);
+                } else {
+                    return Ok(commands);
+                }
+                if !turns.insert((pos, direction)) {
+                    return Err(AsciiError::EndlessWalk);
+                }
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Command {
+        Left,
+        Right,
+        Forward(usize),
+    }
+
+    impl Display for Command {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Command::Left => write!(f, "L"),
+                Command::Right => write!(f, "R"),
+                Command::Forward(steps) => write!(f, "{steps}"),
+            }
+        }
+    }
+
+    fn join<T: Display>(items: &[T]) -> String {
+        items
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// The robot's memory only holds this many characters of each routine, not counting the
+    /// newline.
+    const MAX_ROUTINE_LENGTH: usize = 20;
+
+    /// A walk split into a main routine, which calls movement functions A, B and C by index.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Routines {
+        pub main: Vec<usize>,
+        pub functions: Vec<Vec<Command>>,
+    }
+
+    impl Routines {
+        /// What to feed the robot: the main routine, the three functions, and "n" to decline the
+        /// video feed.
+        pub fn to_input(&self) -> String {
+            let main: Vec<char> = self
+                .main
+                .iter()
+                .map(|&i| (b'A' + i as u8) as char)
+                .collect();
+            let mut result = join(&main);
+            result.push('\n');
+            for i in 0..3 {
+                if let Some(function) = self.functions.get(i) {
+                    result.push_str(&join(function));
+                }
+                result.push('\n');
+            }
+            result.push_str("n\n");
+            result
+        }
+    }
+
+    fn compress_from<'a>(
+        commands: &'a [Command],
+        functions: &mut Vec<&'a [Command]>,
+        main: &mut Vec<usize>,
+    ) -> bool {
+        if commands.is_empty() {
+            return true;
+        }
+        // Each call takes a character, and all but the first take a comma.
+        if 2 * (main.len() + 1) - 1 > MAX_ROUTINE_LENGTH {
+            return false;
+        }
+
+        for i in 0..functions.len() {
+            let function = functions[i];
+            if commands.starts_with(function) {
+                main.push(i);
+                if compress_from(&commands[function.len()..], functions, main) {
+                    return true;
+                }
+                main.pop();
+            }
+        }
+
+        if functions.len() < 3 {
+            for end in 1..=commands.len() {
+                let candidate = &commands[..end];
+                if join(candidate).len() > MAX_ROUTINE_LENGTH {
+                    break;
+                }
+                functions.push(candidate);
+                main.push(functions.len() - 1);
+                if compress_from(&commands[end..], functions, main) {
+                    return true;
+                }
+                main.pop();
+                functions.pop();
+            }
+        }
+
+        false
+    }
+
+    /// Splits the walk into a main routine and at most three movement functions, all of which
+    /// fit in the robot's memory, by depth-first search: at each point of the walk, either call
+    /// a function already defined which matches what comes next, or define a new one from
+    /// some prefix of what comes next.
+    pub fn compress(commands: &[Command]) -> Option<Routines> {
+        let mut functions = vec![];
+        let mut main = vec![];
+        if compress_from(commands, &mut functions, &mut main) {
+            Some(Routines {
+                main,
+                functions: functions.iter().map(|f| f.to_vec()).collect(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Runs the program with no input, and reads what the cameras see.
+    pub fn camera_view(input: &[i64]) -> Result<String, AsciiError> {
+        let mut machine = MachineState::new_with_memory(&input.iter().copied());
+        let output = machine.execute_collect_outputs()?;
+        output
+            .iter()
+            .map(|&c| {
+                u8::try_from(c)
+                    .ok()
+                    .filter(u8::is_ascii)
+                    .map(char::from)
+                    .ok_or(AsciiError::NotAscii(c))
+            })
+            .collect()
+    }
+
+    pub fn part_1(input: &[i64]) -> Result<usize, AsciiError> {
+        Ok(Scaffold::parse(&camera_view(input)?).alignment_sum())
+    }
+
+    /// Wakes the vacuum robot, so that it takes movement routines rather than just showing the
+    /// camera's view.
+    pub const WAKE_UP: Patch<i64> = Patch {
+        addr: 0,
+        value: 2,
+        label: "wake up the vacuum robot",
+    };
+
+    pub fn part_2(input: &[i64]) -> Result<i64, AsciiError> {
+        let walk = Scaffold::parse(&camera_view(input)?).walk()?;
+        let routines = compress(&walk).ok_or(AsciiError::NoCompression)?;
+        debug!("movement routines:\n{}", routines.to_input());
+
+        let program = Program::new(input.iter().copied()).with_patches(&[WAKE_UP]);
+        let mut machine = MachineState::from_program(&program);
+        let output = machine.execute_to_end(&mut routines.to_input().bytes().map(i64::from))?;
+        // Everything else the robot says is ASCII; the dust count is the only number beyond it.
+        match output.last() {
+            Some(&dust) if dust > 127 => Ok(dust),
+            _ => Err(AsciiError::NoDustReport),
+        }
+    }
+
+    pub struct Day17;
+
+    impl Solution for Day17 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_17::*;
+
+    #[test]
+    fn part1_known() {
+        let view = "\
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..
+";
+        let scaffold = Scaffold::parse(view);
+        assert_eq!(
+            scaffold.intersections(),
+            vec![(2, 2), (4, 2), (4, 6), (4, 10)]
+        );
+        assert_eq!(scaffold.alignment_sum(), 76);
+    }
+
+    const PART_2_EXAMPLE: &str = "\
+#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......
+";
+
+    #[test]
+    fn test_walk() {
+        let walk = Scaffold::parse(PART_2_EXAMPLE).walk().unwrap();
+        let rendered: Vec<String> = walk.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            rendered.join(","),
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+
+        let route = Scaffold::parse(PART_2_EXAMPLE).route().unwrap();
+        let steps: usize = walk
+            .iter()
+            .map(|c| match c {
+                Command::Forward(steps) => *steps,
+                _ => 0,
+            })
+            .sum();
+        assert_eq!(route.len(), steps + 1);
+        assert_eq!(route[..3], [(6, 0), (6, 1), (6, 2)]);
+        assert_eq!(route.last(), Some(&(2, 0)));
+
+        let routines = compress(&walk).unwrap();
+        assert_eq!(routines.functions.len(), 3);
+        let expanded: Vec<Command> = routines
+            .main
+            .iter()
+            .flat_map(|&i| routines.functions[i].iter().copied())
+            .collect();
+        assert_eq!(expanded, walk);
+        let input = routines.to_input();
+        assert!(input.lines().all(|l| l.len() <= 20), "{input}");
+        assert!(input.ends_with("\nn\n"));
+    }
+
+    #[test]
+    fn test_endless_walk() {
+        let ring = Scaffold::parse("###\n#.#\n^##\n");
+        assert!(matches!(ring.walk(), Err(AsciiError::EndlessWalk)));
+        // The robot never comes back to where it started, but still goes round forever.
+        let lasso = Scaffold::parse("###\n#.#\n###\n.#.\n.^.\n");
+        assert!(matches!(lasso.route(), Err(AsciiError::EndlessWalk)));
+    }
+
+    #[test]
+    fn test_compress_limits() {
+        // Each function can hold at most seven two-digit steps, and with no repeats each
+        // function is called at most once, so thirty different steps won't fit.
+        let walk: Vec<Command> = (10..40).map(Command::Forward).collect();
+        assert_eq!(compress(&walk), None);
+
+        let routines = compress(&[Command::Left, Command::Forward(4)]).unwrap();
+        assert_eq!(routines.to_input(), "A,B\nL\n4\n\nn\n");
+    }
+
+    #[test]
+    fn test_camera_view() {
+        // Prints "#.\n" and halts.
+        let program = input("104,35,104,46,104,10,99").unwrap();
+        assert_eq!(camera_view(&program).unwrap(), "#.\n");
+        assert!(matches!(
+            camera_view(&input("104,1000,99").unwrap()),
+            Err(AsciiError::NotAscii(1000))
+        ));
+    }
+}
@@ -0,0 +1,73 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_18::day_18::{input, part_1, part_2, shortest_path_with, Maze, Strategy};
+
+/// The slowest of the published part 1 examples.
+const EXAMPLE: &str = "\
+#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################";
+
+/// The slowest of the published part 2 examples.
+const EXAMPLE_FOUR_ROBOTS: &str = "\
+#############
+#g#f.D#..h#l#
+#F###e#E###.#
+#dCba@#@BcIJ#
+#############
+#nK.L@#@G...#
+#M###N#H###.#
+#o#m..#i#jk.#
+#############";
+
+/// Runs every strategy on each of `mazes`.
+fn bench_strategies(c: &mut Criterion, mazes: &[(&str, Maze)]) {
+    let mut group = c.benchmark_group("day 18 strategies");
+    for (name, maze) in mazes.iter() {
+        for strategy in [Strategy::Dijkstra, Strategy::AStar, Strategy::Parallel] {
+            group.bench_function(format!("{name} {strategy:?}"), |b| {
+                b.iter(|| {
+                    black_box(shortest_path_with(maze, strategy).unwrap());
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_strategies(
+        c,
+        &[
+            ("example", input(EXAMPLE).unwrap()),
+            ("example four robots", input(EXAMPLE_FOUR_ROBOTS).unwrap()),
+        ],
+    );
+
+    let Some(input_str) = loader::loader::load(18) else {
+        return;
+    };
+    let real = input(&input_str).unwrap();
+    c.bench_function("day 18 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&real).unwrap());
+        })
+    });
+    c.bench_function("day 18 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&real).unwrap());
+        })
+    });
+
+    let mut four_robots = real.clone();
+    assert!(four_robots.split_entrance());
+    bench_strategies(c, &[("input", real), ("input four robots", four_robots)]);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,517 @@
+pub mod day_18 {
+    use std::collections::{HashMap, VecDeque};
+
+    use graph::graph::astar;
+    use parse::parse::{grid, ParseError};
+    use rayon::prelude::*;
+    use solution::solution::{found, Answer, Solution};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Maze {
+        rows: Vec<Vec<u8>>,
+    }
+
+    /// Parses the maze, which must be rectangular, walled in all the way round, and have between
+    /// one and four entrances.
+    pub fn input(s: &str) -> Result<Maze, ParseError> {
+        let maze = grid(s, |c| {
+            (matches!(c, '#' | '.' | '@') || c.is_ascii_alphabetic()).then_some(c as u8)
+        })?;
+        let (rows, cols) = (maze.row_count(), maze.col_count());
+        for ((row, col), &c) in maze.iter() {
+            let edge = row == 0 || col == 0 || row == rows - 1 || col == cols - 1;
+            if edge && c != b'#' {
+                return Err(ParseError::Invalid {
+                    line: row + 1,
+                    column: col + 1,
+                    message: format!("expected a wall round the edge, but found {:?}", c as char),
+                });
+            }
+        }
+        let entrances = maze.iter().filter(|(_, &c)| c == b'@').count();
+        if !(1..=4).contains(&entrances) {
+            return Err(ParseError::WrongCount {
+                what: "entrances",
+                expected: entrances.clamp(1, 4),
+                actual: entrances,
+            });
+        }
+        Ok(Maze {
+            rows: maze.rows().map(<[u8]>::to_vec).collect(),
+        })
+    }
+
+    /// Keys are nodes 0 to 25, by letter; the entrances follow.
+    const ENTRANCE: u8 = 26;
+
+    /// A way from one node to a key, and the doors (as a bitmask of the keys which open them)
+    /// standing in the way.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Edge {
+        pub to: u8,
+        pub distance: usize,
+        pub doors: u32,
+    }
+
+    fn key_bit(key: u8) -> u32 {
+        1 << key
+    }
+
+    impl Maze {
+        fn entrances(&self) -> Vec<(usize, usize)> {
+            let mut result = vec![];
+            for (row, line) in self.rows.iter().enumerate() {
+                for (col, &c) in line.iter().enumerate() {
+                    if c == b'@' {
+                        result.push((row, col));
+                    }
+                }
+            }
+            result
+        }
+
+        /// The bitmask of every key in the maze.
+        pub fn all_keys(&self) -> u32 {
+            self.rows
+                .iter()
+                .flatten()
+                .filter(|c| c.is_ascii_lowercase())
+                .fold(0, |acc, c| acc | key_bit(c - b'a'))
+        }
+
+        /// Turns the single entrance into four, each walled off from the others, as in part 2.
+        /// Mazes which already have several entrances are left alone. Returns false, leaving the
+        /// maze alone, if the entrance isn't surrounded by open floor inside the outer wall.
+        pub fn split_entrance(&mut self) -> bool {
+            let entrances = self.entrances();
+            if entrances.len() != 1 {
+                return true;
+            }
+            let (row, col) = entrances[0];
+            if row < 2 || col < 2 || row + 2 >= self.rows.len() || col + 2 >= self.rows[0].len() {
+                return false;
+            }
+            let open = self.rows[row - 1..=row + 1].iter().all(|line| {
+                line[col - 1..=col + 1]
+                    .iter()
+                    .all(|&c| c == b'.' || c == b'@')
+            });
+            if !open {
+                return false;
+            }
+            let replacement = [b"@#@", b"###", b"@#@"];
+            for (dr, line) in replacement.iter().enumerate() {
+                for (dc, &c) in line.iter().enumerate() {
+                    self.rows[row + dr - 1][col + dc - 1] = c;
+                }
+            }
+            true
+        }
+
+        /// Breadth-first search from the given point to every key reachable from it.
+        fn edges_from(&self, start: (usize, usize)) -> Vec<Edge> {
+            let mut edges = vec![];
+            let mut seen = vec![vec![false; self.rows[0].len()]; self.rows.len()];
+            seen[start.0][start.1] = true;
+            let mut queue = VecDeque::from([(start, 0, 0)]);
+            while let Some(((row, col), distance, doors)) = queue.pop_front() {
+                let neighbours = [
+                    (row.checked_sub(1), Some(col)),
+                    (Some(row + 1), Some(col)),
+                    (Some(row), col.checked_sub(1)),
+                    (Some(row), Some(col + 1)),
+                ];
+                for (r, c) in neighbours {
+                    let (Some(r), Some(c)) = (r, c) else {
+                        continue;
+                    };
+                    let cell = match self.rows.get(r).and_then(|line| line.get(c)) {
+                        None | Some(b'#') => continue,
+                        Some(&cell) => cell,
+                    };
+                    if seen[r][c] {
+                        continue;
+                    }
+                    seen[r][c] = true;
+                    let mut doors = doors;
+                    if cell.is_ascii_lowercase() {
+                        edges.push(Edge {
+                            to: cell - b'a',
+                            distance: distance + 1,
+                            doors,
+                        });
+                    } else if cell.is_ascii_uppercase() {
+                        doors |= key_bit(cell - b'A');
+                    }
+                    queue.push_back(((r, c), distance + 1, doors));
+                }
+            }
+            edges
+        }
+
+        /// The distance from each key and entrance to each key it can reach, indexed by node.
+        pub fn graph(&self) -> Vec<Vec<Edge>> {
+            let entrances = self.entrances();
+            let mut graph = vec![vec![]; ENTRANCE as usize + entrances.len()];
+            for (row, line) in self.rows.iter().enumerate() {
+                for (col, &c) in line.iter().enumerate() {
+                    if c.is_ascii_lowercase() {
+                        graph[(c - b'a') as usize] = self.edges_from((row, col));
+                    }
+                }
+            }
+            for (i, &entrance) in entrances.iter().enumerate() {
+                graph[ENTRANCE as usize + i] = self.edges_from(entrance);
+            }
+            graph
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    struct State {
+        /// The node each robot is at; only the first `robots` entries are meaningful.
+        positions: [u8; 4],
+        keys: u32,
+    }
+
+    /// How to search the space of (robot positions, keys held).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Strategy {
+        /// Dijkstra, moving one robot from key to key at a time.
+        Dijkstra,
+        /// A*, bounding the distance still to go by a minimum spanning tree over the keys not yet
+        /// collected and the robots.
+        AStar,
+        /// Every move collects exactly one key, so the states can be handled in layers by how many
+        /// keys they hold; each layer is expanded in parallel.
+        Parallel,
+    }
+
+    struct Search {
+        graph: Vec<Vec<Edge>>,
+        /// The length of the shortest path between each pair of nodes, ignoring doors, or None if
+        /// there is none.
+        distances: Vec<Vec<Option<usize>>>,
+        robots: usize,
+        all_keys: u32,
+    }
+
+    impl Search {
+        fn new(maze: &Maze) -> Search {
+            let graph = maze.graph();
+            let robots = graph.len() - ENTRANCE as usize;
+            assert!(
+                (1..=4).contains(&robots),
+                "expected between one and four entrances, got {robots}"
+            );
+            let mut distances = vec![vec![None; ENTRANCE as usize]; graph.len()];
+            for (from, edges) in graph.iter().enumerate() {
+                for edge in edges {
+                    distances[from][edge.to as usize] = Some(edge.distance);
+                }
+            }
+            Search {
+                graph,
+                distances,
+                robots,
+                all_keys: maze.all_keys(),
+            }
+        }
+
+        fn start(&self) -> State {
+            let mut positions = [0; 4];
+            for (i, position) in positions.iter_mut().enumerate().take(self.robots) {
+                *position = ENTRANCE + i as u8;
+            }
+            State { positions, keys: 0 }
+        }
+
+        /// Each state reachable by sending one robot to collect one more key, with the distance
+        /// that robot walks.
+        fn moves(&self, state: State) -> impl Iterator<Item = (State, usize)> + '_ {
+            (0..self.robots).flat_map(move |robot| {
+                self.graph[state.positions[robot] as usize]
+                    .iter()
+                    .filter(move |edge| {
+                        state.keys & key_bit(edge.to) == 0 && edge.doors & !state.keys == 0
+                    })
+                    .map(move |edge| {
+                        let mut next = state;
+                        next.positions[robot] = edge.to;
+                        next.keys |= key_bit(edge.to);
+                        (next, edge.distance)
+                    })
+            })
+        }
+
+        /// The weight of a minimum spanning tree over the remaining keys and a root standing for
+        /// all the robots, found by Prim's algorithm. The robots' remaining walks join every
+        /// remaining key to some robot, so they are at least this long.
+        fn spanning_tree(&self, state: State) -> usize {
+            let remaining: Vec<usize> = (0..ENTRANCE as usize)
+                .filter(|&key| (self.all_keys & !state.keys) & key_bit(key as u8) != 0)
+                .collect();
+            // The cheapest known way to join each remaining key to the tree, which starts as
+            // just the root.
+            let mut cost: Vec<Option<usize>> = remaining
+                .iter()
+                .map(|&key| {
+                    state.positions[..self.robots]
+                        .iter()
+                        .filter_map(|&p| self.distances[p as usize][key])
+                        .min()
+                })
+                .collect();
+            let mut in_tree = vec![false; remaining.len()];
+            let mut total = 0;
+            for _ in 0..remaining.len() {
+                let next = (0..remaining.len())
+                    .filter(|&i| !in_tree[i])
+                    .filter_map(|i| cost[i].map(|c| (c, i)))
+                    .min();
+                let (c, i) = match next {
+                    // The rest are unreachable, which `moves` will discover for itself.
+                    None => break,
+                    Some(next) => next,
+                };
+                in_tree[i] = true;
+                total += c;
+                for j in 0..remaining.len() {
+                    if let Some(d) = self.distances[remaining[i]][remaining[j]] {
+                        if cost[j].is_none_or(|c| d < c) {
+                            cost[j] = Some(d);
+                        }
+                    }
+                }
+            }
+            total
+        }
+
+        /// Best-first search, ordered by distance so far plus `heuristic`, which must never
+        /// overestimate the distance still to go.
+        fn best_first<H>(&self, heuristic: H) -> Option<usize>
+        where
+            H: Fn(State) -> usize,
+        {
+            let (_, distance) = astar(
+                self.start(),
+                |&state| self.moves(state),
+                |&state| heuristic(state),
+                |state| state.keys == self.all_keys,
+            )?;
+            Some(distance)
+        }
+
+        fn layered(&self) -> Option<usize> {
+            let mut layer: HashMap<State, usize> = HashMap::from([(self.start(), 0)]);
+            for _ in 0..self.all_keys.count_ones() {
+                layer = layer
+                    .par_iter()
+                    .fold(HashMap::new, |mut next_layer, (&state, &distance)| {
+                        for (next, walked) in self.moves(state) {
+                            let entry = next_layer.entry(next).or_insert(usize::MAX);
+                            *entry = std::cmp::min(*entry, distance + walked);
+                        }
+                        next_layer
+                    })
+                    .reduce(HashMap::new, |mut a, b| {
+                        for (state, distance) in b {
+                            let entry = a.entry(state).or_insert(usize::MAX);
+                            *entry = std::cmp::min(*entry, distance);
+                        }
+                        a
+                    });
+            }
+            layer
+                .iter()
+                .filter(|(state, _)| state.keys == self.all_keys)
+                .map(|(_, &distance)| distance)
+                .min()
+        }
+    }
+
+    /// The fewest steps to collect every key, or None if they can't all be collected.
+    pub fn shortest_path_with(maze: &Maze, strategy: Strategy) -> Option<usize> {
+        let search = Search::new(maze);
+        match strategy {
+            Strategy::Dijkstra => search.best_first(|_| 0),
+            Strategy::AStar => search.best_first(|state| search.spanning_tree(state)),
+            Strategy::Parallel => search.layered(),
+        }
+    }
+
+    pub fn shortest_path(maze: &Maze) -> Option<usize> {
+        shortest_path_with(maze, Strategy::Dijkstra)
+    }
+
+    pub fn part_1(maze: &Maze) -> Option<usize> {
+        shortest_path(maze)
+    }
+
+    /// None if the entrance can't be split, as well as if the keys can't all be collected.
+    pub fn part_2(maze: &Maze) -> Option<usize> {
+        let mut maze = maze.clone();
+        if !maze.split_entrance() {
+            return None;
+        }
+        shortest_path(&maze)
+    }
+
+    const UNREACHABLE_KEYS: &str = "the keys can't all be collected";
+    const UNSPLITTABLE: &str = "the entrance can't be split, or the keys can't all be collected";
+
+    pub struct Day18;
+
+    impl Solution for Day18 {
+        type Parsed<'a> = Maze;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            found(part_1(parsed), UNREACHABLE_KEYS)
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            found(part_2(parsed), UNSPLITTABLE)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_18::*;
+    use parse::parse::ParseError;
+
+    #[test]
+    fn part1_known() {
+        let examples = [
+            (include_str!("../examples/one-robot-1.txt"), 8),
+            (include_str!("../examples/one-robot-2.txt"), 86),
+            (include_str!("../examples/one-robot-3.txt"), 132),
+            (include_str!("../examples/one-robot-4.txt"), 136),
+            (include_str!("../examples/one-robot-5.txt"), 81),
+        ];
+        for (maze, expected) in examples {
+            for strategy in [Strategy::AStar, Strategy::Parallel] {
+                assert_eq!(
+                    shortest_path_with(&input(maze).unwrap(), strategy),
+                    Some(expected),
+                    "{strategy:?}\n{maze}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn part2_known() {
+        let examples = [
+            (include_str!("../examples/four-robots-1.txt"), 8),
+            (include_str!("../examples/four-robots-2.txt"), 24),
+            (include_str!("../examples/four-robots-3.txt"), 32),
+            (include_str!("../examples/four-robots-4.txt"), 72),
+        ];
+        for (maze, expected) in examples {
+            let mut maze = input(maze).unwrap();
+            assert!(maze.split_entrance());
+            for strategy in [Strategy::AStar, Strategy::Parallel] {
+                assert_eq!(
+                    shortest_path_with(&maze, strategy),
+                    Some(expected),
+                    "{strategy:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_entrance() {
+        let mut maze = input(
+            "\
+#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######",
+        )
+        .unwrap();
+        assert!(maze.split_entrance());
+        let expected = input(
+            "\
+#######
+#a.#Cd#
+##@#@##
+#######
+##@#@##
+#cB#Ab#
+#######",
+        )
+        .unwrap();
+        assert_eq!(maze, expected);
+
+        // The entrance must have open floor all round it, inside the outer wall.
+        for cramped in ["###\n#@#\n###", "#####\n#.@.#\n#...#\n#...#\n#####"] {
+            let mut maze = input(cramped).unwrap();
+            assert!(!maze.split_entrance());
+            assert_eq!(maze, input(cramped).unwrap());
+            assert_eq!(part_2(&maze), None);
+        }
+        let mut keyed =
+            input("#######\n#.....#\n#..a..#\n#..@..#\n#.....#\n#.....#\n#######").unwrap();
+        assert!(!keyed.split_entrance());
+
+        // A door with no key can never be opened.
+        let locked = input("#####\n#@Ab#\n#####").unwrap();
+        assert_eq!(part_1(&locked), None);
+        assert_eq!(shortest_path_with(&locked, Strategy::AStar), None);
+        assert_eq!(shortest_path_with(&locked, Strategy::Parallel), None);
+    }
+
+    #[test]
+    fn test_input_errors() {
+        assert_eq!(input(" \n"), Err(ParseError::Empty));
+        assert_eq!(
+            input("#####\n#@a#\n#####"),
+            Err(ParseError::Ragged {
+                line: 2,
+                expected: 5,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            input("#####\n#@a.!\n#####"),
+            Err(ParseError::Invalid {
+                line: 2,
+                column: 5,
+                message: "unexpected character '!'".to_string()
+            })
+        );
+        assert_eq!(
+            input("#####\n#@a..\n#####"),
+            Err(ParseError::Invalid {
+                line: 2,
+                column: 5,
+                message: "expected a wall round the edge, but found '.'".to_string()
+            })
+        );
+        assert_eq!(
+            input("#"),
+            Err(ParseError::WrongCount {
+                what: "entrances",
+                expected: 1,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            input("#######\n#@@@@@#\n#######"),
+            Err(ParseError::WrongCount {
+                what: "entrances",
+                expected: 4,
+                actual: 5
+            })
+        );
+    }
+}
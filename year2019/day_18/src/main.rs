@@ -0,0 +1,24 @@
+use day_18::day_18;
+use solution::solution::{read_input, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let input_str = read_input(&args)?;
+    let input = match day_18::input(&input_str) {
+        Ok(input) => input,
+        Err(e) => return Err(format!("Error while parsing input: {e}").into()),
+    };
+
+    match day_18::part_1(&input) {
+        Some(steps) => println!("part 1 => {steps}"),
+        None => println!("part 1 => (the keys can't all be collected)"),
+    }
+    match day_18::part_2(&input) {
+        Some(steps) => println!("part 2 => {steps}"),
+        None => {
+            println!("part 2 => (the entrance can't be split, or the keys can't all be collected)")
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_19::day_19::{input, part_1, part_1_analytic, part_2};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input_str) = loader::loader::load(19) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 19 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&input).unwrap());
+        })
+    });
+    c.bench_function("day 19 part 1 analytic", |b| {
+        b.iter(|| {
+            black_box(part_1_analytic(&input).unwrap());
+        })
+    });
+    c.bench_function("day 19 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&input).unwrap());
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
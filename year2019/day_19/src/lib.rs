@@ -0,0 +1,528 @@
+pub mod day_19 {
+    use intcode::ast::{Ast, Condition};
+    use intcode::intcode::{MachineExecutionError, MachineState};
+    use intcode::linked_list::List;
+    use log::debug;
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, AocError, Solution};
+    use std::ops::Range;
+    #[cfg(feature = "image")]
+    use thiserror::Error;
+    #[cfg(feature = "image")]
+    use viz_image::viz_image::{self as viz, RgbaImage, BLACK, RED, WHITE};
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    /// The drone's answer as a formula in the coordinates, x and y.
+    fn get_output(input: &[i64]) -> Result<Ast, AocError> {
+        let mut machine = MachineState::new_with_memory(&input.iter().copied().map(Ast::Constant));
+        machine.push_inputs([Ast::Variable('x'), Ast::Variable('y')]);
+        let result = machine.execute_until_input()?;
+        let reading = machine.queued_inputs() > 0;
+        match result {
+            intcode::intcode::StepIoResult::Terminated if reading => {
+                Err(AocError::Terminated("reading the coordinates"))
+            }
+            intcode::intcode::StepIoResult::Terminated => Err(AocError::Terminated(
+                "working out whether the point is in the beam",
+            )),
+            intcode::intcode::StepIoResult::Output(ast) if reading => Err(AocError::InvalidInput(
+                format!("the program output {ast} before reading the coordinates"),
+            )),
+            intcode::intcode::StepIoResult::AwaitingInput(_) => Err(AocError::UnexpectedInput(
+                "working out whether the point is in the beam",
+            )),
+            intcode::intcode::StepIoResult::Output(ast) => Ok(ast),
+        }
+    }
+
+    /// Runs the drone program once, asking whether (x, y) is in the beam.
+    pub fn query(input: &[i64], x: i64, y: i64) -> Result<bool, MachineExecutionError> {
+        let mut machine: MachineState<i64> = MachineState::new_with_memory(&input.iter().copied());
+        machine.push_inputs([x, y]);
+        let output = machine.execute_collect_outputs()?;
+        Ok(output == [1])
+    }
+
+    /// Tracks the left and right edges of the beam row by row, so that each row costs only the
+    /// few queries needed to move the edges on from those of the row above.
+    /// The beam is assumed to be a cone out of the origin, so that each row's edges are no further
+    /// left than those of the row below, and with a slope (x / y) of less than 10.
+    pub struct BeamScanner<F> {
+        in_beam: F,
+        // The inclusive [left, right] extent of each row scanned so far, or None if it was empty.
+        rows: Vec<Option<(i64, i64)>>,
+        left: i64,
+        right: i64,
+        queries: usize,
+    }
+
+    impl<F> BeamScanner<F>
+    where
+        F: FnMut(i64, i64) -> bool,
+    {
+        pub fn new(in_beam: F) -> BeamScanner<F> {
+            BeamScanner {
+                in_beam,
+                rows: vec![],
+                left: 0,
+                right: 0,
+                queries: 0,
+            }
+        }
+
+        /// How many times the scanner has asked whether a point is in the beam.
+        pub fn queries(&self) -> usize {
+            self.queries
+        }
+
+        fn in_beam(&mut self, x: i64, y: i64) -> bool {
+            self.queries += 1;
+            (self.in_beam)(x, y)
+        }
+
+        fn scan_next_row(&mut self) -> Option<(i64, i64)> {
+            let y = self.rows.len() as i64;
+            let mut x = self.left;
+            while x <= 10 * (y + 1) && !self.in_beam(x, y) {
+                x += 1;
+            }
+            if x > 10 * (y + 1) {
+                return None;
+            }
+            self.left = x;
+            self.right = std::cmp::max(self.right, self.left);
+            while self.right < 10 * (y + 1) && self.in_beam(self.right + 1, y) {
+                self.right += 1;
+            }
+            Some((self.left, self.right))
+        }
+
+        /// The inclusive extent of the beam in row y, or None if the row is empty.
+        pub fn row(&mut self, y: i64) -> Option<(i64, i64)> {
+            while self.rows.len() as i64 <= y {
+                let row = self.scan_next_row();
+                self.rows.push(row);
+            }
+            self.rows[y as usize]
+        }
+    }
+
+    /// Counts the points of the beam in the square [0, size) x [0, size).
+    pub fn count_in_square<F>(scanner: &mut BeamScanner<F>, size: i64) -> u32
+    where
+        F: FnMut(i64, i64) -> bool,
+    {
+        (0..size)
+            .filter_map(|y| scanner.row(y))
+            .map(|(left, right)| std::cmp::max(0, std::cmp::min(right, size - 1) - left + 1) as u32)
+            .sum()
+    }
+
+    pub fn part_1(input: &[i64]) -> Result<u32, MachineExecutionError> {
+        let mut error = None;
+        let mut scanner = BeamScanner::new(|x, y| {
+            query(input, x, y).unwrap_or_else(|e| {
+                error.get_or_insert(e);
+                false
+            })
+        });
+        let result = count_in_square(&mut scanner, 50);
+        match error {
+            Some(e) => Err(e),
+            None => Ok(result),
+        }
+    }
+
+    /// Part 1 without running the drone at all: simplify the symbolic beam predicate once, then
+    /// evaluate the formula at every point of the grid.
+    pub fn part_1_analytic(input: &[i64]) -> Result<u32, AocError> {
+        let output = get_output(input)?.simplify(&List::new());
+        let mut result = 0;
+        for y in 0..=49 {
+            for x in 0..=49 {
+                let query_result = output
+                    .eval(&mut |c| match c {
+                        'x' => Some(x),
+                        'y' => Some(y),
+                        _ => None,
+                    })
+                    .map_err(|c| {
+                        AocError::InvalidInput(format!("the beam depends on an unknown {c:?}"))
+                    })?;
+                result += query_result as u32
+            }
+        }
+        Ok(result)
+    }
+
+    /// Finds the top-left corner of the first `size`-by-`size` square which fits entirely in the
+    /// beam, walking down the rows and checking the far corner of the widest square each row admits.
+    /// Returns None if no square fits with its top row before `max_y`.
+    pub fn square_origin<F>(
+        size: i64,
+        max_y: i64,
+        scanner: &mut BeamScanner<F>,
+    ) -> Option<(i64, i64)>
+    where
+        F: FnMut(i64, i64) -> bool,
+    {
+        for y in 0..max_y {
+            let (left, right) = match scanner.row(y) {
+                None => continue,
+                Some(edges) => edges,
+            };
+            let x = right - size + 1;
+            if x >= left {
+                if let Some((bottom_left, _)) = scanner.row(y + size - 1) {
+                    if bottom_left <= x {
+                        return Some((x, y));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The beam predicate as a formula in x and y, simplified on the assumption that both are
+    /// positive.
+    fn beam_formula(input: &[i64]) -> Result<Ast, AocError> {
+        let formula = get_output(input)?.simplify(
+            &List::new()
+                .prepend(Condition::LessThan(
+                    Box::new(Ast::Zero),
+                    Box::new(Ast::Variable('y')),
+                ))
+                .prepend(Condition::LessThan(
+                    Box::new(Ast::Zero),
+                    Box::new(Ast::Variable('x')),
+                )),
+        );
+        debug!("beam formula: {formula}");
+        Ok(formula)
+    }
+
+    fn formula_in_beam(formula: &Ast, x: i64, y: i64) -> bool {
+        x > 0 && y > 0 && formula.eval(&mut |v| if v == 'x' { Some(x) } else { Some(y) }) == Ok(1)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Square {
+        pub x: i64,
+        pub y: i64,
+        pub size: i64,
+    }
+
+    impl Square {
+        pub fn contains(&self, x: i64, y: i64) -> bool {
+            self.x <= x && x < self.x + self.size && self.y <= y && y < self.y + self.size
+        }
+    }
+
+    /// The first `size`-by-`size` square which fits in the beam, if there is one within
+    /// 100,000 rows of the emitter.
+    pub fn find_square(input: &[i64], size: i64) -> Result<Option<Square>, AocError> {
+        let formula = beam_formula(input)?;
+        let mut scanner = BeamScanner::new(|x, y| formula_in_beam(&formula, x, y));
+        Ok(square_origin(size, 100_000, &mut scanner).map(|(x, y)| Square { x, y, size }))
+    }
+
+    pub fn part_2(input: &[i64]) -> Result<i64, AocError> {
+        let square =
+            find_square(input, 100)?.ok_or(AocError::NoAnswer("no square fits in the beam"))?;
+        Ok(square.x * 10000 + square.y)
+    }
+
+    /// Draws the beam over the given region, in the puzzle's notation: '#' for the beam, '.' for
+    /// empty space, and 'O' for the square if one is supplied.
+    pub fn render_beam<F>(
+        mut in_beam: F,
+        xs: Range<i64>,
+        ys: Range<i64>,
+        square: Option<Square>,
+    ) -> String
+    where
+        F: FnMut(i64, i64) -> bool,
+    {
+        let mut result = String::new();
+        for y in ys {
+            for x in xs.clone() {
+                result.push(if square.is_some_and(|s| s.contains(x, y)) {
+                    'O'
+                } else if in_beam(x, y) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    /// Draws the drone's beam over the given region, overlaid with the first square of the given
+    /// size which fits in the beam.
+    pub fn render(
+        input: &[i64],
+        xs: Range<i64>,
+        ys: Range<i64>,
+        size: i64,
+    ) -> Result<String, AocError> {
+        let formula = beam_formula(input)?;
+        let square = find_square(input, size)?;
+        Ok(render_beam(
+            |x, y| formula_in_beam(&formula, x, y),
+            xs,
+            ys,
+            square,
+        ))
+    }
+
+    #[cfg(feature = "image")]
+    #[derive(Error, Debug)]
+    pub enum ExportError {
+        #[error(transparent)]
+        Drone(#[from] AocError),
+        #[error(transparent)]
+        Export(#[from] viz::ExportError),
+    }
+
+    /// Each point of the region becomes a `scale`-by-`scale` block of pixels: the beam is white,
+    /// empty space black, and the square red.
+    #[cfg(feature = "image")]
+    pub fn beam_image<F>(
+        mut in_beam: F,
+        xs: Range<i64>,
+        ys: Range<i64>,
+        square: Option<Square>,
+        scale: u32,
+    ) -> RgbaImage
+    where
+        F: FnMut(i64, i64) -> bool,
+    {
+        let width = (xs.end - xs.start).max(0) as usize;
+        let height = (ys.end - ys.start).max(0) as usize;
+        viz::draw(height, width, scale, |row, col| {
+            let (x, y) = (xs.start + col as i64, ys.start + row as i64);
+            if square.is_some_and(|s| s.contains(x, y)) {
+                RED
+            } else if in_beam(x, y) {
+                WHITE
+            } else {
+                BLACK
+            }
+        })
+    }
+
+    /// The beam over the region as the program describes it; see `beam_image`.
+    #[cfg(feature = "image")]
+    pub fn scan_image(
+        input: &[i64],
+        xs: Range<i64>,
+        ys: Range<i64>,
+        square: Option<Square>,
+        scale: u32,
+    ) -> Result<RgbaImage, AocError> {
+        let formula = beam_formula(input)?;
+        Ok(beam_image(
+            |x, y| formula_in_beam(&formula, x, y),
+            xs,
+            ys,
+            square,
+            scale,
+        ))
+    }
+
+    #[cfg(feature = "image")]
+    pub fn to_png<P>(
+        input: &[i64],
+        xs: Range<i64>,
+        ys: Range<i64>,
+        size: i64,
+        scale: u32,
+        path: P,
+    ) -> Result<(), ExportError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let square = find_square(input, size)?;
+        let image = scan_image(input, xs, ys, square, scale)?;
+        Ok(viz::save_png(&image, path)?)
+    }
+
+    pub struct Day19;
+
+    impl Solution for Day19 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_19::*;
+    use solution::solution::AocError;
+
+    #[test]
+    fn test_day_19() {
+        let Some(input_str) = loader::loader::load(19) else {
+            return;
+        };
+        let input = input(&input_str).unwrap();
+        // The answers themselves are in answers.toml.
+        assert_eq!(part_1_analytic(&input).unwrap(), part_1(&input).unwrap());
+    }
+
+    fn brute_force_square<F>(size: i64, limit: i64, in_beam: F) -> Option<(i64, i64)>
+    where
+        F: Fn(i64, i64) -> bool,
+    {
+        for y in 0..limit {
+            for x in 0..limit {
+                if in_beam(x, y)
+                    && in_beam(x + size - 1, y)
+                    && in_beam(x, y + size - 1)
+                    && in_beam(x + size - 1, y + size - 1)
+                {
+                    return Some((x, y));
+                }
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_query() {
+        // Reads x and y, and outputs whether x < y.
+        let program = [3, 100, 3, 101, 7, 100, 101, 102, 4, 102, 99];
+        assert!(query(&program, 3, 4).unwrap());
+        assert!(!query(&program, 4, 4).unwrap());
+        // Coordinates beyond the range of an i32 are fine.
+        assert!(query(&program, 3_000_000_000, 3_000_000_001).unwrap());
+        assert!(!query(&program, 3_000_000_001, 3_000_000_000).unwrap());
+    }
+
+    #[test]
+    fn test_beam_everywhere() {
+        // Says every point is in the beam. The scanner only looks as far as a slope of 10, so
+        // row y is counted out to x = 10 * (y + 1).
+        let program = [104, 1, 99];
+        assert_eq!(part_1(&program).unwrap(), 11 + 21 + 31 + 41 + 46 * 50);
+    }
+
+    #[test]
+    fn test_part_1_analytic() {
+        // Reads x and y, and outputs whether x < y.
+        let program = [3, 100, 3, 101, 7, 100, 101, 102, 4, 102, 99];
+        let brute_force = (0..50)
+            .flat_map(|y| (0..50).map(move |x| (x, y)))
+            .filter(|&(x, y)| query(&program, x, y).unwrap())
+            .count() as u32;
+        assert_eq!(brute_force, 49 * 50 / 2);
+        assert_eq!(part_1_analytic(&program).unwrap(), brute_force);
+
+        assert!(matches!(
+            part_1_analytic(&[3, 100, 99]),
+            Err(AocError::Terminated(_))
+        ));
+        assert!(matches!(
+            part_1_analytic(&[104, 1, 99]),
+            Err(AocError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_scanner() {
+        // A beam between edges of slope 3/4 and 1, which is empty in rows 1 to 3.
+        let beam = |x: i64, y: i64| (x, y) == (0, 0) || (4 * x >= 3 * y && x <= y && y > 3);
+        let brute_force = (0..50)
+            .flat_map(|y| (0..50).map(move |x| (x, y)))
+            .filter(|&(x, y)| beam(x, y))
+            .count() as u32;
+        let mut scanner = BeamScanner::new(beam);
+        assert_eq!(scanner.row(0), Some((0, 0)));
+        assert_eq!(scanner.row(2), None);
+        assert_eq!(scanner.row(8), Some((6, 8)));
+        assert_eq!(count_in_square(&mut scanner, 50), brute_force);
+        assert!(scanner.queries() < 300, "{} queries", scanner.queries());
+    }
+
+    #[test]
+    fn test_render_beam() {
+        let beam = |x: i64, y: i64| (x, y) == (0, 0) || (4 * x >= 3 * y && x <= y && y > 3);
+        let square = square_origin(2, 20, &mut BeamScanner::new(beam)).unwrap();
+        assert_eq!(square, (6, 7));
+        let square = Square {
+            x: square.0,
+            y: square.1,
+            size: 2,
+        };
+        let expected = "\
+#.........
+..........
+..........
+..........
+...##.....
+....##....
+.....##...
+......OO..
+......OO#.
+.......###
+";
+        assert_eq!(render_beam(beam, 0..10, 0..10, Some(square)), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_beam_image() {
+        use viz_image::viz_image::{BLACK, RED, WHITE};
+
+        let beam = |x: i64, y: i64| x == y;
+        let square = Square {
+            x: 2,
+            y: 1,
+            size: 1,
+        };
+        let image = beam_image(beam, 1..3, 0..2, Some(square), 2);
+        assert_eq!(image.dimensions(), (4, 4));
+        assert_eq!(*image.get_pixel(0, 0), BLACK);
+        assert_eq!(*image.get_pixel(1, 3), WHITE);
+        assert_eq!(*image.get_pixel(3, 3), RED);
+    }
+
+    #[test]
+    fn test_square_origin() {
+        let beam = |x: i64, y: i64| (x, y) == (0, 0) || (4 * x >= 3 * y && x <= y && y > 3);
+        for size in 1..=10 {
+            assert_eq!(
+                square_origin(size, 200, &mut BeamScanner::new(beam)),
+                brute_force_square(size, 200, beam),
+                "size {size}"
+            );
+        }
+
+        let steep = |x: i64, y: i64| 5 * x >= 2 * y && 3 * x <= 2 * y && y > 3;
+        for size in 1..=10 {
+            assert_eq!(
+                square_origin(size, 200, &mut BeamScanner::new(steep)),
+                brute_force_square(size, 200, steep),
+                "size {size}"
+            );
+        }
+
+        assert_eq!(square_origin(10, 5, &mut BeamScanner::new(steep)), None);
+    }
+}
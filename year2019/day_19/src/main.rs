@@ -0,0 +1,8 @@
+use day_19::day_19::Day19;
+use solution::solution::{run_main, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    run_main::<Day19>(&args)
+}
@@ -2,7 +2,10 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use day_2::day_2::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input(include_str!("../input.txt"));
+    let Some(input_str) = loader::loader::load(2) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
     c.bench_function("day 2 part 1", |b| {
         b.iter(|| {
             black_box(part_1(&input.iter().copied()).unwrap());
@@ -10,7 +13,7 @@ fn criterion_benchmark(c: &mut Criterion) {
     });
     c.bench_function("day 2 part 2", |b| {
         b.iter(|| {
-            black_box(part_2(&input, 19690720));
+            black_box(part_2(&input, 19690720).unwrap());
         })
     });
 }
@@ -0,0 +1,90 @@
+pub mod day_2 {
+    use intcode::intcode::{MachineExecutionError, MachineState, Patch, Program};
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, AocError, Solution};
+
+    pub fn input(s: &str) -> Result<Vec<usize>, ParseError> {
+        comma_separated(s)
+    }
+
+    /// The patches giving the program its noun and verb.
+    pub fn noun_verb(noun: usize, verb: usize) -> [Patch<usize>; 2] {
+        [
+            Patch {
+                addr: 1,
+                value: noun,
+                label: "noun",
+            },
+            Patch {
+                addr: 2,
+                value: verb,
+                label: "verb",
+            },
+        ]
+    }
+
+    pub fn part_1<T>(numbers: &T) -> Result<usize, MachineExecutionError>
+    where
+        T: IntoIterator<Item = usize>,
+        T: Clone,
+    {
+        let program = Program::new(numbers.clone()).with_patches(&noun_verb(12, 2));
+        let mut machine = MachineState::from_program(&program);
+
+        machine.execute_to_end(&mut std::iter::empty())?;
+
+        let result = machine.read_mem_elt(0);
+        Ok(result)
+    }
+
+    pub fn part_2<T>(numbers: &T, target: usize) -> Result<usize, AocError>
+    where
+        T: IntoIterator<Item = usize>,
+        T: Clone,
+    {
+        let program = Program::new(numbers.clone());
+        let mut machine = MachineState::new();
+        let (noun, verb) = (0..=99)
+            .filter_map(|noun| {
+                (0..=99)
+                    .filter_map(|verb| {
+                        machine.reset_from_program(
+                            &program.clone().with_patches(&noun_verb(noun, verb)),
+                        );
+                        machine.execute_to_end(&mut std::iter::empty()).ok()?;
+                        // safety: on termination, program counter is on opcode 99,
+                        // so there is an element in the array
+                        if machine.read_mem_elt(0) == target {
+                            Some((noun, verb))
+                        } else {
+                            None
+                        }
+                    })
+                    .next()
+            })
+            .next()
+            .ok_or(AocError::NoAnswer("no noun and verb give the target"))?;
+        Ok(100 * noun + verb)
+    }
+
+    /// The output which part 2 searches for.
+    pub const PART_2_TARGET: usize = 19690720;
+
+    pub struct Day2;
+
+    impl Solution for Day2 {
+        type Parsed<'a> = Vec<usize>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed, PART_2_TARGET))
+        }
+    }
+}
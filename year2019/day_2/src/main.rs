@@ -0,0 +1,23 @@
+use day_2::day_2;
+use solution::solution::{read_input, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let input_str = read_input(&args)?;
+    let input = match day_2::input(&input_str) {
+        Ok(input) => input,
+        Err(e) => return Err(format!("Error while parsing input: {e}").into()),
+    };
+
+    println!("part 1 => {}", day_2::part_1(&input)?);
+
+    if args.len() <= 2 {
+        return Err("Required the second arg to be a target number".into());
+    }
+    let part_2_target: usize = args[2]
+        .parse()
+        .map_err(|e| format!("Could not parse target {} : {e}", args[2]))?;
+    println!("part 2 => {}", day_2::part_2(&input, part_2_target)?);
+    Ok(())
+}
@@ -0,0 +1,403 @@
+pub mod day_20 {
+    use std::collections::HashMap;
+
+    use graph::graph::bfs;
+    use parse::parse::ParseError;
+    use solution::solution::{solved, Answer, Solution};
+    use thiserror::Error;
+
+    /// Row and column in the input.
+    pub type Position = (usize, usize);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Portal {
+        /// The open tile on the far side.
+        pub to: (usize, usize),
+        /// Whether this end is on the outside edge of the donut; stepping through an outer portal
+        /// goes up a level in the recursive maze, and an inner one down a level.
+        pub outer: bool,
+    }
+
+    pub struct Maze {
+        open: Vec<Vec<bool>>,
+        portals: HashMap<(usize, usize), Portal>,
+        start: (usize, usize),
+        end: (usize, usize),
+    }
+
+    /// An error at the given row and column of the maze, counting from the first line which
+    /// isn't blank.
+    fn error_at(row: usize, col: usize, message: String) -> ParseError {
+        ParseError::Invalid {
+            line: row + 1,
+            column: col + 1,
+            message,
+        }
+    }
+
+    /// The open tile next to the only appearance of the label.
+    fn only_end(
+        labelled: &mut HashMap<[u8; 2], Vec<(Position, bool)>>,
+        label: &[u8; 2],
+        what: &'static str,
+    ) -> Result<Position, ParseError> {
+        match labelled.remove(label).as_deref() {
+            Some([(end, _)]) => Ok(*end),
+            ends => Err(ParseError::WrongCount {
+                what,
+                expected: 1,
+                actual: ends.map_or(0, <[_]>::len),
+            }),
+        }
+    }
+
+    /// Parses the maze. Leading whitespace matters here, so unlike the other days this does not
+    /// trim its input.
+    pub fn input(s: &str) -> Result<Maze, ParseError> {
+        let grid: Vec<Vec<u8>> = s
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.as_bytes().to_vec())
+            .collect();
+        if grid.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let height = grid.len();
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        let get = |row: usize, col: usize| grid.get(row).and_then(|r| r.get(col)).copied();
+
+        let open: Vec<Vec<bool>> = (0..height)
+            .map(|row| (0..width).map(|col| get(row, col) == Some(b'.')).collect())
+            .collect();
+        // Every open tile needs four neighbours, even if they are only labels or blank.
+        for (row, line) in open.iter().enumerate() {
+            for (col, &is_open) in line.iter().enumerate() {
+                if is_open && (row == 0 || col == 0 || row == height - 1 || col == width - 1) {
+                    return Err(error_at(
+                        row,
+                        col,
+                        "expected no open tiles on the edge of the input".to_string(),
+                    ));
+                }
+            }
+        }
+
+        // The outside edge of the donut, which is not the edge of the input if a side has no
+        // labels.
+        let tiles = || {
+            (0..height).flat_map(move |row| {
+                (0..width)
+                    .filter(move |&col| matches!(get(row, col), Some(b'.' | b'#')))
+                    .map(move |col| (row, col))
+            })
+        };
+        let top = tiles().map(|(row, _)| row).min().unwrap_or(0);
+        let bottom = tiles().map(|(row, _)| row).max().unwrap_or(0);
+        let left = tiles().map(|(_, col)| col).min().unwrap_or(0);
+        let right = tiles().map(|(_, col)| col).max().unwrap_or(0);
+
+        // Each open tile next to a label, keyed by the label.
+        let mut labelled: HashMap<[u8; 2], Vec<(Position, bool)>> = HashMap::new();
+        for (row, line) in open.iter().enumerate() {
+            for (col, &is_open) in line.iter().enumerate() {
+                if !is_open {
+                    continue;
+                }
+                // The two letters, in reading order, which might sit on each side of the tile.
+                let candidates = [
+                    (
+                        row.checked_sub(2).map(|r| (r, col)),
+                        row.checked_sub(1).map(|r| (r, col)),
+                    ),
+                    (Some((row + 1, col)), Some((row + 2, col))),
+                    (
+                        col.checked_sub(2).map(|c| (row, c)),
+                        col.checked_sub(1).map(|c| (row, c)),
+                    ),
+                    (Some((row, col + 1)), Some((row, col + 2))),
+                ];
+                for (first, second) in candidates {
+                    let letter = |pos: Option<(usize, usize)>| {
+                        pos.and_then(|(r, c)| get(r, c))
+                            .filter(|c| c.is_ascii_uppercase())
+                    };
+                    if let (Some(a), Some(b)) = (letter(first), letter(second)) {
+                        let outer = row == top || row == bottom || col == left || col == right;
+                        labelled
+                            .entry([a, b])
+                            .or_default()
+                            .push(((row, col), outer));
+                    }
+                }
+            }
+        }
+
+        let start = only_end(&mut labelled, b"AA", "entrances labelled AA")?;
+        let end = only_end(&mut labelled, b"ZZ", "exits labelled ZZ")?;
+        let mut portals = HashMap::new();
+        for (label, ends) in labelled {
+            let [(a, a_outer), (b, b_outer)] = ends[..] else {
+                let ((row, col), _) = ends[0];
+                return Err(error_at(
+                    row,
+                    col,
+                    format!(
+                        "portal {} has {} ends, but should have 2",
+                        String::from_utf8_lossy(&label),
+                        ends.len()
+                    ),
+                ));
+            };
+            portals.insert(
+                a,
+                Portal {
+                    to: b,
+                    outer: a_outer,
+                },
+            );
+            portals.insert(
+                b,
+                Portal {
+                    to: a,
+                    outer: b_outer,
+                },
+            );
+        }
+
+        Ok(Maze {
+            open,
+            portals,
+            start,
+            end,
+        })
+    }
+
+    impl Maze {
+        pub fn portals(&self) -> &HashMap<(usize, usize), Portal> {
+            &self.portals
+        }
+
+        /// The tiles one step away, with the change in level each step makes.
+        fn neighbours(&self, (row, col): (usize, usize)) -> Vec<((usize, usize), i32)> {
+            let mut result: Vec<((usize, usize), i32)> = [
+                (row - 1, col),
+                (row + 1, col),
+                (row, col - 1),
+                (row, col + 1),
+            ]
+            .into_iter()
+            .filter(|&(r, c)| self.open[r][c])
+            .map(|pos| (pos, 0))
+            .collect();
+            if let Some(portal) = self.portals.get(&(row, col)) {
+                result.push((portal.to, if portal.outer { -1 } else { 1 }));
+            }
+            result
+        }
+
+        /// Breadth-first search from AA to ZZ. In the recursive maze, the levels go down without
+        /// limit, so the search gives up below as many levels as there are portal ends. That's
+        /// only a heuristic: nothing rules out a maze whose shortest route goes deeper, so if the
+        /// search found no route but did reach the cap, it says it gave up rather than that
+        /// there's no route.
+        pub fn shortest_path(&self, recursive: bool) -> Result<usize, RouteError> {
+            let max_level = self.portals.len() as i32;
+            let mut capped = false;
+            let path = bfs(
+                (self.start, 0),
+                |&(pos, level)| {
+                    let mut result = vec![];
+                    for (next, change) in self.neighbours(pos) {
+                        let level = if recursive { level + change } else { 0 };
+                        if level > max_level {
+                            capped = true;
+                        } else if level >= 0 {
+                            result.push((next, level));
+                        }
+                    }
+                    result
+                },
+                |&node| node == (self.end, 0),
+            );
+            match path {
+                Some(path) => Ok(path.len() - 1),
+                None if capped => Err(RouteError::GaveUp(max_level)),
+                None => Err(RouteError::NoRoute),
+            }
+        }
+    }
+
+    #[derive(Error, Debug, PartialEq, Eq)]
+    pub enum RouteError {
+        #[error("no route from AA to ZZ")]
+        NoRoute,
+        #[error("found no route from AA to ZZ, having given up below level {0}")]
+        GaveUp(i32),
+    }
+
+    pub fn part_1(maze: &Maze) -> Result<usize, RouteError> {
+        maze.shortest_path(false)
+    }
+
+    pub fn part_2(maze: &Maze) -> Result<usize, RouteError> {
+        maze.shortest_path(true)
+    }
+
+    pub struct Day20;
+
+    impl Solution for Day20 {
+        type Parsed<'a> = Maze;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_20::*;
+    use parse::parse::ParseError;
+
+    const EXAMPLE_1: &str = include_str!("../examples/example-1.txt");
+
+    #[test]
+    fn test_parse() {
+        let maze = input(EXAMPLE_1).unwrap();
+        assert_eq!(maze.portals().len(), 6);
+        // BC goes from the inner edge at the top to the outer edge on the left.
+        assert_eq!(
+            maze.portals()[&(6, 9)],
+            Portal {
+                to: (8, 2),
+                outer: false
+            }
+        );
+        assert_eq!(
+            maze.portals()[&(8, 2)],
+            Portal {
+                to: (6, 9),
+                outer: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_levels() {
+        // From AA, the only way on is the outer end of BC, which is a wall at the top level.
+        let outer_only = input(
+            "
+    A
+    A
+  ##.########
+BC...########
+  ###########
+  ###     ###
+  ###     ###
+  ###     ###
+  ###  B  ###
+  ###  C  ###
+  #####.#####
+  #####..####
+  ######.####
+        Z
+        Z
+",
+        )
+        .unwrap();
+        assert_eq!(part_1(&outer_only), Ok(7));
+        assert_eq!(part_2(&outer_only), Err(RouteError::NoRoute));
+
+        // Down through the inner end of BC, then back up through the outer end of DE.
+        let down_and_up = input(
+            "
+    A
+    A
+  ##.########
+  ##...######
+  ####.######
+  ### B   ###
+  ### C   ###
+BC.##     ###
+  .##  D  ###
+DE.##  E  ###
+  #####.#####
+  #####..####
+  ######.####
+        Z
+        Z
+",
+        )
+        .unwrap();
+        assert_eq!(
+            down_and_up.portals()[&(4, 6)],
+            Portal {
+                to: (7, 2),
+                outer: false
+            }
+        );
+        assert_eq!(part_1(&down_and_up), Ok(11));
+        assert_eq!(part_2(&down_and_up), Ok(11));
+
+        // Down through the inner end of BC over and over, never getting back up to ZZ.
+        let only_down = input(
+            "
+    A
+    A
+  ##.########
+BC.....######
+  ####.######
+  ### B   ###
+  ### C   ###
+  ###     ###
+  ###     ###
+  ###     ###
+  #####.#####
+  #####.#####
+  #####.#####
+       Z
+       Z
+",
+        )
+        .unwrap();
+        assert_eq!(part_1(&only_down), Err(RouteError::NoRoute));
+        assert_eq!(part_2(&only_down), Err(RouteError::GaveUp(2)));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        assert_eq!(input("\n \n").err(), Some(ParseError::Empty));
+        assert_eq!(
+            input("###\n#.#\n###\n").err(),
+            Some(ParseError::WrongCount {
+                what: "entrances labelled AA",
+                expected: 1,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            input("   A\n   A\n  #.#\nBC..#\n  #.#\n   Z\n   Z\n").err(),
+            Some(ParseError::Invalid {
+                line: 4,
+                column: 3,
+                message: "portal BC has 1 ends, but should have 2".to_string()
+            })
+        );
+        assert_eq!(
+            input(".#\n##").err(),
+            Some(ParseError::Invalid {
+                line: 1,
+                column: 1,
+                message: "expected no open tiles on the edge of the input".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use day_20::day_20;
+use solution::solution::{read_input, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let input_str = read_input(&args)?;
+    let input = match day_20::input(&input_str) {
+        Ok(input) => input,
+        Err(e) => return Err(format!("Error while parsing input: {e}").into()),
+    };
+
+    for (part, steps) in [(1, day_20::part_1(&input)), (2, day_20::part_2(&input))] {
+        match steps {
+            Ok(steps) => println!("part {part} => {steps}"),
+            Err(e) => println!("part {part} => ({e})"),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,665 @@
+pub mod day_21 {
+    use std::cmp::Reverse;
+    use std::collections::HashSet;
+    use std::fmt::Display;
+
+    use intcode::intcode::{GasCosts, MachineExecutionError, MachineState};
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, Explainer, Solution, SolveError};
+    use thiserror::Error;
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    #[derive(Error, Debug)]
+    pub enum DroidError {
+        #[error("program output {0}, which is not an ASCII character")]
+        NotAscii(i64),
+        #[error("springscript may only have {MAX_INSTRUCTIONS} instructions, but got {0}")]
+        TooLong(usize),
+        #[error("sensor {0} can only be read when running, not walking")]
+        OutOfRange(Register),
+        #[error("the droid fell into the hull:\n{0}")]
+        Fell(String),
+        #[error("found no springscript program which crosses all {0} stretches of hull seen")]
+        NoProgram(usize),
+        #[error(transparent)]
+        Machine(#[from] MachineExecutionError),
+    }
+
+    /// The droid's memory only holds this many springscript instructions.
+    pub const MAX_INSTRUCTIONS: usize = 15;
+
+    /// The registers an instruction can read: the sensors A to I, which report whether there is
+    /// ground that many tiles ahead, and the two writable registers.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Register {
+        A,
+        B,
+        C,
+        D,
+        E,
+        F,
+        G,
+        H,
+        I,
+        T,
+        J,
+    }
+
+    /// The registers an instruction can write to.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Writable {
+        /// The temporary register.
+        T,
+        /// The jump register: the droid jumps if this is true at the end of the program.
+        J,
+    }
+
+    impl Register {
+        pub const SENSORS: [Register; 9] = [
+            Register::A,
+            Register::B,
+            Register::C,
+            Register::D,
+            Register::E,
+            Register::F,
+            Register::G,
+            Register::H,
+            Register::I,
+        ];
+
+        /// How many tiles ahead this sensor looks, if it is a sensor.
+        pub fn distance(self) -> Option<usize> {
+            Register::SENSORS
+                .iter()
+                .position(|&r| r == self)
+                .map(|i| i + 1)
+        }
+    }
+
+    impl From<Writable> for Register {
+        fn from(value: Writable) -> Self {
+            match value {
+                Writable::T => Register::T,
+                Writable::J => Register::J,
+            }
+        }
+    }
+
+    impl Display for Register {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{self:?}")
+        }
+    }
+
+    impl Display for Writable {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{self:?}")
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Instruction {
+        /// Sets the second register to whether both registers are true.
+        And(Register, Writable),
+        /// Sets the second register to whether either register is true.
+        Or(Register, Writable),
+        /// Sets the second register to whether the first is false.
+        Not(Register, Writable),
+    }
+
+    impl Instruction {
+        fn source(self) -> Register {
+            match self {
+                Instruction::And(r, _) | Instruction::Or(r, _) | Instruction::Not(r, _) => r,
+            }
+        }
+    }
+
+    impl Display for Instruction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Instruction::And(x, y) => write!(f, "AND {x} {y}"),
+                Instruction::Or(x, y) => write!(f, "OR {x} {y}"),
+                Instruction::Not(x, y) => write!(f, "NOT {x} {y}"),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Mode {
+        /// Sensors A to D only.
+        Walk,
+        /// All nine sensors.
+        Run,
+    }
+
+    impl Mode {
+        fn sensors(self) -> usize {
+            match self {
+                Mode::Walk => 4,
+                Mode::Run => 9,
+            }
+        }
+    }
+
+    /// A springscript program which fits in the droid's memory and only reads the sensors
+    /// available in its mode.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Program {
+        mode: Mode,
+        instructions: Vec<Instruction>,
+    }
+
+    impl Program {
+        pub fn new(mode: Mode, instructions: Vec<Instruction>) -> Result<Program, DroidError> {
+            if instructions.len() > MAX_INSTRUCTIONS {
+                return Err(DroidError::TooLong(instructions.len()));
+            }
+            if let Some(register) = instructions
+                .iter()
+                .map(|i| i.source())
+                .find(|r| r.distance().is_some_and(|d| d > mode.sensors()))
+            {
+                return Err(DroidError::OutOfRange(register));
+            }
+            Ok(Program { mode, instructions })
+        }
+
+        pub fn mode(&self) -> Mode {
+            self.mode
+        }
+
+        pub fn instructions(&self) -> &[Instruction] {
+            &self.instructions
+        }
+
+        /// Whether the droid jumps, given what its sensors read as a bitmask: bit `i` is set if
+        /// there is ground `i + 1` tiles ahead.
+        pub fn jumps(&self, sensors: u16) -> bool {
+            let mut registers = [false, false];
+            for instruction in self.instructions.iter() {
+                let read = |r: Register| match r {
+                    Register::T => registers[0],
+                    Register::J => registers[1],
+                    sensor => sensors >> (sensor.distance().unwrap() - 1) & 1 == 1,
+                };
+                let (value, target) = match *instruction {
+                    Instruction::And(x, y) => (read(x) && read(y.into()), y),
+                    Instruction::Or(x, y) => (read(x) || read(y.into()), y),
+                    Instruction::Not(x, y) => (!read(x), y),
+                };
+                registers[target as usize] = value;
+            }
+            registers[1]
+        }
+    }
+
+    impl Display for Program {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for instruction in self.instructions.iter() {
+                writeln!(f, "{instruction}")?;
+            }
+            match self.mode {
+                Mode::Walk => writeln!(f, "WALK"),
+                Mode::Run => writeln!(f, "RUN"),
+            }
+        }
+    }
+
+    /// Runs the springdroid with the given program, returning the hull damage it reports if it
+    /// makes it across.
+    pub fn run(input: &[i64], program: &Program) -> Result<i64, DroidError> {
+        run_on(
+            &mut MachineState::new_with_memory(&input.iter().copied()),
+            program,
+        )
+    }
+
+    /// As `run`, also giving the gas the intcode machine used under `costs`, as a measure of how
+    /// much simulating the program drove, whether or not the droid made it across.
+    pub fn run_metered(
+        input: &[i64],
+        program: &Program,
+        costs: GasCosts,
+    ) -> (Result<i64, DroidError>, u64) {
+        let mut machine = MachineState::new_with_memory(&input.iter().copied());
+        machine.meter(costs);
+        let result = run_on(&mut machine, program);
+        (result, machine.gas_used().unwrap_or(0))
+    }
+
+    fn run_on(machine: &mut MachineState<i64>, program: &Program) -> Result<i64, DroidError> {
+        machine.push_inputs(program.to_string().bytes().map(i64::from));
+        let output = machine.execute_collect_outputs()?;
+        if let Some(&damage) = output.last().filter(|&&d| d > 127) {
+            return Ok(damage);
+        }
+        let text: String = output
+            .iter()
+            .map(|&c| {
+                u8::try_from(c)
+                    .ok()
+                    .filter(u8::is_ascii)
+                    .map(char::from)
+                    .ok_or(DroidError::NotAscii(c))
+            })
+            .collect::<Result<_, _>>()?;
+        // The droid prints its prompts before the last moments of its fall; only the latter are
+        // interesting.
+        let rendering = match text.split_once("Didn't make it across:") {
+            Some((_, rendering)) => rendering,
+            None => &text,
+        };
+        Err(DroidError::Fell(rendering.trim_matches('\n').to_string()))
+    }
+
+    /// A stretch of hull, on whose first tile the droid starts. Everything beyond the end is
+    /// ground.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Hull {
+        ground: Vec<bool>,
+    }
+
+    impl Hull {
+        /// Parses a row like `#####.#..########`.
+        pub fn parse(row: &str) -> Hull {
+            Hull {
+                ground: row.trim().bytes().map(|c| c == b'#').collect(),
+            }
+        }
+
+        /// The hull the droid fell on, from the bottom row of the first frame of its fall.
+        pub fn from_rendering(rendering: &str) -> Option<Hull> {
+            let frame = rendering.split("\n\n").next()?;
+            frame.lines().last().map(Hull::parse)
+        }
+
+        fn is_ground(&self, pos: usize) -> bool {
+            self.ground.get(pos).copied().unwrap_or(true)
+        }
+
+        fn sensors(&self, pos: usize, mode: Mode) -> u16 {
+            (0..mode.sensors())
+                .filter(|i| self.is_ground(pos + i + 1))
+                .fold(0, |mask, i| mask | 1 << i)
+        }
+
+        /// How far the droid gets before falling, or the length of the hull if it gets across.
+        fn progress<F>(&self, mode: Mode, mut jump: F) -> usize
+        where
+            F: FnMut(u16) -> bool,
+        {
+            let mut pos = 0;
+            while pos < self.ground.len() {
+                if !self.is_ground(pos) {
+                    return pos;
+                }
+                pos += if jump(self.sensors(pos, mode)) { 4 } else { 1 };
+            }
+            self.ground.len()
+        }
+
+        pub fn crossed_by(&self, program: &Program) -> bool {
+            self.progress(program.mode, |s| program.jumps(s)) == self.ground.len()
+        }
+    }
+
+    /// A register's value in every situation the droid might be in, indexed by the sensors'
+    /// bitmask.
+    type Table = [u64; 8];
+
+    fn lookup(table: &Table, sensors: u16) -> bool {
+        table[sensors as usize / 64] >> (sensors % 64) & 1 == 1
+    }
+
+    /// Finds a program which crosses all the given hulls, by breadth-first search over
+    /// programs in order of length. Programs are only told apart by what they do in the
+    /// situations which actually come up on those hulls, so any program which behaves the same
+    /// as a shorter one there is pruned; and if more than `beam_width` programs of some length
+    /// remain, only the ones getting furthest across the hulls are kept.
+    pub fn synthesise(mode: Mode, hulls: &[Hull], beam_width: usize) -> Option<Program> {
+        let mut relevant: Table = [0; 8];
+        for hull in hulls {
+            for pos in 0..hull.ground.len() {
+                let sensors = hull.sensors(pos, mode);
+                relevant[sensors as usize / 64] |= 1 << (sensors % 64);
+            }
+        }
+        let mut sensor_tables = [[0; 8]; 9];
+        for (i, table) in sensor_tables.iter_mut().enumerate() {
+            for sensors in 0..512u16 {
+                if sensors >> i & 1 == 1 {
+                    table[sensors as usize / 64] |= 1 << (sensors % 64);
+                }
+            }
+        }
+
+        let mut instructions = vec![];
+        for &source in Register::SENSORS[..mode.sensors()]
+            .iter()
+            .chain(&[Register::T, Register::J])
+        {
+            for target in [Writable::T, Writable::J] {
+                instructions.push(Instruction::And(source, target));
+                instructions.push(Instruction::Or(source, target));
+                instructions.push(Instruction::Not(source, target));
+            }
+        }
+
+        let total: usize = hulls.iter().map(|h| h.ground.len()).sum();
+        let score = |jump: &Table| -> usize {
+            hulls
+                .iter()
+                .map(|h| h.progress(mode, |s| lookup(jump, s)))
+                .sum()
+        };
+
+        // Each entry is the T and J registers' tables, and the program which computed them.
+        let mut seen = HashSet::from([([0; 8], [0; 8])]);
+        let mut beam: Vec<(Table, Table, Vec<Instruction>)> = vec![([0; 8], [0; 8], vec![])];
+        for length in 0..=MAX_INSTRUCTIONS {
+            if let Some((_, _, found)) = beam.iter().find(|(_, j, _)| score(j) == total) {
+                return Program::new(mode, found.clone()).ok();
+            }
+            if length == MAX_INSTRUCTIONS {
+                break;
+            }
+
+            let mut next = vec![];
+            for (t, j, program) in beam.iter() {
+                for &instruction in instructions.iter() {
+                    let registers = [*t, *j];
+                    let read = |r: Register| match r {
+                        Register::T => registers[0],
+                        Register::J => registers[1],
+                        sensor => sensor_tables[sensor.distance().unwrap() - 1],
+                    };
+                    let (value, target): (Table, Writable) = match instruction {
+                        Instruction::And(x, y) => {
+                            let (x, y_value) = (read(x), read(y.into()));
+                            (std::array::from_fn(|i| x[i] & y_value[i]), y)
+                        }
+                        Instruction::Or(x, y) => {
+                            let (x, y_value) = (read(x), read(y.into()));
+                            (std::array::from_fn(|i| x[i] | y_value[i]), y)
+                        }
+                        Instruction::Not(x, y) => {
+                            let x = read(x);
+                            (std::array::from_fn(|i| !x[i]), y)
+                        }
+                    };
+                    let value = std::array::from_fn(|i| value[i] & relevant[i]);
+                    let (t, j) = match target {
+                        Writable::T => (value, *j),
+                        Writable::J => (*t, value),
+                    };
+                    if seen.insert((t, j)) {
+                        let mut program = program.clone();
+                        program.push(instruction);
+                        next.push((t, j, program));
+                    }
+                }
+            }
+            if next.len() > beam_width {
+                next.sort_by_cached_key(|(_, j, _)| Reverse(score(j)));
+                next.truncate(beam_width);
+            }
+            beam = next;
+        }
+        None
+    }
+
+    /// A program which got the droid across, and the hull damage it reported.
+    pub struct Found {
+        pub program: Program,
+        pub damage: i64,
+    }
+
+    /// The beam width which the puzzle answers run `search` with.
+    pub const BEAM_WIDTH: usize = 10_000;
+
+    /// Searches for a program which gets the droid across: propose a program which crosses
+    /// every stretch of hull seen so far, and if the droid falls while running it, add the hull
+    /// it fell on and search again.
+    pub fn search(input: &[i64], mode: Mode, beam_width: usize) -> Result<Found, DroidError> {
+        let mut hulls: Vec<Hull> = vec![];
+        loop {
+            let program =
+                synthesise(mode, &hulls, beam_width).ok_or(DroidError::NoProgram(hulls.len()))?;
+            match run(input, &program) {
+                Ok(damage) => return Ok(Found { program, damage }),
+                Err(DroidError::Fell(rendering)) => match Hull::from_rendering(&rendering) {
+                    // If the droid fell somewhere we thought the program would cross, our model
+                    // of the droid is wrong, and searching again would find the same program.
+                    Some(hull) if !hulls.contains(&hull) => hulls.push(hull),
+                    _ => return Err(DroidError::Fell(rendering)),
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub fn part_1(input: &[i64]) -> Result<i64, DroidError> {
+        Ok(search(input, Mode::Walk, BEAM_WIDTH)?.damage)
+    }
+
+    pub fn part_2(input: &[i64]) -> Result<i64, DroidError> {
+        Ok(search(input, Mode::Run, BEAM_WIDTH)?.damage)
+    }
+
+    pub struct Day21;
+
+    impl Solution for Day21 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+
+        /// Reports the programs which `search` finds for each part, with the hull damage each
+        /// reports, which is the answer.
+        fn explain(
+            parsed: &Self::Parsed<'_>,
+            explainer: &mut dyn Explainer,
+        ) -> Result<(), SolveError> {
+            for (name, mode) in [("walking", Mode::Walk), ("running", Mode::Run)] {
+                let found = search(parsed, mode, BEAM_WIDTH)
+                    .map_err(|e| SolveError::Failed(Box::new(e)))?;
+                explainer.note(&format!("{name} program"), &found.program);
+                explainer.note(&format!("{name} damage"), &found.damage);
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_21::*;
+    use intcode::intcode::GasCosts;
+    use solution::solution::{Note, Solution};
+
+    /// Jump if there is a hole in the next three tiles and ground to land on four tiles ahead.
+    fn walk_program() -> Program {
+        use Instruction::*;
+        Program::new(
+            Mode::Walk,
+            vec![
+                Not(Register::A, Writable::J),
+                Not(Register::B, Writable::T),
+                Or(Register::T, Writable::J),
+                Not(Register::C, Writable::T),
+                Or(Register::T, Writable::J),
+                And(Register::D, Writable::J),
+            ],
+        )
+        .unwrap()
+    }
+
+    /// As when walking, but also only jump if, having landed, the droid can either step on to E
+    /// or immediately jump again to H.
+    fn run_program() -> Program {
+        use Instruction::*;
+        Program::new(
+            Mode::Run,
+            vec![
+                Not(Register::A, Writable::J),
+                Not(Register::B, Writable::T),
+                Or(Register::T, Writable::J),
+                Not(Register::C, Writable::T),
+                Or(Register::T, Writable::J),
+                And(Register::D, Writable::J),
+                Not(Register::E, Writable::T),
+                Not(Register::T, Writable::T),
+                Or(Register::H, Writable::T),
+                And(Register::T, Writable::J),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_day_21() {
+        let Some(input_str) = loader::loader::load(21) else {
+            return;
+        };
+        let input = input(&input_str).unwrap();
+        // The answers themselves are in answers.toml.
+        let walking = search(&input, Mode::Walk, BEAM_WIDTH).unwrap();
+        assert_eq!(walking.damage, part_1(&input).unwrap());
+        assert_eq!(run(&input, &walking.program).unwrap(), walking.damage);
+        assert_eq!(run(&input, &walk_program()).unwrap(), walking.damage);
+        let running = search(&input, Mode::Run, BEAM_WIDTH).unwrap();
+        assert_eq!(running.damage, part_2(&input).unwrap());
+        assert_eq!(run(&input, &running.program).unwrap(), running.damage);
+        assert_eq!(run(&input, &run_program()).unwrap(), running.damage);
+
+        let mut notes: Vec<Note> = vec![];
+        Day21::explain(&input, &mut notes).unwrap();
+        let names: Vec<&str> = notes.iter().map(|note| note.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "walking program",
+                "walking damage",
+                "running program",
+                "running damage"
+            ]
+        );
+        assert_eq!(notes[1].value, part_1(&input).unwrap().to_string());
+        assert_eq!(notes[3].value, part_2(&input).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_program() {
+        assert_eq!(
+            walk_program().to_string(),
+            "NOT A J\nNOT B T\nOR T J\nNOT C T\nOR T J\nAND D J\nWALK\n"
+        );
+        assert!(run_program().to_string().ends_with("AND T J\nRUN\n"));
+
+        assert!(matches!(
+            Program::new(Mode::Walk, vec![Instruction::Not(Register::E, Writable::J)]),
+            Err(DroidError::OutOfRange(Register::E))
+        ));
+        assert!(Program::new(Mode::Run, vec![Instruction::Not(Register::E, Writable::J)]).is_ok());
+        assert!(matches!(
+            Program::new(
+                Mode::Run,
+                vec![Instruction::Or(Register::T, Writable::J); 16]
+            ),
+            Err(DroidError::TooLong(16))
+        ));
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(
+            run(&input("104,1000,99").unwrap(), &walk_program()).unwrap(),
+            1000
+        );
+
+        // Prints a prompt and then a frame of the fall, and halts.
+        let program: Vec<i64> = "Didn't make it across:\n\n@\n#.\n"
+            .bytes()
+            .flat_map(|c| [104, i64::from(c)])
+            .chain([99])
+            .collect();
+        match run(&program, &walk_program()) {
+            Err(DroidError::Fell(rendering)) => assert_eq!(rendering, "@\n#."),
+            other => panic!("expected a fall, got {other:?}"),
+        }
+        let (result, gas) = run_metered(&program, &walk_program(), GasCosts::default());
+        assert!(matches!(result, Err(DroidError::Fell(_))));
+        assert_eq!(gas as usize, program.len() / 2 + 1);
+    }
+
+    #[test]
+    fn test_hull() {
+        let rendering = "\
+.................
+.................
+@................
+#####.#..########
+
+.................
+.................
+.@...............
+#####.#..########";
+        let hull = Hull::from_rendering(rendering).unwrap();
+        assert_eq!(hull, Hull::parse("#####.#..########"));
+        assert!(hull.crossed_by(&walk_program()));
+        assert!(!hull.crossed_by(&Program::new(Mode::Walk, vec![]).unwrap()));
+
+        let program = walk_program();
+        // Hole two tiles ahead, with ground four tiles ahead.
+        assert!(program.jumps(0b1101));
+        assert!(!program.jumps(0b1111));
+        assert!(!program.jumps(0b0101));
+    }
+
+    #[test]
+    fn test_synthesise() {
+        assert_eq!(
+            synthesise(Mode::Walk, &[], 100),
+            Some(Program::new(Mode::Walk, vec![]).unwrap())
+        );
+
+        let hulls = [Hull::parse("####.####")];
+        let program = synthesise(Mode::Walk, &hulls, 100).unwrap();
+        assert_eq!(program.instructions().len(), 1);
+        assert!(hulls[0].crossed_by(&program));
+
+        let hulls = [
+            Hull::parse("#####.#..########"),
+            Hull::parse("#####...#########"),
+            Hull::parse("#####..#.########"),
+            Hull::parse("#####.##.########"),
+        ];
+        let program = synthesise(Mode::Walk, &hulls, 1000).unwrap();
+        assert!(hulls.iter().all(|h| h.crossed_by(&program)), "{program}");
+
+        // Jumping as soon as the walking program would means landing on the first island, from
+        // which the only way on is a jump into a hole.
+        let hulls = [
+            Hull::parse("#####.#.##.#.####"),
+            Hull::parse("#####...#########"),
+        ];
+        assert!(!hulls[0]
+            .crossed_by(&Program::new(Mode::Run, walk_program().instructions().to_vec()).unwrap()));
+        assert!(hulls.iter().all(|h| h.crossed_by(&run_program())));
+        let program = synthesise(Mode::Run, &hulls, 1000).unwrap();
+        assert!(hulls.iter().all(|h| h.crossed_by(&program)), "{program}");
+    }
+}
@@ -0,0 +1,323 @@
+pub mod day_22 {
+    use numtheory::numtheory::{add_mod, mod_inverse, mul_mod, residue};
+    use parse::parse::{error_at, lines_with, token, ParseError};
+    use solution::solution::{answer, found, Answer, Solution};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Technique {
+        NewStack,
+        Cut(i64),
+        Increment(i64),
+    }
+
+    pub fn input(s: &str) -> Result<Vec<Technique>, ParseError> {
+        lines_with(s, |l| {
+            if l == "deal into new stack" {
+                Ok(Technique::NewStack)
+            } else if let Some(n) = l.strip_prefix("cut ") {
+                Ok(Technique::Cut(token(s, n)?))
+            } else if let Some(n) = l.strip_prefix("deal with increment ") {
+                Ok(Technique::Increment(token(s, n)?))
+            } else {
+                Err(error_at(s, l, format!("unrecognised technique {l:?}")))
+            }
+        })
+    }
+
+    /// A shuffle of a deck of some size. Every technique sends the card at position `x` to
+    /// position `a * x + b`, modulo the size of the deck, and hence so does any sequence of them.
+    /// Products of positions can be far too big for an i128, so the arithmetic is all done
+    /// modulo the deck size.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Affine {
+        a: u128,
+        b: u128,
+        deck_size: u128,
+    }
+
+    impl Affine {
+        fn new(a: i128, b: i128, deck_size: i128) -> Affine {
+            assert!(deck_size > 0, "deck must have at least one card");
+            let deck_size = deck_size as u128;
+            Affine {
+                a: residue(a, deck_size),
+                b: residue(b, deck_size),
+                deck_size,
+            }
+        }
+
+        /// Leaves the deck as it is.
+        pub fn identity(deck_size: i128) -> Affine {
+            Affine::new(1, 0, deck_size)
+        }
+
+        pub fn of(technique: Technique, deck_size: i128) -> Affine {
+            match technique {
+                Technique::NewStack => Affine::new(-1, -1, deck_size),
+                Technique::Cut(n) => Affine::new(1, -i128::from(n), deck_size),
+                Technique::Increment(n) => Affine::new(i128::from(n), 0, deck_size),
+            }
+        }
+
+        /// The techniques, one after the other.
+        pub fn shuffle(techniques: &[Technique], deck_size: i128) -> Affine {
+            techniques
+                .iter()
+                .fold(Affine::identity(deck_size), |map, &t| {
+                    map.compose(&Affine::of(t, deck_size))
+                })
+        }
+
+        pub fn deck_size(&self) -> i128 {
+            self.deck_size as i128
+        }
+
+        /// This shuffle followed by the other, which must be of a deck of the same size.
+        pub fn compose(&self, other: &Affine) -> Affine {
+            assert_eq!(
+                self.deck_size, other.deck_size,
+                "cannot compose shuffles of decks of different sizes"
+            );
+            let n = self.deck_size;
+            Affine {
+                a: mul_mod(other.a, self.a, n),
+                b: add_mod(mul_mod(other.a, self.b, n), other.b, n),
+                deck_size: n,
+            }
+        }
+
+        /// This shuffle repeated `n` times.
+        pub fn pow(&self, n: u128) -> Affine {
+            // Exponentiation by squaring, as for numbers. The closed form
+            // a^n x + b (a^n - 1) / (a - 1) would need a - 1 to be invertible, which it need not be.
+            let mut result = Affine::identity(self.deck_size());
+            let mut square = *self;
+            let mut n = n;
+            while n > 0 {
+                if n % 2 == 1 {
+                    result = result.compose(&square);
+                }
+                square = square.compose(&square);
+                n /= 2;
+            }
+            result
+        }
+
+        /// The shuffle which undoes this one, if this one is a permutation; it is not if some
+        /// increment shares a factor with the deck size.
+        pub fn invert(&self) -> Option<Affine> {
+            let n = self.deck_size;
+            let a = mod_inverse(self.a as i128, self.deck_size())? as u128;
+            Some(Affine {
+                a,
+                b: (n - mul_mod(a, self.b, n)) % n,
+                deck_size: n,
+            })
+        }
+
+        /// The position the given card ends up at, starting from a factory-order deck.
+        pub fn apply(&self, card: i128) -> i128 {
+            let n = self.deck_size;
+            add_mod(mul_mod(self.a, residue(card, n), n), self.b, n) as i128
+        }
+
+        /// The card which ends up at the given position, starting from a factory-order deck, if
+        /// the shuffle is a permutation.
+        pub fn card_at(&self, position: i128) -> Option<i128> {
+            Some(self.invert()?.apply(position))
+        }
+    }
+
+    /// Where the given card ends up after shuffling a factory-order deck once.
+    pub fn position_after(techniques: &[Technique], deck_size: i128, card: i128) -> i128 {
+        Affine::shuffle(techniques, deck_size).apply(card)
+    }
+
+    /// Which card ends up at the given position after shuffling a factory-order deck the given
+    /// number of times, if the shuffle is a permutation.
+    pub fn card_after(
+        techniques: &[Technique],
+        deck_size: i128,
+        repeats: u128,
+        position: i128,
+    ) -> Option<i128> {
+        Affine::shuffle(techniques, deck_size)
+            .pow(repeats)
+            .card_at(position)
+    }
+
+    pub const PART_1_DECK_SIZE: i128 = 10007;
+    pub const PART_1_CARD: i128 = 2019;
+    pub const PART_2_DECK_SIZE: i128 = 119_315_717_514_047;
+    pub const PART_2_REPEATS: u128 = 101_741_582_076_661;
+    pub const PART_2_POSITION: i128 = 2020;
+
+    pub fn part_1(techniques: &[Technique]) -> i128 {
+        position_after(techniques, PART_1_DECK_SIZE, PART_1_CARD)
+    }
+
+    /// The deck size is prime, so this is only `None` if some increment is a multiple of it.
+    pub fn part_2(techniques: &[Technique]) -> Option<i128> {
+        card_after(
+            techniques,
+            PART_2_DECK_SIZE,
+            PART_2_REPEATS,
+            PART_2_POSITION,
+        )
+    }
+
+    pub struct Day22;
+
+    impl Solution for Day22 {
+        type Parsed<'a> = Vec<Technique>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            found(
+                part_2(parsed),
+                "an increment is a multiple of the deck size, so the shuffle can't be undone",
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_22::*;
+
+    /// The deck after shuffling a deck of ten cards once.
+    fn deck(techniques: &[Technique]) -> Vec<i128> {
+        let mut deck = vec![0; 10];
+        for card in 0..10 {
+            deck[position_after(techniques, 10, card) as usize] = card;
+        }
+        deck
+    }
+
+    #[test]
+    fn test_techniques() {
+        assert_eq!(
+            deck(&[Technique::NewStack]),
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(
+            deck(&[Technique::Cut(3)]),
+            vec![3, 4, 5, 6, 7, 8, 9, 0, 1, 2]
+        );
+        assert_eq!(
+            deck(&[Technique::Cut(-4)]),
+            vec![6, 7, 8, 9, 0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            deck(&[Technique::Increment(3)]),
+            vec![0, 7, 4, 1, 8, 5, 2, 9, 6, 3]
+        );
+    }
+
+    #[test]
+    fn part1_known() {
+        let examples = [
+            (
+                "deal with increment 7\ndeal into new stack\ndeal into new stack",
+                [0, 3, 6, 9, 2, 5, 8, 1, 4, 7],
+            ),
+            (
+                "cut 6\ndeal with increment 7\ndeal into new stack",
+                [3, 0, 7, 4, 1, 8, 5, 2, 9, 6],
+            ),
+            (
+                "deal with increment 7\ndeal with increment 9\ncut -2",
+                [6, 3, 0, 7, 4, 1, 8, 5, 2, 9],
+            ),
+            (
+                "deal into new stack
+cut -2
+deal with increment 7
+cut 8
+cut -4
+deal with increment 7
+cut 3
+deal with increment 9
+deal with increment 3
+cut -1",
+                [9, 2, 5, 8, 1, 4, 7, 0, 3, 6],
+            ),
+        ];
+        for (techniques, expected) in examples {
+            assert_eq!(deck(&input(techniques).unwrap()), expected);
+        }
+    }
+
+    #[test]
+    fn test_repeats() {
+        let techniques = input("cut 6\ndeal with increment 7\ndeal into new stack").unwrap();
+        let mut repeated: Vec<i128> = (0..10).collect();
+        for repeats in 0..25 {
+            for (position, &card) in repeated.iter().enumerate() {
+                assert_eq!(
+                    card_after(&techniques, 10, repeats, position as i128),
+                    Some(card)
+                );
+            }
+            let once = deck(&techniques);
+            repeated = once.iter().map(|&card| repeated[card as usize]).collect();
+        }
+
+        // Dealing with increment 4 into ten spaces puts two cards in some of them.
+        assert_eq!(card_after(&[Technique::Increment(4)], 10, 1, 0), None);
+        assert_eq!(part_2(&[Technique::Increment(0)]), None);
+        assert_eq!(
+            part_2(&[
+                Technique::Cut(3),
+                Technique::Increment(PART_2_DECK_SIZE as i64 * 2)
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            input("cut 6\n  shuffle").unwrap_err().to_string(),
+            "line 2, column 3: unrecognised technique \"shuffle\""
+        );
+        assert_eq!(
+            input("deal with increment -").unwrap_err().to_string(),
+            "line 1, column 21: could not parse \"-\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_big_deck() {
+        // A prime whose square is too big for a u128.
+        let deck_size: i128 = (1 << 89) - 1;
+        let double = Affine::of(Technique::Increment(2), deck_size);
+        assert_eq!(double.apply(1 << 88), 1);
+        assert_eq!(double.card_at(1), Some(1 << 88));
+        assert_eq!(
+            Affine::of(Technique::NewStack, deck_size).apply(5),
+            deck_size - 6
+        );
+
+        let techniques =
+            input("deal with increment 7\ndeal into new stack\ncut -2\ndeal with increment 9")
+                .unwrap();
+        let map = Affine::shuffle(&techniques, deck_size);
+        assert_eq!(map.deck_size(), deck_size);
+        assert_eq!(map.pow(3), map.compose(&map).compose(&map));
+        assert_eq!(
+            map.compose(&map.invert().unwrap()),
+            Affine::identity(deck_size)
+        );
+        let card = 123_456_789_012_345_678_901;
+        let position = card_after(&techniques, deck_size, 1 << 100, card).unwrap();
+        assert_eq!(map.pow(1 << 100).apply(position), card);
+    }
+}
@@ -0,0 +1,199 @@
+pub mod day_23 {
+    use intcode::intcode::{MachineExecutionError, MachineState};
+    use intcode::network::{Cluster, Schedule};
+    use log::debug;
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, Solution};
+    use thiserror::Error;
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    #[derive(Error, Debug)]
+    pub enum NetworkError {
+        #[error("computer {from} sent a packet to address {to}, which doesn't exist")]
+        BadAddress { from: usize, to: i64 },
+        #[error("the network went idle with no packet for the NAT to send")]
+        Idle,
+        #[error("every computer has terminated")]
+        AllTerminated,
+        #[error(transparent)]
+        Machine(#[from] MachineExecutionError),
+    }
+
+    const COMPUTERS: usize = 50;
+    const NAT: i64 = 255;
+
+    /// How the computers share their time for `part_1` and `part_2`. The puzzle doesn't say, and
+    /// when the network counts as idle depends on it, so it's fixed rather than left to chance.
+    pub const SCHEDULE: Schedule = Schedule::UntilBlocked;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Packet {
+        x: i64,
+        y: i64,
+    }
+
+    /// The computers, and the packets each is part-way through sending.
+    struct Network {
+        cluster: Cluster<i64>,
+        partial: Vec<Vec<i64>>,
+    }
+
+    impl Network {
+        /// Boots every computer, telling it its address.
+        fn new(program: &[i64], schedule: Schedule) -> Network {
+            let machine = MachineState::new_with_memory(&program.iter().copied());
+            let mut cluster = Cluster::new(vec![machine; COMPUTERS])
+                .with_default_input(-1)
+                .with_schedule(schedule);
+            for address in 0..COMPUTERS {
+                cluster.send(address, address as i64);
+            }
+            Network {
+                cluster,
+                partial: vec![vec![]; COMPUTERS],
+            }
+        }
+
+        /// Runs a round of the network, delivering every packet sent between computers, and
+        /// returning the packets sent to the NAT in order and whether the network was idle.
+        fn round(&mut self) -> Result<(Vec<Packet>, bool), NetworkError> {
+            if self.cluster.all_terminated() {
+                return Err(NetworkError::AllTerminated);
+            }
+            let round = self.cluster.round()?;
+            let mut to_nat = vec![];
+            for (from, value) in round.outputs {
+                let partial = &mut self.partial[from];
+                partial.push(value);
+                if partial.len() < 3 {
+                    continue;
+                }
+                let (to, packet) = (
+                    partial[0],
+                    Packet {
+                        x: partial[1],
+                        y: partial[2],
+                    },
+                );
+                partial.clear();
+                if to == NAT {
+                    to_nat.push(packet);
+                    continue;
+                }
+                match usize::try_from(to) {
+                    Ok(address) if address < COMPUTERS => {
+                        self.cluster.send(address, packet.x);
+                        self.cluster.send(address, packet.y);
+                    }
+                    _ => return Err(NetworkError::BadAddress { from, to }),
+                }
+            }
+            Ok((to_nat, round.idle))
+        }
+    }
+
+    pub fn part_1(program: &[i64]) -> Result<i64, NetworkError> {
+        part_1_scheduled(program, SCHEDULE)
+    }
+
+    /// As `part_1`, with the computers sharing their time according to `schedule`.
+    pub fn part_1_scheduled(program: &[i64], schedule: Schedule) -> Result<i64, NetworkError> {
+        let mut network = Network::new(program, schedule);
+        loop {
+            let (to_nat, idle) = network.round()?;
+            if let Some(packet) = to_nat.first() {
+                return Ok(packet.y);
+            }
+            if idle {
+                return Err(NetworkError::Idle);
+            }
+        }
+    }
+
+    /// The NAT remembers the last packet sent to it, and whenever the network is idle sends it
+    /// on to computer 0; we want the first Y value it sends twice in a row.
+    pub fn part_2(program: &[i64]) -> Result<i64, NetworkError> {
+        part_2_scheduled(program, SCHEDULE)
+    }
+
+    /// As `part_2`, with the computers sharing their time according to `schedule`.
+    pub fn part_2_scheduled(program: &[i64], schedule: Schedule) -> Result<i64, NetworkError> {
+        let mut network = Network::new(program, schedule);
+        let mut nat: Option<Packet> = None;
+        let mut last_sent: Option<i64> = None;
+        loop {
+            let (to_nat, idle) = network.round()?;
+            if let Some(&packet) = to_nat.last() {
+                nat = Some(packet);
+            }
+            if idle {
+                let packet = nat.ok_or(NetworkError::Idle)?;
+                debug!("network idle; NAT sends {packet:?} to computer 0");
+                if last_sent == Some(packet.y) {
+                    return Ok(packet.y);
+                }
+                network.cluster.send(0, packet.x);
+                network.cluster.send(0, packet.y);
+                last_sent = Some(packet.y);
+            }
+        }
+    }
+
+    pub struct Day23;
+
+    impl Solution for Day23 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_23::*;
+    use intcode::network::Schedule;
+
+    #[test]
+    fn part1_known() {
+        // Each computer sends (its address, its address + 1000) to the NAT and halts.
+        let program = input("3,100,104,255,4,100,1001,100,1000,101,4,101,99").unwrap();
+        assert_eq!(part_1(&program).unwrap(), 1000);
+    }
+
+    #[test]
+    fn part2_known() {
+        // Computer 0 reads forever; every other computer sends (7, 42) to the NAT once and then
+        // does the same.
+        let program =
+            input("3,100,1005,100,10,3,101,1105,1,5,104,255,104,7,104,42,1105,1,5").unwrap();
+        assert_eq!(part_2(&program).unwrap(), 42);
+        let schedule = Schedule::RoundRobin { quantum: 2 };
+        assert_eq!(part_2_scheduled(&program, schedule).unwrap(), 42);
+        assert!(matches!(
+            part_1(&input("3,100,1105,1,0").unwrap()),
+            Err(NetworkError::Idle)
+        ));
+    }
+
+    #[test]
+    fn test_bad_address() {
+        let program = input("104,50,104,0,104,0,99").unwrap();
+        assert!(matches!(
+            part_1(&program),
+            Err(NetworkError::BadAddress { from: 0, to: 50 })
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+//! Play the adventure by hand. Anything typed goes to the game, except for these commands:
+//!
+//! - `!save <name>` snapshots the game;
+//! - `!load <name>` goes back to a snapshot;
+//! - `!saves` lists the snapshots;
+//! - `!help` shows this list;
+//! - `!quit` quits, as does end of input.
+//!
+//! Usage: day_25_play <input file> [transcript file]
+//!
+//! The transcript records everything the game says and everything typed at it, including these
+//! commands.
+
+use day_25::day_25;
+use solution::solution::{read_input, Report};
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufRead, Write},
+};
+
+const HELP: &str = "Commands: !save <name>, !load <name>, !saves, !help, !quit\n";
+
+struct Session {
+    droid: day_25::Droid,
+    saves: BTreeMap<String, day_25::Droid>,
+    transcript: Option<File>,
+}
+
+impl Session {
+    /// Shows something to the player, and records it in the transcript.
+    fn say(&mut self, text: &str) -> io::Result<()> {
+        if let Some(transcript) = self.transcript.as_mut() {
+            transcript.write_all(text.as_bytes())?;
+        }
+        let mut out = io::stdout();
+        out.write_all(text.as_bytes())?;
+        out.flush()
+    }
+
+    /// Acts on a line the player typed, returning whether to carry on.
+    fn handle(&mut self, line: &str) -> Result<bool, Report> {
+        if let Some(transcript) = self.transcript.as_mut() {
+            writeln!(transcript, "{line}")?;
+        }
+        let mut words = line.split_whitespace();
+        let response = match (words.next(), words.next()) {
+            (Some("!quit"), _) => return Ok(false),
+            (Some("!help"), _) => HELP.to_string(),
+            (Some("!saves"), _) => {
+                let mut names: Vec<&str> = self.saves.keys().map(|s| s.as_str()).collect();
+                if names.is_empty() {
+                    names.push("(none)");
+                }
+                format!("{}\n", names.join("\n"))
+            }
+            (Some("!save"), Some(name)) => {
+                self.saves.insert(name.to_string(), self.droid.clone());
+                format!("Saved {name}.\n")
+            }
+            (Some("!load"), Some(name)) => match self.saves.get(name) {
+                Some(droid) => {
+                    self.droid = droid.clone();
+                    format!("Loaded {name}.\n")
+                }
+                None => format!("No save called {name}.\n"),
+            },
+            (Some(command), _) if command.starts_with('!') => HELP.to_string(),
+            _ if self.droid.is_finished() => {
+                "The game is over; !load a save or !quit.\n".to_string()
+            }
+            _ => self.droid.send(line.trim())?,
+        };
+        self.say(&response)?;
+        Ok(true)
+    }
+}
+
+fn main() -> Result<(), Report> {
+    let args: Vec<String> = std::env::args().collect();
+    let input_str = read_input(&args)?;
+    let transcript = match args.get(2) {
+        None => None,
+        Some(path) => Some(
+            File::create(path)
+                .map_err(|e| format!("Error while creating transcript {path} : {e}"))?,
+        ),
+    };
+    let input = day_25::input(&input_str)
+        .map_err(|e| Report::from(format!("Error while parsing input: {e}")))?;
+
+    let (droid, intro) = day_25::Droid::new(&input)?;
+    let mut session = Session {
+        droid,
+        saves: BTreeMap::new(),
+        transcript,
+    };
+    session.say(HELP)?;
+    session.say(&intro)?;
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        if !session.handle(&line)? {
+            break;
+        }
+    }
+    Ok(())
+}
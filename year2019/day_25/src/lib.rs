@@ -0,0 +1,408 @@
+pub mod day_25 {
+    use std::collections::HashSet;
+    use std::fmt::Display;
+
+    use intcode::ascii::{Console, ConsoleError};
+    use intcode::intcode::MachineState;
+    use log::debug;
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, Solution};
+    use thiserror::Error;
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    #[derive(Error, Debug)]
+    pub enum AdventureError {
+        #[error(transparent)]
+        Console(#[from] ConsoleError),
+        #[error("could not make sense of the game's output:\n{0}")]
+        Unparseable(String),
+        #[error("never found the pressure-sensitive floor")]
+        NoCheckpoint,
+        #[error("no combination of the items got past the pressure-sensitive floor")]
+        NoPassword,
+        #[error("found {0} items, but can only try combinations of up to {MAX_ITEMS}")]
+        TooManyItems(usize),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Direction {
+        North,
+        South,
+        East,
+        West,
+    }
+
+    impl Direction {
+        fn parse(s: &str) -> Option<Direction> {
+            match s {
+                "north" => Some(Direction::North),
+                "south" => Some(Direction::South),
+                "east" => Some(Direction::East),
+                "west" => Some(Direction::West),
+                _ => None,
+            }
+        }
+
+        pub fn opposite(self) -> Direction {
+            match self {
+                Direction::North => Direction::South,
+                Direction::South => Direction::North,
+                Direction::East => Direction::West,
+                Direction::West => Direction::East,
+            }
+        }
+    }
+
+    impl Display for Direction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Direction::North => write!(f, "north"),
+                Direction::South => write!(f, "south"),
+                Direction::East => write!(f, "east"),
+                Direction::West => write!(f, "west"),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Room {
+        pub name: String,
+        pub description: String,
+        pub doors: Vec<Direction>,
+        pub items: Vec<String>,
+    }
+
+    /// Parses the last room described in some output of the game. Being thrown out of a room
+    /// describes two, and we end up in the second.
+    pub fn parse_room(text: &str) -> Option<Room> {
+        let start = text.rfind("== ")?;
+        let mut lines = text[start..].lines();
+        let name = lines.next()?.strip_prefix("== ")?.strip_suffix(" ==")?;
+        let description = lines.next()?;
+
+        let mut doors = vec![];
+        let mut items = vec![];
+        let mut section = None;
+        for line in lines {
+            if let Some(entry) = line.strip_prefix("- ") {
+                match section {
+                    Some("Doors here lead:") => doors.push(Direction::parse(entry)?),
+                    Some("Items here:") => items.push(entry.to_string()),
+                    _ => return None,
+                }
+            } else if !line.is_empty() {
+                section = Some(line);
+            }
+        }
+
+        Some(Room {
+            name: name.to_string(),
+            description: description.to_string(),
+            doors,
+            items,
+        })
+    }
+
+    /// What the game prints when it wants a command.
+    const PROMPT: &str = "Command?";
+
+    /// The droid, as a snapshot of the machine running the game; clone it to try something
+    /// without committing to it.
+    #[derive(Clone)]
+    pub struct Droid {
+        console: Console,
+    }
+
+    impl Droid {
+        /// Starts the game, returning the droid and what the game first says.
+        pub fn new(program: &[i64]) -> Result<(Droid, String), AdventureError> {
+            let machine = MachineState::new_with_memory(&program.iter().copied());
+            let mut droid = Droid {
+                console: Console::new(machine, PROMPT),
+            };
+            let mut output = vec![];
+            droid.console.run(&mut output)?;
+            Ok((droid, String::from_utf8_lossy(&output).into_owned()))
+        }
+
+        pub fn is_finished(&self) -> bool {
+            !self.console.is_prompted()
+        }
+
+        /// Types a command, returning what the game says in response.
+        pub fn send(&mut self, command: &str) -> Result<String, AdventureError> {
+            let mut output = vec![];
+            self.console.send(command, &mut output)?;
+            Ok(String::from_utf8_lossy(&output).into_owned())
+        }
+    }
+
+    /// Items which end the game, or leave the droid unable to continue, if picked up.
+    const FATAL_ITEMS: [&str; 5] = [
+        "infinite loop",
+        "giant electromagnet",
+        "molten lava",
+        "photons",
+        "escape pod",
+    ];
+
+    const PRESSURE_FLOOR: &str = "Pressure-Sensitive Floor";
+
+    /// What the pressure-sensitive floor made of the droid.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Verdict {
+        TooHeavy,
+        TooLight,
+        Password(String),
+    }
+
+    pub fn parse_verdict(text: &str) -> Option<Verdict> {
+        // The alerts are about how everyone else compares to the droid.
+        if text.contains("are lighter than the detected value") {
+            return Some(Verdict::TooHeavy);
+        }
+        if text.contains("are heavier than the detected value") {
+            return Some(Verdict::TooLight);
+        }
+        let (_, after) = text.split_once("typing ")?;
+        let password: String = after.chars().take_while(char::is_ascii_digit).collect();
+        if password.is_empty() {
+            None
+        } else {
+            Some(Verdict::Password(password))
+        }
+    }
+
+    /// The most items `find_combination` can try combinations of, since it keeps them as the
+    /// bits of a `u32`.
+    pub const MAX_ITEMS: usize = 31;
+
+    /// Tries subsets of `count` items, as bitmasks, until one gets past the floor. Anything
+    /// containing a set which was too heavy is too heavy, and anything contained in a set which
+    /// was too light is too light, so those aren't tried.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is more than `MAX_ITEMS`.
+    pub fn find_combination<F, E>(count: usize, mut attempt: F) -> Result<Option<String>, E>
+    where
+        F: FnMut(u32) -> Result<Verdict, E>,
+    {
+        assert!(
+            count <= MAX_ITEMS,
+            "can't try combinations of {count} items, only up to {MAX_ITEMS}"
+        );
+        let mut too_heavy: Vec<u32> = vec![];
+        let mut too_light: Vec<u32> = vec![];
+        let mut subsets: Vec<u32> = (0..1 << count).collect();
+        subsets.sort_by_key(|s| s.count_ones());
+        for subset in subsets {
+            if too_heavy.iter().any(|&h| h & !subset == 0)
+                || too_light.iter().any(|&l| subset & !l == 0)
+            {
+                continue;
+            }
+            match attempt(subset)? {
+                Verdict::TooHeavy => too_heavy.push(subset),
+                Verdict::TooLight => too_light.push(subset),
+                Verdict::Password(password) => return Ok(Some(password)),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Everything learned by walking the ship.
+    struct Exploration {
+        visited: HashSet<String>,
+        items: Vec<String>,
+        path: Vec<Direction>,
+        /// The way to the security checkpoint, and the door from there to the floor.
+        checkpoint: Option<(Vec<Direction>, Direction)>,
+    }
+
+    /// Depth-first search of the ship from the given room, picking up every safe item, and
+    /// finishing back in that room.
+    fn explore(
+        droid: &mut Droid,
+        room: Room,
+        exploration: &mut Exploration,
+    ) -> Result<(), AdventureError> {
+        exploration.visited.insert(room.name);
+        for item in room.items {
+            if !FATAL_ITEMS.contains(&item.as_str()) {
+                droid.send(&format!("take {item}"))?;
+                exploration.items.push(item);
+            }
+        }
+        for door in room.doors {
+            let output = droid.send(&door.to_string())?;
+            if output.contains(&format!("== {PRESSURE_FLOOR} ==")) {
+                // We get thrown back out, so are still where we were.
+                exploration.checkpoint = Some((exploration.path.clone(), door));
+                continue;
+            }
+            let next = parse_room(&output).ok_or(AdventureError::Unparseable(output))?;
+            if !exploration.visited.contains(&next.name) {
+                exploration.path.push(door);
+                explore(droid, next, exploration)?;
+                exploration.path.pop();
+            }
+            droid.send(&door.opposite().to_string())?;
+        }
+        Ok(())
+    }
+
+    pub fn part_1(program: &[i64]) -> Result<String, AdventureError> {
+        let (mut droid, output) = Droid::new(program)?;
+        let start = parse_room(&output).ok_or(AdventureError::Unparseable(output))?;
+        let mut exploration = Exploration {
+            visited: HashSet::new(),
+            items: vec![],
+            path: vec![],
+            checkpoint: None,
+        };
+        explore(&mut droid, start, &mut exploration)?;
+
+        let (path, door) = exploration.checkpoint.ok_or(AdventureError::NoCheckpoint)?;
+        for step in path {
+            droid.send(&step.to_string())?;
+        }
+        let items = exploration.items;
+        if items.len() > MAX_ITEMS {
+            return Err(AdventureError::TooManyItems(items.len()));
+        }
+        debug!("carrying {items:?} to the checkpoint");
+        find_combination(items.len(), |subset| {
+            let mut attempt = droid.clone();
+            for (i, item) in items.iter().enumerate() {
+                if subset >> i & 1 == 0 {
+                    attempt.send(&format!("drop {item}"))?;
+                }
+            }
+            let output = attempt.send(&door.to_string())?;
+            let verdict = parse_verdict(&output).ok_or(AdventureError::Unparseable(output));
+            debug!("items {subset:0width$b}: {verdict:?}", width = items.len());
+            verdict
+        })?
+        .ok_or(AdventureError::NoPassword)
+    }
+
+    pub struct Day25;
+
+    impl Solution for Day25 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_25::*;
+
+    #[test]
+    fn test_parse_room() {
+        let output = "
+
+
+== Hull Breach ==
+You got in through a hole in the floor here. To keep your ship from also freezing, the hole has been sealed.
+
+Doors here lead:
+- north
+- east
+- west
+
+Items here:
+- mutex
+- molten lava
+
+Command?
+";
+        assert_eq!(
+            parse_room(output),
+            Some(Room {
+                name: "Hull Breach".to_string(),
+                description: "You got in through a hole in the floor here. To keep your ship from also freezing, the hole has been sealed.".to_string(),
+                doors: vec![Direction::North, Direction::East, Direction::West],
+                items: vec!["mutex".to_string(), "molten lava".to_string()],
+            })
+        );
+        assert_eq!(parse_room("You take the mutex.\n\nCommand?\n"), None);
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let ejected = "
+
+== Pressure-Sensitive Floor ==
+Analyzing...
+
+Doors here lead:
+- south
+
+A loud, robotic voice says \"Alert! Droids on this ship are heavier than the detected value!\" and you are ejected back to the checkpoint.
+
+
+
+== Security Checkpoint ==
+In the next room, a pressure-sensitive floor will verify your identity.
+
+Doors here lead:
+- north
+- south
+
+Command?
+";
+        assert_eq!(parse_verdict(ejected), Some(Verdict::TooLight));
+        assert_eq!(
+            parse_room(ejected).unwrap().name,
+            "Security Checkpoint".to_string()
+        );
+        assert_eq!(
+            parse_verdict("\"Oh, hello! You should be able to get in by typing 2424308736 on the keypad at the main airlock.\""),
+            Some(Verdict::Password("2424308736".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_find_combination() {
+        let weights = [3, 5, 9, 17, 33, 65, 129, 257];
+        let target = 3 + 17 + 129;
+        let mut attempts = 0;
+        let result = find_combination(weights.len(), |subset| {
+            attempts += 1;
+            let weight: i32 = (0..weights.len())
+                .filter(|i| subset >> i & 1 == 1)
+                .map(|i| weights[i])
+                .sum();
+            Ok::<_, ()>(match weight.cmp(&target) {
+                std::cmp::Ordering::Less => Verdict::TooLight,
+                std::cmp::Ordering::Greater => Verdict::TooHeavy,
+                std::cmp::Ordering::Equal => Verdict::Password(subset.to_string()),
+            })
+        });
+        assert_eq!(result, Ok(Some(0b100_1001.to_string())));
+        assert!(attempts < 1 << weights.len(), "{attempts}");
+
+        assert_eq!(
+            find_combination(2, |_| Ok::<_, ()>(Verdict::TooHeavy)),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can't try combinations of 32 items, only up to 31")]
+    fn test_find_combination_too_many() {
+        let _ = find_combination(32, |_| Ok::<_, ()>(Verdict::TooHeavy));
+    }
+}
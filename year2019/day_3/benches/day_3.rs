@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_3::day_3::{input, part_1, part_1_with, part_2, part_2_with, Backend};
+
+/// Two long wires in a small box: the first spirals inwards from a square of the given size,
+/// and the second zig-zags across it.
+fn spirals(size: u32) -> String {
+    let directions = ['R', 'U', 'L', 'D'];
+    let first: Vec<String> = (1..=size)
+        .rev()
+        .enumerate()
+        .map(|(i, distance)| format!("{}{}", directions[i % 4], distance))
+        .collect();
+    let second: Vec<String> = (0..size / 2)
+        .map(|i| {
+            if i % 2 == 0 {
+                format!("U{size},R2")
+            } else {
+                format!("D{size},R2")
+            }
+        })
+        .collect();
+    format!("{}\n{}", first.join(","), second.join(","))
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input_str) = loader::loader::load(3) else {
+        return;
+    };
+    let (wire1, wire2) = input(&input_str).unwrap();
+    c.bench_function("day 3 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&wire1, &wire2));
+        })
+    });
+    c.bench_function("day 3 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&wire1, &wire2));
+        })
+    });
+
+    let synthetic = spirals(1000);
+    let inputs = [
+        ("real", (wire1, wire2)),
+        ("synthetic", input(&synthetic).unwrap()),
+    ];
+    for (name, (wire1, wire2)) in inputs.iter() {
+        for (backend_name, backend) in [("sparse", Backend::Sparse), ("dense", Backend::Dense)] {
+            c.bench_function(&format!("day 3 part 1 {name} {backend_name}"), |b| {
+                b.iter(|| {
+                    black_box(part_1_with(wire1, wire2, backend));
+                })
+            });
+            c.bench_function(&format!("day 3 part 2 {name} {backend_name}"), |b| {
+                b.iter(|| {
+                    black_box(part_2_with(wire1, wire2, backend));
+                })
+            });
+        }
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,487 @@
+pub mod day_3 {
+    use std::collections::HashMap;
+    use std::fmt::{self, Display};
+
+    pub use geometry::geometry::{Direction, Point2};
+    use parse::parse::{error_at, lines_with, ParseError};
+    use solution::solution::{answer, Answer, Explainer, Solution, SolveError};
+
+    #[derive(Debug)]
+    pub struct Move {
+        dir: Direction,
+        distance: u32,
+    }
+
+    /// How far a wire may go from the origin along either axis, so that the width of its
+    /// bounds fits in an `i32`.
+    pub const MAX_REACH: u32 = i32::MAX as u32 / 2;
+
+    /// The most steps a wire may take, so that the steps to a crossing along both wires fit in
+    /// a `u32`.
+    pub const MAX_LENGTH: u32 = u32::MAX / 2;
+
+    impl Move {
+        /// Parses a move like "R8", which may not go further than `MAX_REACH`.
+        pub fn parse(s: &str) -> Option<Move> {
+            let mut chars = s.chars();
+            let dir = Direction::parse(chars.next()?)?;
+            let mut distance = 0u32;
+            for c in chars {
+                distance = distance.checked_mul(10)?.checked_add(c.to_digit(10)?)?;
+            }
+
+            (distance <= MAX_REACH).then_some(Move { dir, distance })
+        }
+    }
+
+    /// As the move appears in the input, like "R8".
+    impl Display for Move {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}{}", self.dir.initial(), self.distance)
+        }
+    }
+
+    /// Parses two wires, neither of which may go more than `MAX_REACH` from the origin or take
+    /// more than `MAX_LENGTH` steps.
+    pub fn input(s: &str) -> Result<(Vec<Move>, Vec<Move>), ParseError> {
+        let wires = lines_with(s, |l| {
+            let (mut x, mut y, mut length) = (0i64, 0i64, 0u64);
+            l.split(',')
+                .map(|m| {
+                    let mov = Move::parse(m)
+                        .ok_or_else(|| error_at(s, m, format!("invalid move {m:?}")))?;
+                    let offset = mov.dir.offset();
+                    x += i64::from(offset.x) * i64::from(mov.distance);
+                    y += i64::from(offset.y) * i64::from(mov.distance);
+                    length += u64::from(mov.distance);
+                    if x.abs().max(y.abs()) > i64::from(MAX_REACH) {
+                        return Err(error_at(
+                            s,
+                            m,
+                            format!(
+                                "move {m:?} takes the wire more than {MAX_REACH} from the origin"
+                            ),
+                        ));
+                    }
+                    if length > u64::from(MAX_LENGTH) {
+                        return Err(error_at(
+                            s,
+                            m,
+                            format!("move {m:?} makes the wire longer than {MAX_LENGTH}"),
+                        ));
+                    }
+                    Ok(mov)
+                })
+                .collect::<Result<Vec<Move>, _>>()
+        })?;
+        match <[Vec<Move>; 2]>::try_from(wires) {
+            Ok([first, second]) => Ok((first, second)),
+            Err(wires) => Err(ParseError::WrongCount {
+                what: "wires",
+                expected: 2,
+                actual: wires.len(),
+            }),
+        }
+    }
+
+    /// The points a wire passes through, with the number of steps it takes to first reach
+    /// each one.
+    trait WireMap {
+        /// Records that the wire reaches this point after this many steps, unless it got there
+        /// sooner.
+        fn visit(&mut self, pos: Point2, steps: u32);
+        fn steps(&self, pos: Point2) -> Option<u32>;
+        fn contains(&self, pos: Point2) -> bool {
+            self.steps(pos).is_some()
+        }
+    }
+
+    impl WireMap for HashMap<Point2, u32> {
+        fn visit(&mut self, pos: Point2, steps: u32) {
+            self.entry(pos).or_insert(steps);
+        }
+
+        fn steps(&self, pos: Point2) -> Option<u32> {
+            self.get(&pos).copied()
+        }
+    }
+
+    /// The smallest rectangle containing every point a wire passes through.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Bounds {
+        pub min_x: i32,
+        pub max_x: i32,
+        pub min_y: i32,
+        pub max_y: i32,
+    }
+
+    impl Bounds {
+        pub fn of(wire: &[Move]) -> Bounds {
+            let mut bounds = Bounds {
+                min_x: 0,
+                max_x: 0,
+                min_y: 0,
+                max_y: 0,
+            };
+            let mut pos = Point2::ORIGIN;
+            for mov in wire {
+                pos += mov.dir.offset() * mov.distance as i32;
+                bounds.min_x = bounds.min_x.min(pos.x);
+                bounds.max_x = bounds.max_x.max(pos.x);
+                bounds.min_y = bounds.min_y.min(pos.y);
+                bounds.max_y = bounds.max_y.max(pos.y);
+            }
+            bounds
+        }
+
+        pub fn area(&self) -> u64 {
+            (self.max_x - self.min_x + 1) as u64 * (self.max_y - self.min_y + 1) as u64
+        }
+    }
+
+    /// A wire map for a wire confined to some rectangle: a bitmap of the points it visits, and
+    /// the steps to reach each.
+    struct DenseWire {
+        bounds: Bounds,
+        width: usize,
+        visited: Vec<u64>,
+        steps: Vec<u32>,
+    }
+
+    impl DenseWire {
+        fn new(bounds: Bounds) -> DenseWire {
+            let width = (bounds.max_x - bounds.min_x + 1) as usize;
+            let cells = bounds.area() as usize;
+            DenseWire {
+                bounds,
+                width,
+                visited: vec![0; cells.div_ceil(64)],
+                steps: vec![0; cells],
+            }
+        }
+
+        fn index(&self, Point2 { x, y }: Point2) -> Option<usize> {
+            if x < self.bounds.min_x
+                || x > self.bounds.max_x
+                || y < self.bounds.min_y
+                || y > self.bounds.max_y
+            {
+                return None;
+            }
+            Some((y - self.bounds.min_y) as usize * self.width + (x - self.bounds.min_x) as usize)
+        }
+    }
+
+    impl WireMap for DenseWire {
+        fn visit(&mut self, pos: Point2, steps: u32) {
+            let i = self.index(pos).expect("wire went outside its own bounds");
+            if self.visited[i / 64] >> (i % 64) & 1 == 0 {
+                self.visited[i / 64] |= 1 << (i % 64);
+                self.steps[i] = steps;
+            }
+        }
+
+        fn steps(&self, pos: Point2) -> Option<u32> {
+            let i = self.index(pos)?;
+            if self.visited[i / 64] >> (i % 64) & 1 == 1 {
+                Some(self.steps[i])
+            } else {
+                None
+            }
+        }
+
+        fn contains(&self, pos: Point2) -> bool {
+            self.index(pos)
+                .is_some_and(|i| self.visited[i / 64] >> (i % 64) & 1 == 1)
+        }
+    }
+
+    /// How to store the first wire's path.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Backend {
+        Sparse,
+        Dense,
+        /// Dense if the first wire's bounding box has at most `DENSE_LIMIT` points, and sparse
+        /// otherwise.
+        Adaptive,
+    }
+
+    /// The most points the adaptive backend will allocate a dense grid for.
+    pub const DENSE_LIMIT: u64 = 1 << 24;
+
+    /// Calls `visit` with every point the wire passes through, except its far end, and the
+    /// number of steps to get there along the wire.
+    fn walk<F>(wire: &[Move], mut visit: F)
+    where
+        F: FnMut(Point2, u32),
+    {
+        let mut pos = Point2::ORIGIN;
+        let mut steps = 0;
+        for mov in wire {
+            let offset = mov.dir.offset();
+            for i in 0..mov.distance {
+                visit(pos + offset * i as i32, steps + i);
+            }
+            pos += offset * mov.distance as i32;
+            steps += mov.distance;
+        }
+    }
+
+    fn extend_wire<M: WireMap>(wire: &[Move], positions: &mut M) {
+        walk(wire, |pos, steps| positions.visit(pos, steps));
+    }
+
+    enum Layout {
+        Sparse(HashMap<Point2, u32>),
+        Dense(DenseWire),
+    }
+
+    /// Lays out the first wire with the chosen backend.
+    fn layout(wire: &[Move], backend: Backend) -> Layout {
+        let bounds = Bounds::of(wire);
+        let dense = match backend {
+            Backend::Sparse => false,
+            Backend::Dense => true,
+            Backend::Adaptive => bounds.area() <= DENSE_LIMIT,
+        };
+        if dense {
+            let mut positions = DenseWire::new(bounds);
+            extend_wire(wire, &mut positions);
+            Layout::Dense(positions)
+        } else {
+            let mut positions = HashMap::new();
+            extend_wire(wire, &mut positions);
+            Layout::Sparse(positions)
+        }
+    }
+
+    pub fn part_1(wire1: &[Move], wire2: &[Move]) -> u32 {
+        part_1_with(wire1, wire2, Backend::Adaptive)
+    }
+
+    pub fn part_1_with(wire1: &[Move], wire2: &[Move], backend: Backend) -> u32 {
+        closest_crossing_with(wire1, wire2, backend)
+            .map_or(u32::MAX, |pos| pos.manhattan(Point2::ORIGIN))
+    }
+
+    /// Where the wires cross nearest the origin (other than at it), if they cross at all.
+    pub fn closest_crossing(wire1: &[Move], wire2: &[Move]) -> Option<Point2> {
+        closest_crossing_with(wire1, wire2, Backend::Adaptive)
+    }
+
+    fn closest_crossing_with(wire1: &[Move], wire2: &[Move], backend: Backend) -> Option<Point2> {
+        match layout(wire1, backend) {
+            Layout::Sparse(positions) => closest_crossing_in(&positions, wire2),
+            Layout::Dense(positions) => closest_crossing_in(&positions, wire2),
+        }
+    }
+
+    fn closest_crossing_in<M: WireMap>(positions: &M, wire2: &[Move]) -> Option<Point2> {
+        let mut best: Option<(u32, Point2)> = None;
+        walk(wire2, |pos, _| {
+            if positions.contains(pos) {
+                let new_distance = pos.manhattan(Point2::ORIGIN);
+                if new_distance > 0 && best.is_none_or(|(distance, _)| new_distance < distance) {
+                    best = Some((new_distance, pos));
+                }
+            }
+        });
+        best.map(|(_, pos)| pos)
+    }
+
+    /// A point where the wires cross, and how many steps along each wire it is.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Crossing {
+        pub pos: Point2,
+        pub steps: (u32, u32),
+    }
+
+    pub fn part_2(wire1: &[Move], wire2: &[Move]) -> u32 {
+        part_2_with(wire1, wire2, Backend::Adaptive)
+    }
+
+    pub fn part_2_with(wire1: &[Move], wire2: &[Move], backend: Backend) -> u32 {
+        quickest_crossing_with(wire1, wire2, backend)
+            .map_or(u32::MAX, |crossing| crossing.steps.0 + crossing.steps.1)
+    }
+
+    /// The crossing which the wires reach in the fewest steps between them, if they cross at all.
+    pub fn quickest_crossing(wire1: &[Move], wire2: &[Move]) -> Option<Crossing> {
+        quickest_crossing_with(wire1, wire2, Backend::Adaptive)
+    }
+
+    fn quickest_crossing_with(
+        wire1: &[Move],
+        wire2: &[Move],
+        backend: Backend,
+    ) -> Option<Crossing> {
+        match layout(wire1, backend) {
+            Layout::Sparse(positions) => quickest_crossing_in(&positions, wire2),
+            Layout::Dense(positions) => quickest_crossing_in(&positions, wire2),
+        }
+    }
+
+    fn quickest_crossing_in<M: WireMap>(positions: &M, wire2: &[Move]) -> Option<Crossing> {
+        let mut best: Option<Crossing> = None;
+        walk(wire2, |pos, step| {
+            if let Some(s1) = positions.steps(pos) {
+                let new_steps = s1 + step;
+                if pos.x != 0
+                    && pos.y != 0
+                    && best.is_none_or(|c| new_steps < c.steps.0 + c.steps.1)
+                {
+                    best = Some(Crossing {
+                        pos,
+                        steps: (s1, step),
+                    });
+                }
+            }
+        });
+        best
+    }
+
+    pub struct Day3;
+
+    impl Solution for Day3 {
+        type Parsed<'a> = (Vec<Move>, Vec<Move>);
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_1(&parsed.0, &parsed.1))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_2(&parsed.0, &parsed.1))
+        }
+
+        fn explain(
+            (wire1, wire2): &Self::Parsed<'_>,
+            explainer: &mut dyn Explainer,
+        ) -> Result<(), SolveError> {
+            let Some(closest) = closest_crossing(wire1, wire2) else {
+                return Err(SolveError::NoAnswer("the wires never cross"));
+            };
+            explainer.note(
+                "closest crossing",
+                &format_args!(
+                    "{closest}, at distance {}",
+                    closest.manhattan(Point2::ORIGIN)
+                ),
+            );
+            if let Some(Crossing { pos, steps }) = quickest_crossing(wire1, wire2) {
+                explainer.note(
+                    "quickest crossing",
+                    &format_args!(
+                        "{pos}, {} steps along the first wire and {} along the second",
+                        steps.0, steps.1
+                    ),
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_3::*;
+    use proptest::prelude::*;
+    use strategies::strategies::{format_wire, wire};
+
+    #[test]
+    fn test_backends() {
+        let (wire1, wire2) = input(include_str!("../examples/larger-2.txt")).unwrap();
+        assert_eq!(
+            Bounds::of(&wire1),
+            Bounds {
+                min_x: 0,
+                max_x: 179,
+                min_y: -16,
+                max_y: 104
+            }
+        );
+        for backend in [Backend::Sparse, Backend::Dense, Backend::Adaptive] {
+            assert_eq!(part_1_with(&wire1, &wire2, backend), 135);
+            assert_eq!(part_2_with(&wire1, &wire2, backend), 410);
+        }
+    }
+
+    #[test]
+    fn test_crossings() {
+        let (wire1, wire2) = input(include_str!("../examples/small.txt")).unwrap();
+        assert_eq!(closest_crossing(&wire1, &wire2), Some(Point2::new(3, 3)));
+        assert_eq!(
+            quickest_crossing(&wire1, &wire2),
+            Some(Crossing {
+                pos: Point2::new(6, 5),
+                steps: (15, 15)
+            })
+        );
+
+        let (apart1, apart2) = input("R2,U2\nL2,D2").unwrap();
+        assert_eq!(closest_crossing(&apart1, &apart2), None);
+        assert_eq!(quickest_crossing(&apart1, &apart2), None);
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        assert_eq!(
+            input("R99999999999\nU1").unwrap_err().to_string(),
+            "line 1, column 1: invalid move \"R99999999999\""
+        );
+        assert_eq!(
+            input("U1\nR2147483647").unwrap_err().to_string(),
+            "line 2, column 1: invalid move \"R2147483647\""
+        );
+        assert_eq!(
+            input("L1073741823,L1\nU1").unwrap_err().to_string(),
+            "line 1, column 13: move \"L1\" takes the wire more than 1073741823 from the origin"
+        );
+        assert_eq!(
+            input("U1\nR1073741823,L1073741823,R1073741823")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 25: move \"R1073741823\" makes the wire longer than 2147483647"
+        );
+
+        let (wire1, _) = input("L1073741823,U1073741823\nU1").unwrap();
+        assert_eq!(
+            Bounds::of(&wire1),
+            Bounds {
+                min_x: -1073741823,
+                max_x: 0,
+                min_y: 0,
+                max_y: 1073741823
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn moves_roundtrip(wire1 in wire(10, 1000), wire2 in wire(10, 1000)) {
+            let text = format!("{}\n{}", format_wire(&wire1), format_wire(&wire2));
+            let (parsed1, parsed2) = input(&text).unwrap();
+            let format = |wire: &[Move]| {
+                wire.iter().map(Move::to_string).collect::<Vec<_>>().join(",")
+            };
+            prop_assert_eq!(format!("{}\n{}", format(&parsed1), format(&parsed2)), text);
+        }
+
+        #[test]
+        fn backends_agree(wire1 in wire(12, 20), wire2 in wire(12, 20)) {
+            let text = format!("{}\n{}", format_wire(&wire1), format_wire(&wire2));
+            let (wire1, wire2) = input(&text).unwrap();
+            let sparse = (
+                part_1_with(&wire1, &wire2, Backend::Sparse),
+                part_2_with(&wire1, &wire2, Backend::Sparse),
+            );
+            for backend in [Backend::Dense, Backend::Adaptive] {
+                prop_assert_eq!(part_1_with(&wire1, &wire2, backend), sparse.0);
+                prop_assert_eq!(part_2_with(&wire1, &wire2, backend), sparse.1);
+            }
+        }
+    }
+}
@@ -2,7 +2,10 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use day_4::day_4::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let (low, high) = input(include_str!("../input.txt"));
+    let Some(input_str) = loader::loader::load(4) else {
+        return;
+    };
+    let (low, high) = input(&input_str).unwrap();
     c.bench_function("day 4 part 1", |b| {
         b.iter(|| {
             black_box(part_1(low, high));
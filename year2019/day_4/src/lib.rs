@@ -1,9 +1,19 @@
 pub mod day_4 {
     use std::cmp::Ordering;
 
-    pub fn input(s: &str) -> (u32, u32) {
-        let mut inputs = s.trim().split('-').map(|l| str::parse(l).unwrap());
-        (inputs.next().unwrap(), inputs.next().unwrap())
+    use parse::parse::{error_at, token, ParseError};
+    use solution::solution::{answer, Answer, Solution};
+
+    /// Parses a range of the form `start-end`.
+    pub fn input(s: &str) -> Result<(u32, u32), ParseError> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let (start, end) = trimmed
+            .split_once('-')
+            .ok_or_else(|| error_at(s, trimmed, "expected a range of the form start-end"))?;
+        Ok((token(s, start)?, token(s, end)?))
     }
 
     pub(crate) fn is_valid(i: u32) -> bool {
@@ -72,11 +82,30 @@ pub mod day_4 {
             .filter(|&x| is_valid_2(x))
             .count() as u32
     }
+
+    pub struct Day4;
+
+    impl Solution for Day4 {
+        type Parsed<'a> = (u32, u32);
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_1(parsed.0, parsed.1))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_2(parsed.0, parsed.1))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::day_4::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_is_valid() {
@@ -92,11 +121,27 @@ mod tests {
         assert!(is_valid(111122));
     }
 
-    #[test]
-    #[cfg(not(feature = "no_real_inputs"))]
-    fn test_day_4() {
-        let (low, high) = input(include_str!("../input.txt"));
-        assert_eq!(part_1(low, high), 1855);
-        assert_eq!(part_2(low, high), 1253);
+    /// The lengths of the runs of equal digits in the number, if its digits never decrease.
+    fn runs(i: u32) -> Option<Vec<usize>> {
+        let digits = i.to_string().into_bytes();
+        if digits.windows(2).any(|pair| pair[0] > pair[1]) {
+            return None;
+        }
+        Some(digits.chunk_by(|a, b| a == b).map(<[u8]>::len).collect())
+    }
+
+    proptest! {
+        #[test]
+        fn validity_matches_runs(i in 100000u32..1000000) {
+            let runs = runs(i);
+            prop_assert_eq!(is_valid(i), runs.as_ref().is_some_and(|r| r.iter().any(|&l| l >= 2)));
+            prop_assert_eq!(is_valid_2(i), runs.is_some_and(|r| r.contains(&2)));
+            prop_assert!(!is_valid_2(i) || is_valid(i));
+        }
+
+        #[test]
+        fn part_2_counts_fewer(low in 123456u32..990000, width in 0u32..5000) {
+            prop_assert!(part_2(low, low + width) <= part_1(low, low + width));
+        }
     }
 }
@@ -2,7 +2,10 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use day_5::day_5::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input(include_str!("../input.txt"));
+    let Some(input_str) = loader::loader::load(5) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
     c.bench_function("day 5 part 1", |b| {
         b.iter(|| {
             black_box(part_1(&input.iter().copied()).unwrap());
@@ -0,0 +1,91 @@
+pub mod day_5 {
+    use intcode::intcode::MachineState;
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, AocError, Solution};
+
+    pub fn input(s: &str) -> Result<Vec<i32>, ParseError> {
+        comma_separated(s)
+    }
+
+    /// The diagnostic code, which is the last output; every test before it should output 0.
+    pub fn part_1<T>(numbers: &T) -> Result<i32, AocError>
+    where
+        T: IntoIterator<Item = i32>,
+        T: Clone,
+    {
+        let mut machine = MachineState::new_with_memory(numbers);
+        let outputs = machine.execute_to_end(&mut std::iter::once(1))?;
+        let mut outputs_iter = outputs.iter().rev();
+        let ans = *outputs_iter
+            .next()
+            .ok_or(AocError::Terminated("expecting a diagnostic code"))?;
+        for &output in outputs_iter {
+            if output != 0 {
+                return Err(AocError::UnexpectedOutput(
+                    output.into(),
+                    "expecting a successful test",
+                ));
+            }
+        }
+
+        Ok(ans)
+    }
+
+    pub fn part_2<T>(numbers: &T) -> Result<i32, AocError>
+    where
+        T: IntoIterator<Item = i32>,
+        T: Clone,
+    {
+        let mut machine = MachineState::new_with_memory(numbers);
+        let outputs = machine.execute_to_end(&mut std::iter::once(5))?;
+        if outputs.len() != 1 {
+            return Err(AocError::OutputCount {
+                expected: 1,
+                actual: outputs.len(),
+            });
+        }
+
+        Ok(outputs[0])
+    }
+
+    pub struct Day5;
+
+    impl Solution for Day5 {
+        type Parsed<'a> = Vec<i32>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_5::*;
+
+    #[test]
+    fn test_unexpected_outputs() {
+        use solution::solution::AocError;
+
+        assert!(matches!(
+            part_1(&[104, 1, 104, 2, 99]),
+            Err(AocError::UnexpectedOutput(1, _))
+        ));
+        assert!(matches!(part_1(&[99]), Err(AocError::Terminated(_))));
+        assert!(matches!(
+            part_2(&[99]),
+            Err(AocError::OutputCount {
+                expected: 1,
+                actual: 0
+            })
+        ));
+    }
+}
@@ -2,15 +2,18 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use day_6::day_6::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input(include_str!("../input.txt"));
+    let Some(input_str) = loader::loader::load(6) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
     c.bench_function("day 6 part 1", |b| {
         b.iter(|| {
-            black_box(part_1(&input));
+            black_box(part_1(&input).unwrap());
         })
     });
     c.bench_function("day 6 part 2", |b| {
         b.iter(|| {
-            black_box(part_2(&input));
+            black_box(part_2(&input).unwrap());
         })
     });
 }
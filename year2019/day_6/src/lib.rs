@@ -0,0 +1,208 @@
+pub mod day_6 {
+    use std::collections::{HashMap, HashSet};
+    use std::hash::Hash;
+
+    use graph::graph::bfs;
+    use parse::parse::{error_at, lines_with, ParseError};
+    use solution::solution::{solved, Answer, AocError, Explainer, Solution, SolveError};
+    use thiserror::Error;
+
+    pub struct Edge<T> {
+        source: T,
+        dest: T,
+    }
+
+    struct Tree<Label> {
+        arena: Vec<(Label, Vec<usize>)>,
+        lookup: HashMap<Label, usize>,
+        root: Label,
+    }
+
+    #[derive(Error, Debug)]
+    enum DagConstructionError {
+        #[error("more than one object orbits nothing")]
+        MultipleRoots,
+        #[error("the orbits go round in a cycle")]
+        Cycle,
+    }
+
+    impl<Label> Tree<Label> {
+        fn make(inputs: &[Edge<Label>]) -> Result<Tree<Label>, DagConstructionError>
+        where
+            Label: Copy + Eq + Hash,
+        {
+            let mut arena: Vec<(Label, Vec<usize>)> = Vec::with_capacity(inputs.len());
+            let mut lookup: HashMap<Label, usize> = HashMap::with_capacity(inputs.len());
+            let mut roots: HashSet<Label> = inputs
+                .iter()
+                .flat_map(|edge| [edge.source, edge.dest])
+                .collect();
+
+            for edge in inputs {
+                roots.remove(&edge.dest);
+                let source_index = *lookup.entry(edge.source).or_insert_with(|| {
+                    arena.push((edge.source, vec![]));
+                    arena.len() - 1
+                });
+                let dest_index = *lookup.entry(edge.dest).or_insert_with(|| {
+                    arena.push((edge.dest, vec![]));
+                    arena.len() - 1
+                });
+
+                let (_, ref mut entry) = &mut arena[source_index];
+                entry.push(dest_index);
+            }
+
+            if roots.len() > 1 {
+                return Err(DagConstructionError::MultipleRoots);
+            }
+
+            match roots.iter().next() {
+                None => Err(DagConstructionError::Cycle),
+                Some(root) => Ok(Tree {
+                    arena,
+                    lookup,
+                    root: *root,
+                }),
+            }
+        }
+
+        fn cata_inner<F, Ret>(self: &Tree<Label>, depth: u32, node: usize, f: &mut F) -> Ret
+        where
+            F: FnMut(u32, &Label, &[Ret]) -> Ret,
+            Label: Hash + Eq,
+        {
+            let (label, children) = &self.arena[node];
+            let child_results: Vec<_> = children
+                .iter()
+                .map(|child| self.cata_inner(depth + 1, *child, f))
+                .collect();
+            f(depth, label, &child_results)
+        }
+
+        /*
+         We give you the depth you're at, as well. The root is at depth 0.
+        */
+        fn cata<F, Ret>(self: &Tree<Label>, f: &mut F) -> Ret
+        where
+            F: FnMut(u32, &Label, &[Ret]) -> Ret,
+            Label: Hash + Eq,
+        {
+            let root = *self.lookup.get(&self.root).unwrap();
+            self.cata_inner(0, root, f)
+        }
+    }
+
+    pub fn input(s: &str) -> Result<Vec<Edge<&str>>, ParseError> {
+        lines_with(s, |l| {
+            let (source, dest) = l
+                .split_once(')')
+                .ok_or_else(|| error_at(s, l, "expected an orbit of the form A)B"))?;
+            Ok(Edge { source, dest })
+        })
+    }
+
+    pub fn part_1(input: &[Edge<&str>]) -> Result<u32, AocError> {
+        let dag = Tree::make(input).map_err(|e| AocError::InvalidInput(e.to_string()))?;
+        Ok(dag.cata(&mut |depth, _node, children| {
+            children.iter().copied().map(|x| x + depth + 1).sum::<u32>()
+        }))
+    }
+
+    /// The objects passed through on the fewest orbital transfers from the object YOU orbit to
+    /// the object SAN orbits, both included. Transfers go between an object and anything
+    /// orbiting it, in either direction.
+    pub fn transfer_path<'a>(input: &[Edge<&'a str>]) -> Result<Vec<&'a str>, AocError> {
+        let mut adjacent: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in input {
+            adjacent.entry(edge.source).or_default().push(edge.dest);
+            adjacent.entry(edge.dest).or_default().push(edge.source);
+        }
+        let path = bfs(
+            "YOU",
+            |node| adjacent.get(node).cloned().unwrap_or_default(),
+            |&node| node == "SAN",
+        )
+        .ok_or(AocError::NoAnswer("there is no path from YOU to SAN"))?;
+        // The path includes YOU and SAN themselves, which aren't transferred between.
+        Ok(path[1..path.len() - 1].to_vec())
+    }
+
+    /// The fewest orbital transfers to get from the object YOU orbit to the object SAN orbits.
+    pub fn part_2(input: &[Edge<&str>]) -> Result<u32, AocError> {
+        Ok(transfer_path(input)?.len().saturating_sub(1) as u32)
+    }
+
+    pub struct Day6;
+
+    impl Solution for Day6 {
+        type Parsed<'a> = Vec<Edge<&'a str>>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+
+        fn explain(
+            parsed: &Self::Parsed<'_>,
+            explainer: &mut dyn Explainer,
+        ) -> Result<(), SolveError> {
+            let path = transfer_path(parsed)?;
+            explainer.note("transfer path", &path.join(" -> "));
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_6::*;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+    use strategies::strategies::{format_orbits, orbits};
+
+    #[test]
+    fn test_bad_orbits() {
+        let two_roots = input("A)B\nC)D").unwrap();
+        assert_eq!(
+            part_1(&two_roots).unwrap_err().to_string(),
+            "the input is invalid: more than one object orbits nothing"
+        );
+        let cycle = input("A)B\nB)A").unwrap();
+        assert!(part_1(&cycle).is_err());
+        let no_santa = input("COM)YOU").unwrap();
+        assert!(part_2(&no_santa).is_err());
+    }
+
+    #[test]
+    fn test_transfer_path() {
+        let orbits = input(include_str!("../examples/transfers.txt")).unwrap();
+        assert_eq!(transfer_path(&orbits).unwrap(), ["K", "J", "E", "D", "I"]);
+    }
+
+    proptest! {
+        #[test]
+        fn part_1_sums_depths(orbits in orbits(50)) {
+            let centres: HashMap<&str, &str> = orbits
+                .iter()
+                .map(|(centre, satellite)| (satellite.as_str(), centre.as_str()))
+                .collect();
+            let mut expected = 0;
+            for mut body in centres.keys() {
+                while let Some(centre) = centres.get(body) {
+                    body = centre;
+                    expected += 1;
+                }
+            }
+            let text = format_orbits(&orbits);
+            prop_assert_eq!(part_1(&input(&text).unwrap()).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_7::day_7::{input, part_1, part_1_parallel, part_1_shared_prefix, part_2, part_2_parallel};
+use intcode::intcode::{MachineState, Program};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input_str) = loader::loader::load(7) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
+    c.bench_function("day 7 part 1", |b| {
+        b.iter(|| {
+            black_box(part_1(&input.iter().copied()).unwrap());
+        })
+    });
+    // The machine setup which part 1 does between each of its 120 permutations.
+    let mut machines: [MachineState<i32>; 5] =
+        std::array::from_fn(|_| MachineState::new_with_memory(&input.iter().copied()));
+    c.bench_function("day 7 reset from iterator", |b| {
+        b.iter(|| {
+            for machine in machines.iter_mut() {
+                machine.reset(input.iter().copied());
+            }
+            black_box(&machines);
+        })
+    });
+    let program = Program::new(input.iter().copied());
+    c.bench_function("day 7 reset from program", |b| {
+        b.iter(|| {
+            for machine in machines.iter_mut() {
+                machine.reset_from_program(&program);
+            }
+            black_box(&machines);
+        })
+    });
+    c.bench_function("day 7 part 2", |b| {
+        b.iter(|| {
+            black_box(part_2(&input).unwrap());
+        })
+    });
+    c.bench_function("day 7 part 1 parallel", |b| {
+        b.iter(|| {
+            black_box(part_1_parallel(&input).unwrap());
+        })
+    });
+    c.bench_function("day 7 part 1 shared prefix", |b| {
+        b.iter(|| {
+            black_box(part_1_shared_prefix(&input).unwrap());
+        })
+    });
+    c.bench_function("day 7 part 2 parallel", |b| {
+        b.iter(|| {
+            black_box(part_2_parallel(&input).unwrap());
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
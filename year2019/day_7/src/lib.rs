@@ -0,0 +1,509 @@
+pub mod day_7 {
+    use std::array;
+
+    use intcode::intcode::StepIoResult;
+    use intcode::intcode::{MachineExecutionError, MachineState, Program};
+    use intcode::network::{Cluster, Schedule};
+    use itertools::Itertools;
+    use parse::parse::{comma_separated, ParseError};
+    use rayon::prelude::*;
+    use solution::solution::{solved, Answer, Solution};
+    use thiserror::Error;
+
+    pub fn input(s: &str) -> Result<Vec<i32>, ParseError> {
+        comma_separated(s)
+    }
+
+    #[derive(Error, Debug)]
+    pub enum AmplifierError {
+        #[error("machine {machine} is waiting for input which will never come, because the machine feeding it has terminated")]
+        Deadlock { machine: usize },
+        #[error("machine {machine} terminated unexpectedly")]
+        UnexpectedTermination { machine: usize },
+        #[error(
+            "machine {machine} produced output {value} before it had read its phase and input signal"
+        )]
+        UnexpectedOutput { machine: usize, value: i32 },
+        #[error(transparent)]
+        MachineError(#[from] MachineExecutionError),
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    enum ExecutionState<T> {
+        AwaitingInput,
+        OutputPending(T),
+        Ready,
+        Terminated,
+    }
+
+    pub fn initialise<const N: usize>(
+        phase: &[u8],
+        machines: &mut [MachineState<i32>; N],
+    ) -> Result<(), AmplifierError> {
+        for i in 0..N {
+            let phase = phase[i];
+            match machines[i].execute_until_input()? {
+                StepIoResult::AwaitingInput(_) => {
+                    machines[i].push_input(phase as i32);
+                }
+                StepIoResult::Terminated => {
+                    return Err(AmplifierError::UnexpectedTermination { machine: i });
+                }
+                StepIoResult::Output(value) => {
+                    return Err(AmplifierError::UnexpectedOutput { machine: i, value });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs until machine E emits a value, returning that value;
+    /// or until all machines have halted, in which case you get back None.
+    fn execute<const N: usize>(
+        input_to_first: Option<i32>,
+        readiness: &mut [ExecutionState<i32>; N],
+        machines: &mut [MachineState<i32>; N],
+    ) -> Result<Option<i32>, AmplifierError> {
+        let mut first_input_consumed = false;
+
+        loop {
+            let mut progress_made = false;
+
+            for i in 0..N {
+                match readiness[i] {
+                    ExecutionState::Ready => {
+                        progress_made = true;
+                        match machines[i].execute_until_input()? {
+                            StepIoResult::Terminated => {
+                                readiness[i] = ExecutionState::Terminated;
+                            }
+                            StepIoResult::Output(output_val) => {
+                                readiness[i] = ExecutionState::OutputPending(output_val)
+                            }
+                            StepIoResult::AwaitingInput(_) => {
+                                readiness[i] = ExecutionState::AwaitingInput
+                            }
+                        }
+                    }
+                    ExecutionState::AwaitingInput => {
+                        if i == 0 {
+                            if !first_input_consumed {
+                                progress_made = true;
+                                match input_to_first {
+                                    None => {
+                                        machines[0].push_input(0);
+                                    }
+                                    Some(input) => {
+                                        machines[0].push_input(input);
+                                        readiness[N - 1] = ExecutionState::Ready;
+                                    }
+                                }
+                                readiness[0] = ExecutionState::Ready;
+                                first_input_consumed = true;
+                            }
+                        } else {
+                            match readiness[i - 1] {
+                                ExecutionState::OutputPending(output) => {
+                                    progress_made = true;
+                                    machines[i].push_input(output);
+                                    readiness[i] = ExecutionState::Ready;
+                                    readiness[i - 1] = ExecutionState::Ready;
+                                }
+                                ExecutionState::Terminated => {
+                                    return Err(AmplifierError::Deadlock { machine: i });
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    ExecutionState::OutputPending(val) => {
+                        // first_input_consumed, to determine whether the output is
+                        // still pending from a previous round
+                        if i == N - 1 && first_input_consumed {
+                            return Ok(Some(val));
+                        }
+                    }
+                    ExecutionState::Terminated => {}
+                }
+            }
+
+            if !progress_made {
+                return Ok(None);
+            }
+        }
+    }
+
+    fn clear_all<T: Clone>(machines: &mut [MachineState<T>], program: &Program<T>) {
+        for machine in machines {
+            machine.reset_from_program(program);
+        }
+    }
+
+    /// Runs one pass of the amplifier chain with the given phases, returning the output of
+    /// the last amplifier. The machines must be freshly loaded with the program.
+    fn amplify_once<const N: usize>(
+        phase: &[u8],
+        machines: &mut [MachineState<i32>; N],
+    ) -> Result<i32, AmplifierError> {
+        initialise(phase, machines)?;
+        let mut readiness = [ExecutionState::<i32>::Ready; N];
+        execute(None, &mut readiness, machines)?
+            .ok_or(AmplifierError::UnexpectedTermination { machine: N - 1 })
+    }
+
+    /// Runs the amplifiers in a feedback loop with the given phases until they all halt,
+    /// returning the last value emitted by the final amplifier (if any).
+    /// The machines must be freshly loaded with the program.
+    fn amplify_feedback<const N: usize>(
+        phase: &[u8],
+        machines: &mut [MachineState<i32>; N],
+    ) -> Result<Option<i32>, AmplifierError> {
+        initialise(phase, machines)?;
+        let mut readiness = [ExecutionState::<i32>::Ready; N];
+        let mut input_to_first = None;
+        while let Some(result) = execute(input_to_first, &mut readiness, machines)? {
+            input_to_first = Some(result);
+        }
+        Ok(input_to_first)
+    }
+
+    pub fn part_1<T>(numbers: &T) -> Result<i32, AmplifierError>
+    where
+        T: IntoIterator<Item = i32>,
+        T: Clone,
+    {
+        let program = Program::new(numbers.clone());
+        let mut machines: [MachineState<_>; 5] =
+            array::from_fn(|_| MachineState::from_program(&program));
+
+        let mut best = i32::MIN;
+
+        for phase in (0..=4).permutations(5) {
+            let result = amplify_once(&phase, &mut machines)?;
+            if result > best {
+                best = result;
+            }
+
+            clear_all(&mut machines, &program);
+        }
+
+        Ok(best)
+    }
+
+    /// As `part_1`, but evaluates the permutations in parallel, each on a set of machines
+    /// belonging to its thread, reloaded with the program for each permutation.
+    pub fn part_1_parallel(numbers: &[i32]) -> Result<i32, AmplifierError> {
+        let program = Program::new(numbers.iter().copied());
+        let phases: Vec<Vec<u8>> = (0..=4).permutations(5).collect();
+        phases
+            .par_iter()
+            .map_init(
+                || array::from_fn(|_| MachineState::from_program(&program)),
+                |machines: &mut [MachineState<_>; 5], phase| {
+                    clear_all(machines, &program);
+                    amplify_once(phase, machines)
+                },
+            )
+            .try_reduce(|| i32::MIN, |a, b| Ok(a.max(b)))
+    }
+
+    /// Feeds a single signal to an amplifier which has already been given its phase, and
+    /// returns the amplifier's output.
+    fn run_amplifier(
+        machine: &mut MachineState<i32>,
+        index: usize,
+        signal: i32,
+    ) -> Result<i32, AmplifierError> {
+        match machine.execute_until_input()? {
+            StepIoResult::AwaitingInput(_) => {
+                machine.push_input(signal);
+            }
+            StepIoResult::Output(value) => {
+                return Err(AmplifierError::UnexpectedOutput {
+                    machine: index,
+                    value,
+                });
+            }
+            StepIoResult::Terminated => {
+                return Err(AmplifierError::UnexpectedTermination { machine: index });
+            }
+        }
+        match machine.execute_until_input()? {
+            StepIoResult::Output(value) => Ok(value),
+            StepIoResult::AwaitingInput(_) => Err(AmplifierError::Deadlock { machine: index }),
+            StepIoResult::Terminated => {
+                Err(AmplifierError::UnexpectedTermination { machine: index })
+            }
+        }
+    }
+
+    fn search_prefixes<const N: usize>(
+        primed: &MachineState<i32>,
+        signal: i32,
+        depth: usize,
+        used: &mut [bool; N],
+        best: &mut i32,
+    ) -> Result<(), AmplifierError> {
+        if depth == N {
+            if signal > *best {
+                *best = signal;
+            }
+            return Ok(());
+        }
+
+        for phase in 0..N {
+            if used[phase] {
+                continue;
+            }
+            let mut machine = primed.clone();
+            machine.push_input(phase as i32);
+            let output = run_amplifier(&mut machine, depth, signal)?;
+
+            used[phase] = true;
+            search_prefixes(primed, output, depth + 1, used, best)?;
+            used[phase] = false;
+        }
+
+        Ok(())
+    }
+
+    /// As `part_1`, but searches the permutations as a tree over phase choices, so that the
+    /// amplifier outputs for a shared prefix of phases are only computed once.
+    /// Each amplifier starts from a snapshot of the program which has already run up to its
+    /// request for a phase.
+    ///
+    /// This relies on each amplifier consuming exactly one signal and producing one output,
+    /// as the puzzle specifies; it does 325 amplifier runs rather than 600.
+    pub fn part_1_shared_prefix(numbers: &[i32]) -> Result<i32, AmplifierError> {
+        let mut primed = MachineState::from_program(&Program::new(numbers.iter().copied()));
+        match primed.execute_until_input()? {
+            StepIoResult::AwaitingInput(_) => {}
+            StepIoResult::Terminated => {
+                return Err(AmplifierError::UnexpectedTermination { machine: 0 });
+            }
+            StepIoResult::Output(value) => {
+                return Err(AmplifierError::UnexpectedOutput { machine: 0, value });
+            }
+        }
+
+        let mut best = i32::MIN;
+        search_prefixes(&primed, 0, 0, &mut [false; 5], &mut best)?;
+        Ok(best)
+    }
+
+    pub fn part_2<T>(numbers: &T) -> Result<i32, AmplifierError>
+    where
+        T: IntoIterator<Item = i32>,
+        T: Clone,
+    {
+        let program = Program::new(numbers.clone());
+        let mut machines: [MachineState<_>; 5] =
+            array::from_fn(|_| MachineState::from_program(&program));
+
+        let mut best = i32::MIN;
+
+        for phase in (5..=9).permutations(5) {
+            if let Some(x) = amplify_feedback(&phase, &mut machines)? {
+                if x > best {
+                    best = x;
+                }
+            }
+            clear_all(&mut machines, &program);
+        }
+
+        Ok(best)
+    }
+
+    /// As `part_2`, but evaluates the permutations in parallel, each on a set of machines
+    /// belonging to its thread, reloaded with the program for each permutation.
+    pub fn part_2_parallel(numbers: &[i32]) -> Result<i32, AmplifierError> {
+        let program = Program::new(numbers.iter().copied());
+        let phases: Vec<Vec<u8>> = (5..=9).permutations(5).collect();
+        phases
+            .par_iter()
+            .map_init(
+                || array::from_fn(|_| MachineState::from_program(&program)),
+                |machines: &mut [MachineState<_>; 5], phase| {
+                    clear_all(machines, &program);
+                    Ok(amplify_feedback(phase, machines)?.unwrap_or(i32::MIN))
+                },
+            )
+            .try_reduce(|| i32::MIN, |a, b| Ok(a.max(b)))
+    }
+
+    /// Runs the amplifiers in a feedback loop as a `Cluster` under `schedule`, with each
+    /// amplifier's outputs sent on to the next, returning the last value emitted by the final
+    /// amplifier (if any).
+    fn amplify_cluster(
+        program: &Program<i32>,
+        phase: &[u8],
+        schedule: Schedule,
+    ) -> Result<Option<i32>, AmplifierError> {
+        let machines = phase
+            .iter()
+            .map(|_| MachineState::from_program(program))
+            .collect();
+        let mut cluster = Cluster::new(machines).with_schedule(schedule);
+        for (i, &phase) in phase.iter().enumerate() {
+            cluster.send(i, phase as i32);
+        }
+        cluster.send(0, 0);
+
+        let mut last = None;
+        while !cluster.all_terminated() {
+            let round = cluster.round()?;
+            if round.idle && !cluster.all_terminated() {
+                let machine = (0..cluster.len()).find(|&i| cluster.is_blocked(i));
+                return Err(AmplifierError::Deadlock {
+                    machine: machine.unwrap_or(0),
+                });
+            }
+            for (from, value) in round.outputs {
+                if from == cluster.len() - 1 {
+                    last = Some(value);
+                }
+                cluster.send((from + 1) % cluster.len(), value);
+            }
+        }
+        Ok(last)
+    }
+
+    /// As `part_2`, but running each feedback loop as a `Cluster` under `schedule`. Each
+    /// amplifier only ever waits on the one before it, so the answer is the same whatever the
+    /// schedule.
+    pub fn part_2_scheduled(numbers: &[i32], schedule: Schedule) -> Result<i32, AmplifierError> {
+        let program = Program::new(numbers.iter().copied());
+        let mut best = i32::MIN;
+        for phase in (5..=9).permutations(5) {
+            if let Some(x) = amplify_cluster(&program, &phase, schedule)? {
+                best = best.max(x);
+            }
+        }
+        Ok(best)
+    }
+
+    pub struct Day7;
+
+    impl Solution for Day7 {
+        type Parsed<'a> = Vec<i32>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_7::*;
+    use intcode::network::Schedule;
+
+    const SCHEDULES: [Schedule; 3] = [
+        Schedule::UntilBlocked,
+        Schedule::RoundRobin { quantum: 1 },
+        Schedule::RoundRobin { quantum: 7 },
+    ];
+
+    #[test]
+    fn test_part_1() {
+        for (example, expected) in [
+            (include_str!("../examples/thrusters-1.txt"), 43210),
+            (include_str!("../examples/thrusters-2.txt"), 54321),
+            (include_str!("../examples/thrusters-3.txt"), 65210),
+        ] {
+            let i = input(example).unwrap();
+            assert_eq!(part_1_parallel(&i).unwrap(), expected);
+            assert_eq!(part_1_shared_prefix(&i).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        for (example, expected) in [
+            (include_str!("../examples/feedback-1.txt"), 139629729),
+            (include_str!("../examples/feedback-2.txt"), 18216),
+        ] {
+            let i = input(example).unwrap();
+            assert_eq!(part_2_parallel(&i).unwrap(), expected);
+            for schedule in SCHEDULES {
+                assert_eq!(part_2_scheduled(&i, schedule).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_programs() {
+        // Halts before asking for its phase.
+        let i = input("99").unwrap();
+        assert!(matches!(
+            part_1(&i),
+            Err(AmplifierError::UnexpectedTermination { machine: 0 })
+        ));
+        // Outputs before asking for its phase.
+        let i = input("104,7,99").unwrap();
+        assert!(matches!(
+            part_1(&i),
+            Err(AmplifierError::UnexpectedOutput {
+                machine: 0,
+                value: 7
+            })
+        ));
+        // Outputs after reading its phase, but before reading its input signal.
+        let i = input("3,0,104,7,3,0,4,0,99").unwrap();
+        assert!(matches!(
+            part_1_shared_prefix(&i),
+            Err(AmplifierError::UnexpectedOutput {
+                machine: 0,
+                value: 7
+            })
+        ));
+        // Consumes one input and halts, so amplifier B waits forever on amplifier A.
+        let i = input("3,0,3,0,99").unwrap();
+        assert!(matches!(
+            part_1(&i),
+            Err(AmplifierError::Deadlock { machine: 1 })
+        ));
+        assert!(matches!(
+            part_2_parallel(&i),
+            Err(AmplifierError::Deadlock { machine: 1 })
+        ));
+        for schedule in SCHEDULES {
+            assert!(matches!(
+                part_2_scheduled(&i, schedule),
+                Err(AmplifierError::Deadlock { machine: 1 })
+            ));
+        }
+        // Without the chained executor, the same program is simply seen to halt without output.
+        assert!(matches!(
+            part_1_shared_prefix(&i),
+            Err(AmplifierError::UnexpectedTermination { machine: 0 })
+        ));
+        // Bad opcode.
+        let i = input("3,0,55").unwrap();
+        assert!(matches!(part_1(&i), Err(AmplifierError::MachineError(_))));
+    }
+
+    #[test]
+    fn test_day_7() {
+        let Some(input_str) = loader::loader::load(7) else {
+            return;
+        };
+        let input = input(&input_str).unwrap();
+        // The answers themselves are in answers.toml; the other executors must agree.
+        let (part_1, part_2) = (part_1(&input).unwrap(), part_2(&input).unwrap());
+        assert_eq!(part_1_parallel(&input).unwrap(), part_1);
+        assert_eq!(part_1_shared_prefix(&input).unwrap(), part_1);
+        assert_eq!(part_2_parallel(&input).unwrap(), part_2);
+        for schedule in SCHEDULES {
+            assert_eq!(part_2_scheduled(&input, schedule).unwrap(), part_2);
+        }
+    }
+}
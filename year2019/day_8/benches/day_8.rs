@@ -2,7 +2,10 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use day_8::day_8::{input, part_1, part_2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input::<6, 25>(include_str!("../input.txt"));
+    let Some(input_str) = loader::loader::load(8) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
     c.bench_function("day 8 part 1", |b| {
         b.iter(|| {
             black_box(part_1(&input));
@@ -10,7 +13,7 @@ fn criterion_benchmark(c: &mut Criterion) {
     });
     c.bench_function("day 8 part 2", |b| {
         b.iter(|| {
-            black_box(part_2(&input));
+            black_box(part_2(&input).unwrap());
         })
     });
 }
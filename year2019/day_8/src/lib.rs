@@ -0,0 +1,476 @@
+pub mod day_8 {
+    use std::fmt::Display;
+
+    use grid::grid::{Cell, Grid};
+    use ocr::ocr::recognize;
+    pub use ocr::ocr::OcrError;
+    use parse::parse::{error_at, ParseError};
+    use solution::solution::{answer, solved, Answer, Solution};
+    use thiserror::Error;
+    #[cfg(feature = "image")]
+    use viz_image::viz_image::{self as viz, ExportError, RgbaImage, BLACK, TRANSPARENT, WHITE};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Colour {
+        Black,
+        White,
+        Transparent,
+    }
+
+    impl Colour {
+        pub fn from_digit(digit: u32) -> Option<Colour> {
+            match digit {
+                0 => Some(Colour::Black),
+                1 => Some(Colour::White),
+                2 => Some(Colour::Transparent),
+                _ => None,
+            }
+        }
+
+        /// The colour seen when this colour is placed on top of `below`.
+        pub fn over(self, below: Colour) -> Colour {
+            match self {
+                Colour::Transparent => below,
+                colour => colour,
+            }
+        }
+    }
+
+    impl Cell for Colour {
+        fn to_char(&self) -> char {
+            match self {
+                Colour::Transparent => ' ',
+                Colour::White => 'X',
+                Colour::Black => '.',
+            }
+        }
+    }
+
+    /// Black and white, with transparent pixels fully transparent.
+    #[cfg(feature = "image")]
+    fn palette(colour: &Colour) -> viz::Rgba<u8> {
+        match colour {
+            Colour::Black => BLACK,
+            Colour::White => WHITE,
+            Colour::Transparent => TRANSPARENT,
+        }
+    }
+
+    #[derive(Error, Debug, PartialEq, Eq)]
+    pub enum ImageError {
+        #[error("image dimensions must be nonzero, but got {width}x{height}")]
+        ZeroDimension { width: usize, height: usize },
+        #[error("image dimensions {width}x{height} are too large")]
+        TooLarge { width: usize, height: usize },
+        #[error("image has no layers")]
+        NoLayers,
+        #[error(
+            "input has {len} pixels, which is not a multiple of the layer size {width}x{height}"
+        )]
+        IncompleteLayer {
+            len: usize,
+            width: usize,
+            height: usize,
+        },
+        #[error("invalid pixel {pixel:?} at index {index}")]
+        BadPixel { pixel: char, index: usize },
+        #[error(transparent)]
+        Unreadable(#[from] OcrError),
+    }
+
+    /// A single layer of an image.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Layer {
+        pixels: Grid<Colour>,
+    }
+
+    impl Display for Layer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.pixels.fmt(f)
+        }
+    }
+
+    impl Layer {
+        /// A layer of the given size which is entirely one colour.
+        pub fn filled(width: usize, height: usize, colour: Colour) -> Layer {
+            Layer {
+                pixels: Grid::new(height, width, colour),
+            }
+        }
+
+        pub fn width(&self) -> usize {
+            self.pixels.col_count()
+        }
+        pub fn height(&self) -> usize {
+            self.pixels.row_count()
+        }
+        pub fn get(&self, row: usize, col: usize) -> Option<Colour> {
+            self.pixels.get(row, col).copied()
+        }
+        /// The pixels in row-major order.
+        pub fn pixels(&self) -> &[Colour] {
+            self.pixels.cells()
+        }
+        pub fn grid(&self) -> &Grid<Colour> {
+            &self.pixels
+        }
+        pub fn count(&self, colour: Colour) -> usize {
+            self.pixels().iter().filter(|x| **x == colour).count()
+        }
+
+        /// Place this layer on top of `below`, in place.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the layers have different dimensions.
+        pub fn over(&mut self, below: &Layer) {
+            assert_eq!(
+                (self.width(), self.height()),
+                (below.width(), below.height())
+            );
+            for (pixel, below) in self.pixels.cells_mut().iter_mut().zip(below.pixels()) {
+                *pixel = pixel.over(*below);
+            }
+        }
+
+        /// Read the letters out of the layer.
+        pub fn recognise(&self) -> Result<String, OcrError> {
+            recognize(&self.pixels.map(|pixel| *pixel == Colour::White))
+        }
+
+        /// Render the layer, with each pixel drawn as a `scale`-by-`scale` square.
+        /// Transparent pixels are fully transparent in the output.
+        #[cfg(feature = "image")]
+        pub fn to_image(&self, scale: u32) -> RgbaImage {
+            viz::grid_image(&self.pixels, &palette, scale)
+        }
+
+        /// Save the layer as a PNG, with each pixel drawn as a `scale`-by-`scale` square.
+        #[cfg(feature = "image")]
+        pub fn to_png<P>(&self, path: P, scale: u32) -> Result<(), ExportError>
+        where
+            P: AsRef<std::path::Path>,
+        {
+            viz::save_png(&self.to_image(scale), path)
+        }
+    }
+
+    /// Composite layers, the first of which is frontmost.
+    /// Returns `None` if there are no layers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layers do not all have the same dimensions.
+    pub fn composite<'a, I>(layers: I) -> Option<Layer>
+    where
+        I: IntoIterator<Item = &'a Layer>,
+    {
+        let mut layers = layers.into_iter();
+        let mut result = layers.next()?.clone();
+        for layer in layers {
+            result.over(layer);
+        }
+        Some(result)
+    }
+
+    /// An image whose dimensions are only known at runtime, storing raw digits, which needn't
+    /// be colours; the `checksum` only counts them.
+    pub struct Image {
+        width: usize,
+        height: usize,
+        layers: Vec<Grid<u8>>,
+    }
+
+    impl Image {
+        /// Parse a string of digits into layers of the given size, ignoring surrounding
+        /// whitespace.
+        pub fn parse(s: &str, width: usize, height: usize) -> Result<Image, ImageError> {
+            let layer_size = match width.checked_mul(height) {
+                None => return Err(ImageError::TooLarge { width, height }),
+                Some(0) => return Err(ImageError::ZeroDimension { width, height }),
+                Some(layer_size) => layer_size,
+            };
+            let s = s.trim();
+            if s.is_empty() {
+                return Err(ImageError::NoLayers);
+            }
+            let pixels = s
+                .chars()
+                .enumerate()
+                .map(|(index, pixel)| {
+                    pixel
+                        .to_digit(10)
+                        .map(|digit| digit as u8)
+                        .ok_or(ImageError::BadPixel { pixel, index })
+                })
+                .collect::<Result<Vec<u8>, _>>()?;
+            if !pixels.len().is_multiple_of(layer_size) {
+                return Err(ImageError::IncompleteLayer {
+                    len: pixels.len(),
+                    width,
+                    height,
+                });
+            }
+
+            let layers = pixels
+                .chunks_exact(layer_size)
+                .map(|layer| Grid::from_cells(height, width, layer.to_vec()))
+                .collect();
+
+            Ok(Image {
+                width,
+                height,
+                layers,
+            })
+        }
+
+        pub fn width(&self) -> usize {
+            self.width
+        }
+        pub fn height(&self) -> usize {
+            self.height
+        }
+        pub fn layer_count(&self) -> usize {
+            self.layers.len()
+        }
+
+        /// The colours of each layer, frontmost first; a layer is an error if one of its pixels
+        /// isn't a colour.
+        pub fn iter_layers(&self) -> impl Iterator<Item = Result<Layer, ImageError>> + '_ {
+            let layer_size = self.width * self.height;
+            self.layers.iter().enumerate().map(move |(i, digits)| {
+                let mut pixels = Vec::with_capacity(layer_size);
+                for (j, &digit) in digits.cells().iter().enumerate() {
+                    let colour =
+                        Colour::from_digit(u32::from(digit)).ok_or(ImageError::BadPixel {
+                            pixel: char::from(b'0' + digit),
+                            index: i * layer_size + j,
+                        })?;
+                    pixels.push(colour);
+                }
+                Ok(Layer {
+                    pixels: Grid::from_cells(self.height, self.width, pixels),
+                })
+            })
+        }
+
+        /// The colours of each layer, frontmost first, or the first pixel which isn't a colour.
+        pub fn layers(&self) -> Result<Vec<Layer>, ImageError> {
+            self.iter_layers().collect()
+        }
+
+        /// The number of 1 digits multiplied by the number of 2 digits, on the layer with the
+        /// fewest 0 digits.
+        pub fn checksum(&self) -> u32 {
+            let count = |layer: &Grid<u8>, digit: u8| {
+                layer.cells().iter().filter(|d| **d == digit).count() as u32
+            };
+            let best_layer = self
+                .layers
+                .iter()
+                .min_by_key(|layer| count(layer, 0))
+                .expect("parsing guarantees there is at least one layer");
+            count(best_layer, 1) * count(best_layer, 2)
+        }
+
+        /// Composite all the layers of the image, which must all be colours.
+        pub fn decode(&self) -> Result<Layer, ImageError> {
+            let layers = self.layers()?;
+            Ok(composite(&layers).expect("parsing guarantees there is at least one layer"))
+        }
+    }
+
+    /// The dimensions of the puzzle's image.
+    pub const PUZZLE_WIDTH: usize = 25;
+    pub const PUZZLE_HEIGHT: usize = 6;
+
+    /// The puzzle's image, whose layers are `PUZZLE_WIDTH` by `PUZZLE_HEIGHT`.
+    pub fn input(s: &str) -> Result<Image, ParseError> {
+        let trimmed = s.trim();
+        Image::parse(s, PUZZLE_WIDTH, PUZZLE_HEIGHT).map_err(|e| match e {
+            ImageError::NoLayers => ParseError::Empty,
+            ImageError::BadPixel { index, .. } => {
+                let (i, c) = trimmed
+                    .char_indices()
+                    .nth(index)
+                    .expect("the bad pixel is in the input");
+                error_at(
+                    s,
+                    &trimmed[i..i + c.len_utf8()],
+                    format!("expected a digit, but found {c:?}"),
+                )
+            }
+            // Every pixel is an ASCII digit by now, so this slices at a character boundary.
+            ImageError::IncompleteLayer { len, .. } => {
+                error_at(s, &trimmed[len - len % (PUZZLE_WIDTH * PUZZLE_HEIGHT)..], e)
+            }
+            e => error_at(s, trimmed, e),
+        })
+    }
+
+    pub fn part_1(image: &Image) -> u32 {
+        image.checksum()
+    }
+
+    pub fn part_2(image: &Image) -> Result<String, ImageError> {
+        Ok(image.decode()?.recognise()?)
+    }
+
+    pub struct Day8;
+
+    impl Solution for Day8 {
+        type Parsed<'a> = Image;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            answer(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_8::*;
+    use parse::parse::ParseError;
+    use snapshot::assert_grid_snapshot;
+
+    #[test]
+    fn test_part1_known() {
+        assert_eq!(Image::parse("123456789012", 3, 2).unwrap().checksum(), 1);
+    }
+
+    #[test]
+    fn test_input() {
+        let pixels = "0".repeat(PUZZLE_WIDTH * PUZZLE_HEIGHT);
+        assert_eq!(input(&pixels).unwrap().layer_count(), 1);
+        assert_eq!(
+            input(&format!("\n{pixels}12")).err().map(|e| e.to_string()),
+            Some(
+                "line 2, column 151: input has 152 pixels, which is not a multiple of the layer size 25x6"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            input("\n12x").err().map(|e| e.to_string()),
+            Some("line 2, column 3: expected a digit, but found 'x'".to_string())
+        );
+        assert_eq!(input(" \n").err(), Some(ParseError::Empty));
+    }
+
+    #[test]
+    fn test_part2_known() {
+        let image = Image::parse("0222112222120000", 2, 2).unwrap();
+        assert_grid_snapshot!(8, 2, "example", image.decode().unwrap());
+    }
+
+    #[test]
+    fn test_image_known() {
+        let image = Image::parse("123456789012", 3, 2).unwrap();
+        assert_eq!(image.layer_count(), 2);
+        assert_eq!(image.checksum(), 1);
+        assert_eq!(
+            image.decode().err(),
+            Some(ImageError::BadPixel {
+                pixel: '3',
+                index: 2
+            })
+        );
+
+        let image = Image::parse("0222112222120000\n", 2, 2).unwrap();
+        assert_eq!(image.decode().unwrap().to_string(), ".X\nX.\n");
+    }
+
+    #[test]
+    fn test_image_errors() {
+        assert_eq!(
+            Image::parse("12345678901", 3, 2).err(),
+            Some(ImageError::IncompleteLayer {
+                len: 11,
+                width: 3,
+                height: 2
+            })
+        );
+        assert_eq!(
+            Image::parse("123456", 0, 2).err(),
+            Some(ImageError::ZeroDimension {
+                width: 0,
+                height: 2
+            })
+        );
+        assert_eq!(
+            Image::parse("123456", usize::MAX, 2).err(),
+            Some(ImageError::TooLarge {
+                width: usize::MAX,
+                height: 2
+            })
+        );
+        assert_eq!(Image::parse("\n", 3, 2).err(), Some(ImageError::NoLayers));
+        assert_eq!(
+            Image::parse("12x456", 3, 2).err(),
+            Some(ImageError::BadPixel {
+                pixel: 'x',
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_composite() {
+        let image = Image::parse("0222112222120000", 2, 2).unwrap();
+        let layers = image.layers().unwrap();
+        assert_eq!(
+            image.iter_layers().collect::<Result<Vec<_>, _>>().unwrap(),
+            layers
+        );
+        assert_eq!(layers[1].get(0, 0), Some(Colour::White));
+        assert_eq!(layers[1].count(Colour::Transparent), 2);
+
+        // Compositing a prefix of the layers, or a layer over an opaque background
+        let top_two = composite(&layers[..2]).unwrap();
+        assert_eq!(top_two.to_string(), ".X\n  \n");
+        let mut over_white = top_two.clone();
+        over_white.over(&Layer::filled(2, 2, Colour::White));
+        assert_eq!(over_white.to_string(), ".X\nXX\n");
+
+        assert_eq!(composite(std::iter::empty()), None);
+        assert_eq!(composite(&layers), Some(image.decode().unwrap()));
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_to_image() {
+        use viz_image::viz_image::{BLACK, WHITE};
+
+        let image = Image::parse("0222112222120000", 2, 2)
+            .unwrap()
+            .decode()
+            .unwrap();
+        let rendered = image.to_image(3);
+        assert_eq!(rendered.dimensions(), (6, 6));
+        assert_eq!(*rendered.get_pixel(0, 0), BLACK);
+        assert_eq!(*rendered.get_pixel(5, 0), WHITE);
+        assert_eq!(*rendered.get_pixel(0, 5), WHITE);
+        assert_eq!(*rendered.get_pixel(5, 5), BLACK);
+    }
+
+    #[test]
+    fn test_day_8() {
+        let Some(input_str) = loader::loader::load(8) else {
+            return;
+        };
+        let image = input(&input_str).unwrap();
+        // The answers themselves are in answers.toml.
+        assert_eq!(part_1(&image), image.checksum());
+        assert_eq!(
+            part_2(&image).unwrap(),
+            image.decode().unwrap().recognise().unwrap()
+        );
+    }
+}
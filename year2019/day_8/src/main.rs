@@ -0,0 +1,32 @@
+use day_8::day_8;
+use solution::solution::{read_input, Report};
+
+fn main() -> Result<(), Report> {
+    let mut args: Vec<String> = std::env::args().collect();
+    logging::logging::init(&mut args);
+    let input_str = read_input(&args)?;
+    let (width, height) = match (args.get(2), args.get(3)) {
+        (None, None) => (day_8::PUZZLE_WIDTH, day_8::PUZZLE_HEIGHT),
+        (Some(width), Some(height)) => {
+            let width = width
+                .parse()
+                .map_err(|e| format!("Could not parse width {width} : {e}"))?;
+            let height = height
+                .parse()
+                .map_err(|e| format!("Could not parse height {height} : {e}"))?;
+            (width, height)
+        }
+        _ => {
+            return Err("Width and height must be supplied together".into());
+        }
+    };
+    let image = day_8::Image::parse(&input_str, width, height)?;
+
+    println!("part 1 => {}", image.checksum());
+    let decoded = image.decode()?;
+    match decoded.recognise() {
+        Ok(letters) => println!("part 2 => {letters}"),
+        Err(e) => println!("part 2 => ({e})\n{decoded}"),
+    }
+    Ok(())
+}
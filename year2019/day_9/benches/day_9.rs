@@ -1,8 +1,11 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_9::day_9::{input, part_1, part_2};
+use day_9::day_9::{input, part_1, part_2, run_big};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = input(include_str!("../input.txt"));
+    let Some(input_str) = loader::loader::load(9) else {
+        return;
+    };
+    let input = input(&input_str).unwrap();
     c.bench_function("day 9 part 1", |b| {
         b.iter(|| {
             black_box(part_1(&input.iter().copied()).unwrap());
@@ -13,6 +16,11 @@ fn criterion_benchmark(c: &mut Criterion) {
             black_box(part_2(&input).unwrap());
         })
     });
+    c.bench_function("day 9 part 2 bigint", |b| {
+        b.iter(|| {
+            black_box(run_big(&input, 2).unwrap());
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,312 @@
+pub mod day_9 {
+    use std::fmt::Display;
+
+    use intcode::intcode::{MachineExecutionError, MachineState};
+    use num_bigint::BigInt;
+    use parse::parse::{comma_separated, ParseError};
+    use solution::solution::{solved, Answer, AocError, Solution};
+    use thiserror::Error;
+
+    pub fn input(s: &str) -> Result<Vec<i64>, ParseError> {
+        comma_separated(s)
+    }
+
+    /// An instruction, with its parameter modes, which the BOOST program reported as faulty.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct FailedCheck {
+        instruction: i64,
+    }
+
+    impl FailedCheck {
+        /// The raw value which the BOOST program emitted, e.g. 203 for opcode 3 in relative mode.
+        pub fn instruction(&self) -> i64 {
+            self.instruction
+        }
+
+        pub fn opcode(&self) -> i64 {
+            self.instruction % 100
+        }
+
+        pub fn mnemonic(&self) -> Option<&'static str> {
+            match self.opcode() {
+                1 => Some("add"),
+                2 => Some("multiply"),
+                3 => Some("input"),
+                4 => Some("output"),
+                5 => Some("jump-if-true"),
+                6 => Some("jump-if-false"),
+                7 => Some("less than"),
+                8 => Some("equals"),
+                9 => Some("adjust relative base"),
+                99 => Some("halt"),
+                _ => None,
+            }
+        }
+
+        /// The parameter mode digits of the first three parameters.
+        pub fn parameter_modes(&self) -> [i64; 3] {
+            [
+                (self.instruction / 100) % 10,
+                (self.instruction / 1000) % 10,
+                (self.instruction / 10000) % 10,
+            ]
+        }
+    }
+
+    impl Display for FailedCheck {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "opcode {}", self.opcode())?;
+            if let Some(mnemonic) = self.mnemonic() {
+                write!(f, " ({mnemonic})")?;
+            }
+            f.write_str(" with parameter modes [")?;
+            for (i, mode) in self.parameter_modes().iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                f.write_str(match mode {
+                    0 => "position",
+                    1 => "immediate",
+                    2 => "relative",
+                    _ => "invalid",
+                })?;
+            }
+            write!(f, "] (raw value {})", self.instruction)
+        }
+    }
+
+    /// The outcome of running the BOOST program in test mode.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BoostReport {
+        pub failed_checks: Vec<FailedCheck>,
+        pub keycode: i64,
+    }
+
+    impl BoostReport {
+        pub fn passed(&self) -> bool {
+            self.failed_checks.is_empty()
+        }
+    }
+
+    impl Display for BoostReport {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{} failed check(s), keycode {}",
+                self.failed_checks.len(),
+                self.keycode
+            )?;
+            for check in &self.failed_checks {
+                write!(f, "\n  {check}")?;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Error, Debug)]
+    pub enum BoostError {
+        #[error("BOOST program produced no output")]
+        NoOutput,
+        #[error("BOOST self-test failed: {0}")]
+        ChecksFailed(BoostReport),
+        #[error("arbitrary-precision run with input {mode} gave {actual:?}, but i64 run gave {expected}")]
+        Mismatch {
+            mode: i64,
+            expected: i64,
+            actual: Vec<BigInt>,
+        },
+        #[error(transparent)]
+        Machine(#[from] MachineExecutionError),
+        #[error(transparent)]
+        Aoc(#[from] AocError),
+    }
+
+    /// Run the BOOST program in test mode. The last output is the keycode; any other output is
+    /// the result of a check, which is 0 if the check passed, or otherwise identifies an
+    /// instruction which the interpreter got wrong.
+    pub fn self_test<T>(numbers: &T) -> Result<BoostReport, BoostError>
+    where
+        T: IntoIterator<Item = i64>,
+        T: Clone,
+    {
+        let mut machine = MachineState::new_with_memory(numbers);
+        let mut outputs = machine.execute_to_end(&mut std::iter::once(1))?;
+        let keycode = outputs.pop().ok_or(BoostError::NoOutput)?;
+        let failed_checks = outputs
+            .into_iter()
+            .filter(|&instruction| instruction != 0)
+            .map(|instruction| FailedCheck { instruction })
+            .collect();
+
+        Ok(BoostReport {
+            failed_checks,
+            keycode,
+        })
+    }
+
+    pub fn part_1<T>(numbers: &T) -> Result<i64, BoostError>
+    where
+        T: IntoIterator<Item = i64>,
+        T: Clone,
+    {
+        let report = self_test(numbers)?;
+        if report.passed() {
+            Ok(report.keycode)
+        } else {
+            Err(BoostError::ChecksFailed(report))
+        }
+    }
+
+    pub fn part_2<T>(numbers: &T) -> Result<i64, AocError>
+    where
+        T: IntoIterator<Item = i64>,
+        T: Clone,
+    {
+        let mut machine = MachineState::new_with_memory(numbers);
+        let outputs = machine.execute_to_end(&mut std::iter::once(2))?;
+        match outputs[..] {
+            [coordinates] => Ok(coordinates),
+            _ => Err(AocError::OutputCount {
+                expected: 1,
+                actual: outputs.len(),
+            }),
+        }
+    }
+
+    /// Run the program with arbitrary-precision memory cells, feeding it the single given input.
+    pub fn run_big(numbers: &[i64], input: i64) -> Result<Vec<BigInt>, MachineExecutionError> {
+        let mut machine: MachineState<BigInt> =
+            MachineState::new_with_memory(&numbers.iter().map(|&i| BigInt::from(i)));
+        machine.execute_to_end(&mut std::iter::once(BigInt::from(input)))
+    }
+
+    /// Solve both parts, and check that the arbitrary-precision machine produces exactly the
+    /// same outputs as the `i64` one.
+    pub fn cross_check(numbers: &[i64]) -> Result<(i64, i64), BoostError> {
+        let answers = (
+            part_1(&numbers.iter().copied())?,
+            part_2(&numbers.iter().copied())?,
+        );
+        for (mode, expected) in [(1, answers.0), (2, answers.1)] {
+            let actual = run_big(numbers, mode)?;
+            if actual != [BigInt::from(expected)] {
+                return Err(BoostError::Mismatch {
+                    mode,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub struct Day9;
+
+    impl Solution for Day9 {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(s: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input(s)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_1(parsed))
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Answer {
+            solved(part_2(parsed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::day_9::*;
+    use num_bigint::BigInt;
+    use solution::solution::AocError;
+
+    #[test]
+    fn test_self_test_report() {
+        // Pretends that relative-mode input and immediate/position equality are broken.
+        let program = input("3,0,104,203,104,1108,104,42,99").unwrap();
+        let report = self_test(&program).unwrap();
+        assert!(!report.passed());
+        assert_eq!(report.keycode, 42);
+        let instructions: Vec<i64> = report
+            .failed_checks
+            .iter()
+            .map(|c| c.instruction())
+            .collect();
+        assert_eq!(instructions, vec![203, 1108]);
+        assert_eq!(report.failed_checks[0].opcode(), 3);
+        assert_eq!(
+            report.failed_checks[1].to_string(),
+            "opcode 8 (equals) with parameter modes [immediate, immediate, position] (raw value 1108)"
+        );
+        assert!(matches!(part_1(&program), Err(BoostError::ChecksFailed(r)) if r == report));
+
+        let program = input("3,0,104,42,99").unwrap();
+        assert_eq!(part_1(&program).unwrap(), 42);
+
+        // A check which outputs 0 passed.
+        let program = input("3,0,104,0,104,0,104,42,99").unwrap();
+        let report = self_test(&program).unwrap();
+        assert!(report.passed());
+        assert_eq!(report.keycode, 42);
+        assert_eq!(part_1(&program).unwrap(), 42);
+
+        let program = input("3,0,104,0,104,203,104,42,99").unwrap();
+        let report = self_test(&program).unwrap();
+        assert_eq!(report.failed_checks.len(), 1);
+        assert_eq!(report.failed_checks[0].instruction(), 203);
+
+        let program = input("3,0,99").unwrap();
+        assert!(matches!(part_1(&program), Err(BoostError::NoOutput)));
+    }
+
+    #[test]
+    fn test_big() {
+        let quine = input("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99").unwrap();
+        let expected: Vec<BigInt> = quine.iter().map(|&i| BigInt::from(i)).collect();
+        assert_eq!(run_big(&quine, 0).unwrap(), expected);
+
+        let program = input("104,1125899906842624,99").unwrap();
+        assert_eq!(
+            run_big(&program, 0).unwrap(),
+            vec![BigInt::from(1125899906842624i64)]
+        );
+
+        // 2^62 * 4 overflows an i64.
+        let program = input("1102,4611686018427387904,4,7,4,7,99,0").unwrap();
+        assert_eq!(
+            run_big(&program, 0).unwrap(),
+            vec!["18446744073709551616".parse::<BigInt>().unwrap()]
+        );
+
+        let echo = input("3,0,4,0,99").unwrap();
+        assert_eq!(cross_check(&echo).unwrap(), (1, 2));
+
+        let chatty = input("104,1,104,2,99").unwrap();
+        assert!(matches!(
+            part_2(&chatty),
+            Err(AocError::OutputCount {
+                expected: 1,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_day_9() {
+        let Some(input_str) = loader::loader::load(9) else {
+            return;
+        };
+        let input = input(&input_str).unwrap();
+        // The answers themselves are in answers.toml.
+        assert_eq!(
+            cross_check(&input).unwrap(),
+            (part_1(&input).unwrap(), part_2(&input).unwrap())
+        );
+    }
+}